@@ -0,0 +1,13 @@
+//! Overlap tests on the axis-aligned boxes everything collides as.
+
+/// Boxes at (`ax`, `ay`) and (`bx`, `by`) share at least one pixel; edges that only touch don't count.
+#[allow(clippy::too_many_arguments)]
+pub fn aabb_overlap(ax: i32, ay: i32, aw: i32, ah: i32, bx: i32, by: i32, bw: i32, bh: i32) -> bool {
+    ax < bx + bw && ax + aw > bx && ay < by + bh && ay + ah > by
+}
+
+/// Left edges a `w` px ship at `x` is drawn at on a field `field_w` wide: two while edge wrap
+/// has it split over the right edge.
+pub fn ship_copies(x: i32, w: i32, field_w: i32) -> impl Iterator<Item = i32> {
+    [x, x - field_w].into_iter().take(if x + w > field_w { 2 } else { 1 })
+}
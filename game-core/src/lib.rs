@@ -0,0 +1,12 @@
+//! Game rules that don't touch the hardware: collision math and entity pools.
+//!
+//! Kept out of the firmware binary so it builds for the host too, where
+//! `cargo test` in this directory runs its property tests.
+
+#![no_std]
+
+mod collide;
+mod pool;
+
+pub use collide::{aabb_overlap, ship_copies};
+pub use pool::{Pool, Slot};
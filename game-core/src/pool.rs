@@ -0,0 +1,27 @@
+//! Fixed-size entity pools: a slot is free while its entity is inactive, and
+//! spawning takes the first free one.
+
+/// An entity kept in a fixed pool.
+pub trait Slot {
+    fn active(&self) -> bool;
+}
+
+pub trait Pool<T> {
+    /// First free slot, or `None` while every slot is taken.
+    fn free_slot(&mut self) -> Option<&mut T>;
+    /// Up to `n` free slots, in pool order.
+    fn free_slots<'a>(&'a mut self, n: usize) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a;
+}
+
+impl<T: Slot> Pool<T> for [T] {
+    fn free_slot(&mut self) -> Option<&mut T> { self.iter_mut().find(|t| !t.active()) }
+
+    fn free_slots<'a>(&'a mut self, n: usize) -> impl Iterator<Item = &'a mut T>
+    where
+        T: 'a,
+    {
+        self.iter_mut().filter(|t| !t.active()).take(n)
+    }
+}
@@ -0,0 +1,129 @@
+//! Property tests for the collision math and entity pools.
+
+use game_core::{aabb_overlap, ship_copies, Pool, Slot};
+use proptest::prelude::*;
+
+fn coord() -> impl Strategy<Value = i32> { -300..300 }
+fn size() -> impl Strategy<Value = i32> { 1..64 }
+
+proptest! {
+    #[test]
+    fn overlap_is_symmetric(
+        (ax, ay, aw, ah) in (coord(), coord(), size(), size()),
+        (bx, by, bw, bh) in (coord(), coord(), size(), size()),
+    ) {
+        prop_assert_eq!(aabb_overlap(ax, ay, aw, ah, bx, by, bw, bh), aabb_overlap(bx, by, bw, bh, ax, ay, aw, ah));
+    }
+
+    #[test]
+    fn touching_edges_dont_overlap(
+        (x, y, w, h) in (coord(), coord(), size(), size()),
+        (ow, oh) in (size(), size()),
+        slide in -63..64,
+    ) {
+        // Flush against each side of the first box, anywhere along it
+        prop_assert!(!aabb_overlap(x, y, w, h, x + w, y + slide, ow, oh));
+        prop_assert!(!aabb_overlap(x, y, w, h, x - ow, y + slide, ow, oh));
+        prop_assert!(!aabb_overlap(x, y, w, h, x + slide, y + h, ow, oh));
+        prop_assert!(!aabb_overlap(x, y, w, h, x + slide, y - oh, ow, oh));
+    }
+
+    #[test]
+    fn shared_pixel_overlaps(
+        (x, y, w, h) in (coord(), coord(), size(), size()),
+        (ow, oh) in (size(), size()),
+        (dx, dy) in (0..64, 0..64),
+    ) {
+        // The second box's corner lands inside the first, up to its last row and column
+        prop_assert!(aabb_overlap(x, y, w, h, x + dx % w, y + dy % h, ow, oh));
+        // ...and the same one pixel in from the left and top
+        prop_assert!(aabb_overlap(x, y, w, h, x - ow + 1, y - oh + 1, ow, oh));
+    }
+}
+
+const FIELD_W: i32 = 240;
+
+proptest! {
+    #[test]
+    fn wrapped_ship_covers_its_columns_once(x in 0..FIELD_W, w in 1..=32) {
+        let copies: Vec<i32> = ship_copies(x, w, FIELD_W).collect();
+        prop_assert_eq!(copies[0], x);
+        prop_assert_eq!(copies.len(), if x + w > FIELD_W { 2 } else { 1 });
+        // Every column of the wrapped hull is drawn by exactly one copy, and nothing else is
+        for col in 0..FIELD_W {
+            let drawn = copies.iter().filter(|&&c| (c..c + w).contains(&col)).count();
+            let hull = (x..x + w).any(|p| p.rem_euclid(FIELD_W) == col);
+            prop_assert_eq!(drawn, hull as usize);
+        }
+    }
+}
+
+const POOL: usize = 8;
+
+#[derive(Clone, Copy, Default)]
+struct Entity {
+    id: u32,
+    active: bool,
+}
+
+impl Slot for Entity {
+    fn active(&self) -> bool { self.active }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Spawn,
+    /// A burst asking for this many slots at once
+    Burst(usize),
+    Despawn(usize),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![Just(Op::Spawn), (0..POOL + 3).prop_map(Op::Burst), (0..POOL).prop_map(Op::Despawn)]
+}
+
+proptest! {
+    #[test]
+    fn spawns_stay_in_bounds_and_never_take_a_live_slot(ops in prop::collection::vec(op(), 0..200)) {
+        let mut pool = [Entity::default(); POOL];
+        let mut next_id = 1;
+        let mut live = 0;
+        for op in ops {
+            let before = pool;
+            match op {
+                Op::Spawn => match pool.free_slot() {
+                    Some(e) => {
+                        prop_assert!(!e.active);
+                        *e = Entity { id: next_id, active: true };
+                        next_id += 1;
+                        live += 1;
+                    }
+                    None => prop_assert_eq!(live, POOL),
+                },
+                Op::Burst(n) => {
+                    let mut got = 0;
+                    for e in pool.free_slots(n) {
+                        prop_assert!(!e.active);
+                        *e = Entity { id: next_id, active: true };
+                        next_id += 1;
+                        got += 1;
+                    }
+                    prop_assert_eq!(got, n.min(POOL - live));
+                    live += got;
+                }
+                Op::Despawn(i) => {
+                    if pool[i].active { live -= 1; }
+                    pool[i].active = false;
+                }
+            }
+            // Whatever was live is untouched, bar the one despawned
+            for (i, (b, a)) in before.iter().zip(&pool).enumerate() {
+                if b.active && !matches!(op, Op::Despawn(d) if d == i) {
+                    prop_assert!(a.active);
+                    prop_assert_eq!(a.id, b.id);
+                }
+            }
+            prop_assert_eq!(pool.iter().filter(|e| e.active).count(), live);
+        }
+    }
+}
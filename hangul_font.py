@@ -0,0 +1,183 @@
+#!/usr/bin/env python3
+"""Generate the Hangul bitmap glyphs used by the Korean UI strings.
+
+Scans src/strings.rs for Hangul syllables, composes each one from stroked
+jamo into a 12x12 bitmap and rewrites the glyph table in src/hangul.rs.
+Run it again whenever a Korean string changes.
+"""
+
+import re
+import sys
+
+SIZE = 12
+STRINGS_RS = "src/strings.rs"
+HANGUL_RS = "src/hangul.rs"
+BEGIN = "// BEGIN GENERATED GLYPHS"
+END = "// END GENERATED GLYPHS"
+
+# Consonants as polylines in a unit box, x right and y down
+CONSONANTS = {
+    "ㄱ": [[(0, 0), (1, 0), (1, 1)]],
+    "ㄴ": [[(0, 0), (0, 1), (1, 1)]],
+    "ㄷ": [[(1, 0), (0, 0), (0, 1), (1, 1)]],
+    "ㄹ": [[(0, 0), (1, 0), (1, 0.5), (0, 0.5), (0, 1), (1, 1)]],
+    "ㅁ": [[(0, 0), (1, 0), (1, 1), (0, 1), (0, 0)]],
+    "ㅂ": [[(0, 0), (0, 1), (1, 1), (1, 0)], [(0, 0.5), (1, 0.5)]],
+    "ㅅ": [[(0.5, 0), (0, 1)], [(0.5, 0), (1, 1)]],
+    "ㅈ": [[(0, 0), (1, 0)], [(0.5, 0), (0, 1)], [(0.5, 0), (1, 1)]],
+    "ㅊ": [[(0.5, 0), (0.5, 0)], [(0, 0.25), (1, 0.25)], [(0.5, 0.25), (0, 1)], [(0.5, 0.25), (1, 1)]],
+    "ㅋ": [[(0, 0), (1, 0), (1, 1)], [(0, 0.5), (1, 0.5)]],
+    "ㅌ": [[(1, 0), (0, 0), (0, 1), (1, 1)], [(0, 0.5), (1, 0.5)]],
+    "ㅍ": [[(0, 0), (1, 0)], [(0, 1), (1, 1)], [(0.3, 0), (0.3, 1)], [(0.7, 0), (0.7, 1)]],
+    "ㅎ": [[(0.5, 0), (0.5, 0)], [(0, 0.2), (1, 0.2)],
+          [(0.35, 0.4), (0.65, 0.4), (0.8, 0.55), (0.8, 0.85), (0.65, 1), (0.35, 1), (0.2, 0.85), (0.2, 0.55),
+           (0.35, 0.4)]],
+}
+# Double and compound consonants are two halves side by side
+PAIRS = {
+    "ㄲ": "ㄱㄱ", "ㄸ": "ㄷㄷ", "ㅃ": "ㅂㅂ", "ㅆ": "ㅅㅅ", "ㅉ": "ㅈㅈ",
+    "ㄳ": "ㄱㅅ", "ㄵ": "ㄴㅈ", "ㄶ": "ㄴㅎ", "ㄺ": "ㄹㄱ", "ㄻ": "ㄹㅁ", "ㄼ": "ㄹㅂ", "ㄽ": "ㄹㅅ",
+    "ㄾ": "ㄹㅌ", "ㄿ": "ㄹㅍ", "ㅀ": "ㄹㅎ", "ㅄ": "ㅂㅅ",
+}
+
+INITIALS = "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ"
+VOWELS = "ㅏㅐㅑㅒㅓㅔㅕㅖㅗㅘㅙㅚㅛㅜㅝㅞㅟㅠㅡㅢㅣ"
+FINALS = " ㄱㄲㄳㄴㄵㄶㄷㄹㄺㄻㄼㄽㄾㄿㅀㅁㅂㅄㅅㅆㅇㅈㅊㅋㅌㅍㅎ"
+
+# Vertical vowels: (bars, stubs) as columns and (first, last, row fraction) stubs
+VERTICAL = {
+    "ㅏ": ([8], [(9, 10, 0.5)]),
+    "ㅐ": ([7, 10], [(8, 9, 0.5)]),
+    "ㅑ": ([8], [(9, 10, 0.3), (9, 10, 0.7)]),
+    "ㅒ": ([7, 10], [(8, 9, 0.3), (8, 9, 0.7)]),
+    "ㅓ": ([9], [(7, 8, 0.5)]),
+    "ㅔ": ([8, 10], [(6, 7, 0.5)]),
+    "ㅕ": ([9], [(7, 8, 0.3), (7, 8, 0.7)]),
+    "ㅖ": ([8, 10], [(6, 7, 0.3), (6, 7, 0.7)]),
+    "ㅣ": ([9], []),
+}
+# Horizontal vowels: stems above (ㅗ) or below (ㅜ) the long stroke, or none
+HORIZONTAL = {"ㅗ": ("up", [5]), "ㅛ": ("up", [3, 8]), "ㅜ": ("down", [5]), "ㅠ": ("down", [3, 8]), "ㅡ": ("none", [])}
+# Combined vowels: a horizontal part plus a vertical part on the right
+COMBINED = {"ㅘ": ("ㅗ", "ㅏ"), "ㅙ": ("ㅗ", "ㅐ"), "ㅚ": ("ㅗ", "ㅣ"), "ㅝ": ("ㅜ", "ㅓ"), "ㅞ": ("ㅜ", "ㅔ"),
+            "ㅟ": ("ㅜ", "ㅣ"), "ㅢ": ("ㅡ", "ㅣ")}
+
+
+def line(px, x0, y0, x1, y1):
+    dx, dy = abs(x1 - x0), -abs(y1 - y0)
+    sx, sy = (1 if x0 < x1 else -1), (1 if y0 < y1 else -1)
+    err = dx + dy
+    while True:
+        px.add((x0, y0))
+        if x0 == x1 and y0 == y1:
+            return
+        e2 = 2 * err
+        if e2 >= dy:
+            err += dy
+            x0 += sx
+        if e2 <= dx:
+            err += dx
+            y0 += sy
+
+
+def scale(u, lo, hi):
+    """Round from the nearer edge so shapes stay symmetric in odd-sized boxes."""
+    if u <= 0.5:
+        return lo + int(u * (hi - lo) + 0.5)
+    return hi - int((1 - u) * (hi - lo) + 0.5)
+
+
+def consonant(px, jamo, box):
+    x0, y0, x1, y1 = box
+    if jamo in PAIRS:
+        left, right = PAIRS[jamo]
+        mid = (x0 + x1) // 2
+        consonant(px, left, (x0, y0, mid - 1, y1))
+        consonant(px, right, (mid + 1, y0, x1, y1))
+        return
+    if jamo == "ㅇ":
+        # A box with the corners cut, deeper once there is room for it
+        c = 2 if x1 - x0 >= 8 else 1
+        ring = [(x0 + c, y0), (x1 - c, y0), (x1, y0 + c), (x1, y1 - c), (x1 - c, y1), (x0 + c, y1), (x0, y1 - c),
+                (x0, y0 + c), (x0 + c, y0)]
+        for (ax, ay), (bx, by) in zip(ring, ring[1:]):
+            line(px, ax, ay, bx, by)
+        return
+    for stroke in CONSONANTS[jamo]:
+        pts = [(scale(u, x0, x1), scale(v, y0, y1)) for u, v in stroke]
+        for (ax, ay), (bx, by) in zip(pts, pts[1:] or pts):
+            line(px, ax, ay, bx, by)
+
+
+def vertical(px, vowel, top, bottom):
+    bars, stubs = VERTICAL[vowel]
+    for x in bars:
+        line(px, x, top, x, bottom)
+    for first, last, f in stubs:
+        y = top + int(f * (bottom - top) + 0.5)
+        line(px, first, y, last, y)
+
+
+def horizontal(px, vowel, row, left, right, centre, stem):
+    kind, stems = HORIZONTAL[vowel]
+    line(px, left, row, right, row)
+    for x in stems:
+        x = centre if len(stems) == 1 else x
+        if kind == "up":
+            line(px, x, row - stem, x, row - 1)
+        elif kind == "down":
+            line(px, x, row + 1, x, row + stem)
+
+
+def syllable(c):
+    s = ord(c) - 0xAC00
+    lead, vowel, tail = INITIALS[s // 588], VOWELS[s % 588 // 28], FINALS[s % 28].strip()
+    px = set()
+    bottom = 5 if tail else 11
+    if vowel in VERTICAL:
+        right = 5 if VERTICAL[vowel][1] and VERTICAL[vowel][1][0][0] > 6 else 4
+        consonant(px, lead, (0, 0, right, 4) if tail else (0, 1, right, 10))
+        vertical(px, vowel, 0, bottom)
+    elif vowel in HORIZONTAL:
+        kind = HORIZONTAL[vowel][0]
+        # ㄹ needs five rows, so over a final it takes the gap under the vowel
+        tall = tail and lead == "ㄹ"
+        row = (4 if kind == "down" else 5) + tall if tail else (8 if kind == "down" else 9)
+        consonant(px, lead, (1, 0, 10, 3 + tall if tail else row - (3 if kind == "up" else 2)))
+        horizontal(px, vowel, row, 0, 11, 5, 1 if tail else 2)
+    else:
+        top, side = COMBINED[vowel]
+        kind = HORIZONTAL[top][0]
+        row = (4 if kind == "down" else 5) if tail else (7 if kind == "down" else 8)
+        consonant(px, lead, (0, 0, 6, 3 if tail else row - (3 if kind == "up" else 2)))
+        horizontal(px, top, row, 0, 6, 3, 1 if tail else 2)
+        vertical(px, side, 0, bottom)
+    if tail:
+        consonant(px, tail, (1, 7, 10, 11))
+    return [sum(1 << (15 - x) for x in range(SIZE) if (x, y) in px) for y in range(SIZE)]
+
+
+def main():
+    with open(STRINGS_RS, encoding="utf-8") as f:
+        chars = sorted(set(re.findall("[가-힣]", f.read())))
+    rows = []
+    for c in chars:
+        bits = ", ".join("0x{:04X}".format(r) for r in syllable(c))
+        rows.append("    ('{}', [{}]),".format(c, bits))
+    table = "\n".join(
+        ["{}".format(BEGIN), "pub const GLYPHS: [(char, [u16; SIZE]); {}] = [".format(len(rows))] + rows + ["];", END])
+    with open(HANGUL_RS, encoding="utf-8") as f:
+        src = f.read()
+    start, end = src.index(BEGIN), src.index(END) + len(END)
+    with open(HANGUL_RS, "w", encoding="utf-8") as f:
+        f.write(src[:start] + table + src[end:])
+    print("{} syllables".format(len(chars)))
+    if "--show" in sys.argv:
+        for c in chars:
+            print(c)
+            for r in syllable(c):
+                print("".join("#" if r & (1 << (15 - x)) else "." for x in range(SIZE)))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,165 @@
+//! Achievements earned from gameplay events.
+//!
+//! The game posts `Event`s onto a per-frame queue; `Tracker::handle` turns
+//! them into newly earned achievements, reported as a bitmask over
+//! `Achievement as u16`. Earned bits are persisted with the lifetime progress.
+
+use crate::strings::Msg;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Achievement {
+    FirstKill,
+    Combo10,
+    Sniper,
+    BigBomb,
+    Pacifist,
+    Untouchable,
+    LastStand,
+    GiftHunter,
+    CoinPurse,
+    Shopper,
+    Wave3,
+    Score50,
+    Score100,
+    Score200,
+    Veteran,
+    BossSlayer,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 16] = [
+        Self::FirstKill, Self::Combo10, Self::Sniper, Self::BigBomb, Self::Pacifist,
+        Self::Untouchable, Self::LastStand, Self::GiftHunter, Self::CoinPurse, Self::Shopper,
+        Self::Wave3, Self::Score50, Self::Score100, Self::Score200, Self::Veteran,
+        Self::BossSlayer,
+    ];
+
+    pub const fn bit(self) -> u16 { 1 << self as u16 }
+
+    pub fn name(self) -> Msg {
+        match self {
+            Self::FirstKill => Msg::FirstKill,
+            Self::Combo10 => Msg::Combo10,
+            Self::Sniper => Msg::Sniper,
+            Self::BigBomb => Msg::BigBomb,
+            Self::Pacifist => Msg::Pacifist,
+            Self::Untouchable => Msg::Untouchable,
+            Self::LastStand => Msg::LastStand,
+            Self::GiftHunter => Msg::GiftHunter,
+            Self::CoinPurse => Msg::CoinPurse,
+            Self::Shopper => Msg::Shopper,
+            Self::Wave3 => Msg::Wave3,
+            Self::Score50 => Msg::Score50,
+            Self::Score100 => Msg::Score100,
+            Self::Score200 => Msg::Score200,
+            Self::Veteran => Msg::Veteran,
+            Self::BossSlayer => Msg::BossSlayer,
+        }
+    }
+}
+
+/// Something that happened this frame. Also traced over defmt, one line per event.
+#[derive(Clone, Copy, defmt::Format)]
+pub enum Event {
+    /// Obstacle shot down; `high` when it was near the top of the play area.
+    /// `slot` is its obstacle pool index, or `BOSS_SLOT` for a mini-boss
+    Kill { high: bool, slot: u8 },
+    /// One bomb cleared this many obstacles
+    Bomb(u32),
+    Fire,
+    Hit { lives: u8, score: u32 },
+    Gift,
+    Coin { wallet: u32 },
+    Buy,
+    WaveClear { wave: u32 },
+    Score(u32),
+    GameOver { score: u32 },
+    LifetimeKills(u32),
+    BossKill,
+}
+
+/// `Event::Kill::slot` for kills outside the obstacle pool
+pub const BOSS_SLOT: u8 = u8::MAX;
+
+const COMBO_WINDOW_MS: u32 = 2000;
+const COMBO_KILLS: u32 = 10;
+const BIG_BOMB_KILLS: u32 = 4;
+const PACIFIST_SCORE: u32 = 20;
+const LAST_STAND_POINTS: u32 = 30;
+const GIFT_HUNTER_GIFTS: u32 = 5;
+const COIN_PURSE_COINS: u32 = 20;
+const VETERAN_KILLS: u32 = 1000;
+
+pub struct Tracker {
+    /// Already earned, never reported again
+    pub earned: u16,
+    combo: u32,
+    combo_left_ms: u32,
+    fired: bool,
+    hit_this_wave: bool,
+    last_life_from: Option<u32>,
+    gifts: u32,
+}
+
+impl Tracker {
+    pub const fn new(earned: u16) -> Self {
+        Self {
+            earned, combo: 0, combo_left_ms: 0, fired: false, hit_this_wave: false,
+            last_life_from: None, gifts: 0,
+        }
+    }
+
+    /// Forget per-run stats at the start of a game.
+    pub fn start_run(&mut self) { *self = Self::new(self.earned); }
+
+    /// Advance by one frame of `frame_ms`; the combo lapses without kills.
+    pub fn tick(&mut self, frame_ms: u32) {
+        self.combo_left_ms = self.combo_left_ms.saturating_sub(frame_ms);
+        if self.combo_left_ms == 0 { self.combo = 0; }
+    }
+
+    /// Returns the bits of achievements this event newly earned.
+    pub fn handle(&mut self, ev: Event) -> u16 {
+        use Achievement as A;
+        let mut got = 0u16;
+        let mut award = |a: A, cond: bool| if cond { got |= a.bit(); };
+        match ev {
+            Event::Kill { high, .. } => {
+                self.combo += 1;
+                self.combo_left_ms = COMBO_WINDOW_MS;
+                award(A::FirstKill, true);
+                award(A::Combo10, self.combo >= COMBO_KILLS);
+                award(A::Sniper, high);
+            }
+            Event::Bomb(n) => award(A::BigBomb, n >= BIG_BOMB_KILLS),
+            Event::Fire => self.fired = true,
+            Event::Hit { lives, score } => {
+                self.hit_this_wave = true;
+                if lives == 1 { self.last_life_from = Some(score); }
+            }
+            Event::Gift => {
+                self.gifts += 1;
+                award(A::GiftHunter, self.gifts >= GIFT_HUNTER_GIFTS);
+            }
+            Event::Coin { wallet } => award(A::CoinPurse, wallet >= COIN_PURSE_COINS),
+            Event::Buy => award(A::Shopper, true),
+            Event::WaveClear { wave } => {
+                award(A::Untouchable, !self.hit_this_wave);
+                award(A::Wave3, wave >= 3);
+                self.hit_this_wave = false;
+            }
+            Event::Score(s) => {
+                award(A::Score50, s >= 50);
+                award(A::Score100, s >= 100);
+                award(A::Score200, s >= 200);
+                award(A::LastStand, self.last_life_from.is_some_and(|from| s >= from + LAST_STAND_POINTS));
+            }
+            Event::GameOver { score } => award(A::Pacifist, !self.fired && score >= PACIFIST_SCORE),
+            Event::LifetimeKills(k) => award(A::Veteran, k >= VETERAN_KILLS),
+            Event::BossKill => award(A::BossSlayer, true),
+        }
+        let new = got & !self.earned;
+        self.earned |= new;
+        new
+    }
+}
@@ -0,0 +1,238 @@
+//! Commands typed into the USB serial console, for backing up the store and
+//! tuning the log.
+//!
+//! The logger hands incoming bytes to `Commands`, which gathers them into
+//! lines; the game loop picks a finished line up with `take` and runs it
+//! between frames, so the store is never touched from two places. Replies go
+//! out as log lines. `dump` prints one `load` command per stored key, so a
+//! backup is restored by pasting its lines back in. `log` sets the `logs`
+//! channel levels; `overlay` and `hitbox` toggle in-game debug drawing,
+//! `latency` times button presses through to the screen, and `god` and
+//! `spawn` are test cheats that keep the run off the records. `tas` lines
+//! build an input script for `tas::Script` and play it back.
+
+use core::cell::RefCell;
+
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+use embassy_sync::channel::Channel as Queue;
+use embassy_usb_logger::ReceiverHandler;
+
+use crate::kv::{Key, Store, VALUE_MAX};
+use crate::logs::{self, Channel};
+use crate::tas;
+use crate::{GiftKind, ObstacleKind};
+
+/// Longest line: a `load` with a full value in hex
+const LINE_MAX: usize = 16 + 2 * VALUE_MAX;
+const KEYS: [(&str, Key); 3] = [("settings", Key::Settings), ("progress", Key::Progress), ("run", Key::Run)];
+const OBSTACLES: [(&str, ObstacleKind); 7] = [
+    ("falling", ObstacleKind::Falling), ("side", ObstacleKind::Side), ("bouncer", ObstacleKind::Bouncer),
+    ("rock", ObstacleKind::Rock), ("meteor", ObstacleKind::Meteor), ("armored", ObstacleKind::Armored),
+    ("shooter", ObstacleKind::Shooter),
+];
+const GIFTS: [(&str, GiftKind); 9] = [
+    ("bomb", GiftKind::Bomb), ("life", GiftKind::Life), ("freeze", GiftKind::Freeze), ("homing", GiftKind::Homing),
+    ("laser", GiftKind::Laser), ("shield", GiftKind::Shield), ("twin", GiftKind::Twin), ("magnet", GiftKind::Magnet),
+    ("reverse", GiftKind::Reverse),
+];
+
+pub type Line = heapless::Vec<u8, LINE_MAX>;
+
+struct Input {
+    /// Line being typed
+    partial: Line,
+    /// The partial line outgrew `LINE_MAX`; skip to the next newline
+    overflow: bool,
+}
+
+static INPUT: Mutex<CriticalSectionRawMutex, RefCell<Input>> =
+    Mutex::new(RefCell::new(Input { partial: heapless::Vec::new(), overflow: false }));
+/// Finished lines waiting for the game loop
+static READY: Queue<CriticalSectionRawMutex, Line, 2> = Queue::new();
+
+pub struct Commands;
+
+impl ReceiverHandler for Commands {
+    async fn handle_data(&self, data: &[u8]) {
+        for &b in data {
+            let done = INPUT.lock(|input| {
+                let input = &mut *input.borrow_mut();
+                if b != b'\n' && b != b'\r' {
+                    input.overflow |= input.partial.push(b).is_err();
+                    return None;
+                }
+                let line = if input.overflow {
+                    log::warn!("Console line too long");
+                    None
+                } else {
+                    Some(input.partial.clone()).filter(|l| !l.is_empty())
+                };
+                input.partial.clear();
+                input.overflow = false;
+                line
+            });
+            // The loop takes one line a frame; until it catches up, USB holds off the rest of a paste
+            if let Some(line) = done { READY.send(line).await; }
+        }
+    }
+
+    fn new() -> Self { Self }
+}
+
+/// The next finished command line, if one came in.
+pub fn take() -> Option<Line> { READY.try_receive().ok() }
+
+#[derive(PartialEq)]
+pub enum Action {
+    None,
+    Reboot,
+    ToggleOverlay,
+    ToggleHitboxes,
+    ToggleLatency,
+    ToggleGod,
+    /// Drop an entity at column `x`, or above the ship
+    Spawn(Entity, Option<i32>),
+    Tas(tas::Cmd),
+}
+
+#[derive(PartialEq)]
+pub enum Entity {
+    Obstacle(ObstacleKind),
+    Gift(GiftKind),
+}
+
+/// Run one command line against the store.
+pub fn run(line: &[u8], store: &mut Store<'_>) -> Action {
+    let line = core::str::from_utf8(line).unwrap_or("");
+    let mut words = line.split_ascii_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("dump"), None, _) => dump(store),
+        (Some("load"), Some(name), Some(hex)) => load(store, name, hex),
+        (Some("reboot"), None, _) => return Action::Reboot,
+        (Some("overlay"), None, _) => return Action::ToggleOverlay,
+        (Some("hitbox"), None, _) => return Action::ToggleHitboxes,
+        (Some("latency"), None, _) => return Action::ToggleLatency,
+        (Some("god"), None, _) => return Action::ToggleGod,
+        (Some("spawn"), Some(name), x) => return spawn(name, x, words.next()),
+        (Some("tas"), first, second) => return tas(first, second, words.next()),
+        (Some("log"), channel, level) => set_log(channel, level),
+        _ => log::info!("Commands: dump | load <settings|progress|run> <hex> | log [<channel|all> <off|info|debug>] \
+            | overlay | hitbox | latency | god | spawn <kind> [x] | tas [seed <hex> | <frame> <abxy|-> | play | stop] \
+            | reboot"),
+    }
+    Action::None
+}
+
+fn dump(store: &mut Store<'_>) {
+    let mut value = [0u8; VALUE_MAX];
+    for (name, key) in KEYS {
+        let Some(len) = store.get(key, &mut value) else { continue };
+        let mut out = heapless::String::<LINE_MAX>::new();
+        out.push_str("load ").ok();
+        out.push_str(name).ok();
+        out.push(' ').ok();
+        for b in &value[..len] {
+            for nibble in [b >> 4, b & 0xF] { out.push(char::from_digit(nibble as u32, 16).unwrap_or('0')).ok(); }
+        }
+        log::info!("{}", out);
+    }
+    log::info!("dump done");
+}
+
+fn load(store: &mut Store<'_>, name: &str, hex: &str) {
+    let Some(&(_, key)) = KEYS.iter().find(|(n, _)| *n == name) else {
+        log::warn!("Unknown key {}", name);
+        return;
+    };
+    let mut value = [0u8; VALUE_MAX];
+    let Some(len) = decode_hex(hex, &mut value) else {
+        log::warn!("Bad hex for {}", name);
+        return;
+    };
+    match store.set(key, &value[..len]) {
+        Ok(()) => log::info!("Loaded {} ({} bytes); reboot to apply", name, len),
+        Err(e) => log::warn!("Loading {} failed: {:?}", name, e),
+    }
+}
+
+fn spawn(name: &str, x: Option<&str>, extra: Option<&str>) -> Action {
+    let x = match x.map(str::parse) {
+        Some(Ok(x)) if extra.is_none() => Some(x),
+        None => None,
+        _ => {
+            log::warn!("Usage: spawn <kind> [x]");
+            return Action::None;
+        }
+    };
+    let entity = OBSTACLES.iter().find(|(n, _)| *n == name).map(|&(_, k)| Entity::Obstacle(k))
+        .or_else(|| GIFTS.iter().find(|(n, _)| *n == name).map(|&(_, k)| Entity::Gift(k)));
+    match entity {
+        Some(entity) => Action::Spawn(entity, x),
+        None => {
+            log::warn!("Kinds: falling, side, bouncer, rock, meteor, armored, shooter, \
+                bomb, life, freeze, homing, laser, shield, twin, magnet, reverse");
+            Action::None
+        }
+    }
+}
+
+fn tas(first: Option<&str>, second: Option<&str>, extra: Option<&str>) -> Action {
+    let cmd = match (first, second, extra) {
+        (None, _, _) => Some(tas::Cmd::Status),
+        (Some("play"), None, _) => Some(tas::Cmd::Play),
+        (Some("stop"), None, _) => Some(tas::Cmd::Stop),
+        (Some("seed"), Some(hex), None) => u32::from_str_radix(hex, 16).ok().map(tas::Cmd::Seed),
+        (Some(frame), Some(buttons), None) => frame.parse().ok().zip(tas::parse_buttons(buttons))
+            .map(|(f, b)| tas::Cmd::Step(f, b)),
+        _ => None,
+    };
+    match cmd {
+        Some(cmd) => Action::Tas(cmd),
+        None => {
+            log::warn!("Usage: tas seed <hex> | tas <frame> <abxy|-> | tas play | tas stop");
+            Action::None
+        }
+    }
+}
+
+fn set_log(channel: Option<&str>, level: Option<&str>) {
+    if let (Some(channel), Some(level)) = (channel, level) {
+        let level = match level {
+            "off" => log::LevelFilter::Off,
+            "info" => log::LevelFilter::Info,
+            "debug" => log::LevelFilter::Debug,
+            _ => {
+                log::warn!("Levels: off, info, debug");
+                return;
+            }
+        };
+        let mut matched = false;
+        for c in Channel::ALL.into_iter().filter(|c| channel == "all" || channel == c.name()) {
+            logs::set_level(c, level);
+            matched = true;
+        }
+        if !matched {
+            log::warn!("Channels: input, spawn, collide, render, all");
+            return;
+        }
+    }
+    for c in Channel::ALL {
+        let level = match logs::level(c) {
+            log::LevelFilter::Off => "off",
+            log::LevelFilter::Debug | log::LevelFilter::Trace => "debug",
+            _ => "info",
+        };
+        log::info!("log {} {}", c.name(), level);
+    }
+}
+
+fn decode_hex(hex: &str, out: &mut [u8]) -> Option<usize> {
+    let hex = hex.as_bytes();
+    if !hex.len().is_multiple_of(2) || hex.len() / 2 > out.len() { return None; }
+    for (o, pair) in out.iter_mut().zip(hex.chunks(2)) {
+        let digit = |c: u8| (c as char).to_digit(16);
+        *o = (digit(pair[0])? << 4 | digit(pair[1])?) as u8;
+    }
+    Some(hex.len() / 2)
+}
@@ -0,0 +1,113 @@
+//! Game events routed to whatever outputs the board has.
+//!
+//! Every event plays through the buzzer and may also flash the Display
+//! Pack's RGB LED, pulse a vibration motor or blink the onboard LED, which
+//! otherwise stays lit while a game is on. `ROUTES` is the one table that
+//! says which; the options switch each output off separately, and a build
+//! missing a part simply has no pin for it.
+
+use embassy_rp::gpio::Output;
+
+use crate::sound::{Buzzer, Sfx};
+
+/// Output bits, as kept in `Settings::outputs` (the buzzer has `sound`)
+pub const BUZZER: u8 = 1;
+pub const RGB: u8 = 2;
+pub const VIBE: u8 = 4;
+pub const LED: u8 = 8;
+
+/// RGB LED legs, one bit each
+const R: u8 = 1;
+const G: u8 = 2;
+const B: u8 = 4;
+
+/// Outputs besides the buzzer, the RGB color and how long the light, vibration or blink lasts in ms.
+const ROUTES: &[(Sfx, u8, u8, u16)] = &[
+    (Sfx::PlayerHit, RGB | VIBE | LED, R, 150),
+    (Sfx::GameOver, RGB | VIBE | LED, R, 400),
+    (Sfx::Bomb, RGB | VIBE, R | G | B, 120),
+    (Sfx::Heartbeat, VIBE, 0, 40),
+    (Sfx::Pickup, RGB, G, 80),
+    (Sfx::Coin, RGB, R | G, 60),
+    (Sfx::SpeedUp, RGB, G | B, 100),
+    (Sfx::Warning, RGB, R | G, 60),
+    (Sfx::Achievement, RGB | LED, R | B, 200),
+    (Sfx::Fanfare, RGB | LED, G | B, 300),
+];
+
+fn route(sfx: Sfx) -> (u8, u8, u16) {
+    ROUTES.iter().find(|(e, ..)| *e == sfx).map_or((0, 0, 0), |&(_, out, rgb, ms)| (out, rgb, ms))
+}
+
+pub struct Feedback<'d> {
+    buzzer: Buzzer<'d>,
+    /// Red, green and blue legs, driven low to light
+    rgb: [Option<Output<'d>>; 3],
+    vibe: Option<Output<'d>>,
+    led: Output<'d>,
+    /// Onboard LED state between blinks
+    led_on: bool,
+    rgb_ms: i32,
+    vibe_ms: i32,
+    blink_ms: i32,
+    /// Output bits to drive; set every frame, anything switched off stops on the next tick
+    pub enabled: u8,
+}
+
+impl<'d> Feedback<'d> {
+    pub fn new(buzzer: Buzzer<'d>, rgb: [Option<Output<'d>>; 3], vibe: Option<Output<'d>>, led: Output<'d>) -> Self {
+        let mut fb = Self { buzzer, rgb, vibe, led, led_on: false, rgb_ms: 0, vibe_ms: 0, blink_ms: 0, enabled: BUZZER };
+        fb.set_rgb(0);
+        fb
+    }
+
+    pub fn play(&mut self, sfx: Sfx) {
+        self.buzzer.play(sfx);
+        let (out, rgb, ms) = route(sfx);
+        let (out, ms) = (out & self.enabled, ms as i32);
+        if out & RGB != 0 && ms >= self.rgb_ms {
+            self.set_rgb(rgb);
+            self.rgb_ms = ms;
+        }
+        if out & VIBE != 0 {
+            if let Some(v) = &mut self.vibe { v.set_high(); }
+            self.vibe_ms = self.vibe_ms.max(ms);
+        }
+        if out & LED != 0 {
+            self.led.set_level((!self.led_on).into());
+            self.blink_ms = self.blink_ms.max(ms);
+        }
+    }
+
+    /// The onboard LED's steady state, lit while a game is on; it shows from the next tick.
+    pub fn set_led(&mut self, on: bool) { self.led_on = on; }
+
+    fn set_rgb(&mut self, bits: u8) {
+        for (i, leg) in self.rgb.iter_mut().enumerate() {
+            if let Some(leg) = leg { leg.set_level((bits & (1 << i) == 0).into()); }
+        }
+    }
+
+    /// Advance by one frame of `frame_ms`; call every frame.
+    pub fn tick(&mut self, frame_ms: u32) {
+        self.buzzer.enabled = self.enabled & BUZZER != 0;
+        self.buzzer.tick(frame_ms);
+        let ms = frame_ms as i32;
+        if self.rgb_ms > 0 {
+            self.rgb_ms -= ms;
+            if self.rgb_ms <= 0 || self.enabled & RGB == 0 { self.rgb_ms = 0; self.set_rgb(0); }
+        }
+        if self.vibe_ms > 0 {
+            self.vibe_ms -= ms;
+            if self.vibe_ms <= 0 || self.enabled & VIBE == 0 {
+                self.vibe_ms = 0;
+                if let Some(v) = &mut self.vibe { v.set_low(); }
+            }
+        }
+        if self.blink_ms > 0 {
+            self.blink_ms -= ms;
+            if self.blink_ms <= 0 || self.enabled & LED == 0 { self.blink_ms = 0; }
+        }
+        if self.blink_ms <= 0 { self.led.set_level((self.led_on && self.enabled & LED != 0).into()); }
+    }
+}
@@ -0,0 +1,67 @@
+//! MB85RC256 (32 KB) I2C FRAM as a `kv` medium, with the `fram` feature.
+//!
+//! FRAM writes in place with no erase step and no wear to speak of, so
+//! saves survive reflashing even with UF2 tools that wipe the whole chip.
+//! The store's log layout is kept as is: erasing a bank just fills it with
+//! `0xFF`. Any other 24-series FRAM or EEPROM with 16-bit addresses works
+//! if `SIZE` and `PAGE` are set to match.
+
+use embassy_rp::i2c::{self, Blocking, I2c};
+use embassy_rp::peripherals::I2C0;
+
+use crate::kv::{Error, Medium};
+
+/// A0-A2 tied low
+const ADDRESS: u8 = 0x50;
+/// Bytes per write transaction after the 2-byte address; EEPROMs need their page size here
+const PAGE: usize = 32;
+
+pub struct Fram<'d> {
+    i2c: I2c<'d, I2C0, Blocking>,
+}
+
+impl<'d> Fram<'d> {
+    pub const fn new(i2c: I2c<'d, I2C0, Blocking>) -> Self { Self { i2c } }
+
+    /// Fast mode; the chip is good for 1 MHz but jumper wires often aren't
+    pub fn config() -> i2c::Config {
+        let mut config = i2c::Config::default();
+        config.frequency = 400_000;
+        config
+    }
+
+    /// Write `len` bytes from `byte(i)` at `offset`, a page at a time.
+    fn fill(&mut self, offset: u32, len: usize, byte: impl Fn(usize) -> u8) -> Result<(), Error> {
+        let mut buf = [0u8; 2 + PAGE];
+        let mut done = 0;
+        while done < len {
+            let n = PAGE.min(len - done);
+            buf[..2].copy_from_slice(&((offset as usize + done) as u16).to_be_bytes());
+            for (i, b) in buf[2..2 + n].iter_mut().enumerate() { *b = byte(done + i); }
+            self.i2c.blocking_write(ADDRESS, &buf[..2 + n]).map_err(io)?;
+            done += n;
+        }
+        Ok(())
+    }
+}
+
+impl Medium for Fram<'_> {
+    const SIZE: u32 = 32 * 1024;
+
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error> {
+        self.i2c.blocking_write_read(ADDRESS, &(offset as u16).to_be_bytes(), buf).map_err(io)
+    }
+
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Error> {
+        self.fill(offset, data.len(), |i| data[i])
+    }
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Error> {
+        self.fill(from, (to - from) as usize, |_| 0xFF)
+    }
+}
+
+fn io(e: i2c::Error) -> Error {
+    log::warn!("FRAM access failed: {:?}", e);
+    Error::Io
+}
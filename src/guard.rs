@@ -0,0 +1,144 @@
+//! Draw target wrapper that keeps display write errors from halting the game.
+//!
+//! Solid fills are retried on the spot. Pixel and area writes consume their
+//! iterator, so a failed one drops the rest of the frame instead; the game
+//! loop repaints what it caches and carries on. A run of failing frames asks
+//! for a controller re-init, and only a longer one is treated as fatal.
+//! As a `Renderer` it draws straight to the panel, so erases are immediate
+//! fills.
+
+use core::convert::Infallible;
+use core::fmt::Debug;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::render::Renderer;
+
+/// Extra attempts for a solid fill before the frame is dropped
+const FILL_RETRIES: u32 = 2;
+/// Consecutive failing frames before asking for a re-init
+const REINIT_FRAMES: u32 = 10;
+/// Consecutive failing frames (about a second) before giving up
+const FATAL_FRAMES: u32 = 30;
+
+/// The kind of write that failed, shown as the error code.
+#[derive(Clone, Copy)]
+pub enum Op {
+    Pixels = 1,
+    Area = 2,
+    Fill = 3,
+    Clear = 4,
+}
+
+/// How the frame's writes went, from `Renderer::end_frame`.
+pub enum Frame {
+    Drawn,
+    /// A write failed and the rest of the frame was skipped
+    Dropped,
+    /// Like `Dropped`, but writes have failed long enough to re-init the controller
+    Stuck,
+    /// Writes kept failing; carries the code of the last failed write
+    Fatal(u8),
+}
+
+pub struct Guarded<D> {
+    inner: D,
+    /// First failed write this frame; later writes are skipped until `end_frame`
+    fault: Option<Op>,
+    /// Consecutive frames with a failed write
+    streak: u32,
+}
+
+impl<D: DrawTarget<Color = Rgb565>> Guarded<D>
+where
+    D::Error: Debug,
+{
+    pub const fn new(inner: D) -> Self { Self { inner, fault: None, streak: 0 } }
+
+    /// The wrapped display, for controller commands outside drawing.
+    pub fn inner_mut(&mut self) -> &mut D { &mut self.inner }
+
+    /// Swap in a rebuilt display, keeping the failure streak so a re-init
+    /// that doesn't help still ends in `Frame::Fatal`.
+    pub fn rebuild(self, f: impl FnOnce(D) -> D) -> Self {
+        Self { inner: f(self.inner), fault: None, streak: self.streak }
+    }
+
+    fn fail(&mut self, op: Op, e: D::Error) {
+        crate::logs::clog!(Render, Warn, "Display write failed ({}): {:?}", op as u8, e);
+        self.fault = Some(op);
+    }
+
+    fn retry(&mut self, op: Op, mut write: impl FnMut(&mut D) -> Result<(), D::Error>) {
+        if self.fault.is_some() { return; }
+        for attempt in 0..=FILL_RETRIES {
+            match write(&mut self.inner) {
+                Ok(()) => return,
+                Err(e) if attempt == FILL_RETRIES => self.fail(op, e),
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+impl<D: Dimensions> Dimensions for Guarded<D> {
+    fn bounding_box(&self) -> Rectangle { self.inner.bounding_box() }
+}
+
+impl<D: DrawTarget<Color = Rgb565>> DrawTarget for Guarded<D>
+where
+    D::Error: Debug,
+{
+    type Color = Rgb565;
+    type Error = Infallible;
+
+    fn draw_iter<I: IntoIterator<Item = Pixel<Rgb565>>>(&mut self, pixels: I) -> Result<(), Infallible> {
+        if self.fault.is_none() && let Err(e) = self.inner.draw_iter(pixels) { self.fail(Op::Pixels, e); }
+        Ok(())
+    }
+
+    fn fill_contiguous<I: IntoIterator<Item = Rgb565>>(&mut self, area: &Rectangle, colors: I) -> Result<(), Infallible> {
+        if self.fault.is_none() && let Err(e) = self.inner.fill_contiguous(area, colors) { self.fail(Op::Area, e); }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Rgb565) -> Result<(), Infallible> {
+        self.retry(Op::Fill, |d| d.fill_solid(area, color));
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Rgb565) -> Result<(), Infallible> {
+        self.retry(Op::Clear, |d| d.clear(color));
+        Ok(())
+    }
+}
+
+impl<D: DrawTarget<Color = Rgb565>> Renderer for Guarded<D>
+where
+    D::Error: Debug,
+{
+    fn begin_frame(&mut self) {}
+
+    fn erase(&mut self, region: Rectangle) {
+        // A full-screen erase goes out as a clear, which keeps its own error code
+        if region == self.bounding_box() {
+            self.retry(Op::Clear, |d| d.clear(Rgb565::BLACK));
+        } else {
+            self.retry(Op::Fill, |d| d.fill_solid(&region, Rgb565::BLACK));
+        }
+    }
+
+    fn end_frame(&mut self) -> Frame {
+        let Some(op) = self.fault.take() else {
+            self.streak = 0;
+            return Frame::Drawn;
+        };
+        self.streak += 1;
+        match self.streak {
+            REINIT_FRAMES => Frame::Stuck,
+            FATAL_FRAMES.. => Frame::Fatal(op as u8),
+            _ => Frame::Dropped,
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Bitmap Hangul syllables for the Korean UI strings.
+//!
+//! Only the syllables that appear in `strings` are bundled, as 12x12 glyphs
+//! with one u16 per row, leftmost pixel in the high bit. The table is
+//! generated by `hangul_font.py` and sorted so lookups can binary search.
+
+pub const SIZE: usize = 12;
+
+/// Glyph rows for a syllable, or `None` for anything not in the table.
+pub fn glyph(c: char) -> Option<&'static [u16; SIZE]> {
+    GLYPHS.binary_search_by_key(&c, |g| g.0).ok().map(|i| &GLYPHS[i].1)
+}
+
+// BEGIN GENERATED GLYPHS
+pub const GLYPHS: [(char, [u16; SIZE]); 202] = [
+    ('가', [0x0080, 0xFC80, 0x0480, 0x0480, 0x0480, 0x0480, 0x04E0, 0x0480, 0x0480, 0x0480, 0x0480, 0x0080]),
+    ('간', [0xFC80, 0x0480, 0x0480, 0x04E0, 0x0480, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('감', [0xFC80, 0x0480, 0x0480, 0x04E0, 0x0480, 0x0080, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('갑', [0xFC80, 0x0480, 0x0480, 0x04E0, 0x0480, 0x0080, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('개', [0x0120, 0xFD20, 0x0520, 0x0520, 0x0520, 0x0520, 0x05E0, 0x0520, 0x0520, 0x0520, 0x0520, 0x0120]),
+    ('건', [0xFC40, 0x0440, 0x0440, 0x05C0, 0x0440, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('게', [0x00A0, 0xF8A0, 0x08A0, 0x08A0, 0x08A0, 0x08A0, 0x0BA0, 0x08A0, 0x08A0, 0x08A0, 0x08A0, 0x00A0]),
+    ('격', [0xFC40, 0x0440, 0x05C0, 0x0440, 0x05C0, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('결', [0xFC40, 0x0440, 0x05C0, 0x0440, 0x05C0, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('경', [0xFC40, 0x0440, 0x05C0, 0x0440, 0x05C0, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('계', [0x00A0, 0xF8A0, 0x08A0, 0x0BA0, 0x08A0, 0x08A0, 0x08A0, 0x08A0, 0x0BA0, 0x08A0, 0x08A0, 0x00A0]),
+    ('고', [0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('골', [0x7FE0, 0x0020, 0x0020, 0x0020, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('과', [0xFE80, 0x0280, 0x0280, 0x0280, 0x0280, 0x0280, 0x10E0, 0x1080, 0xFE80, 0x0080, 0x0080, 0x0080]),
+    ('괴', [0xFE40, 0x0240, 0x0240, 0x0240, 0x0240, 0x0240, 0x1040, 0x1040, 0xFE40, 0x0040, 0x0040, 0x0040]),
+    ('구', [0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('국', [0x7FE0, 0x0020, 0x0020, 0x0020, 0xFFF0, 0x0400, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('균', [0x7FE0, 0x0020, 0x0020, 0x0020, 0xFFF0, 0x1080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('금', [0x7FE0, 0x0020, 0x0020, 0x0020, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('기', [0x0040, 0xF840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0040]),
+    ('길', [0xF840, 0x0840, 0x0840, 0x0840, 0x0840, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('꺼', [0x0040, 0xDC40, 0x4440, 0x4440, 0x4440, 0x4440, 0x45C0, 0x4440, 0x4440, 0x4440, 0x4440, 0x0040]),
+    ('꾼', [0x7BE0, 0x0820, 0x0820, 0x0820, 0xFFF0, 0x0400, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('나', [0x0080, 0x8080, 0x8080, 0x8080, 0x8080, 0x8080, 0x80E0, 0x8080, 0x8080, 0x8080, 0xFC80, 0x0080]),
+    ('내', [0x0120, 0x8120, 0x8120, 0x8120, 0x8120, 0x8120, 0x81E0, 0x8120, 0x8120, 0x8120, 0xFD20, 0x0120]),
+    ('냉', [0x8120, 0x8120, 0x8120, 0x81E0, 0xFD20, 0x0120, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('냥', [0x8080, 0x8080, 0x80E0, 0x8080, 0xFCE0, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('너', [0x0040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x81C0, 0x8040, 0x8040, 0x8040, 0xFC40, 0x0040]),
+    ('노', [0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('녹', [0x4000, 0x4000, 0x4000, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('누', [0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('눌', [0x4000, 0x4000, 0x4000, 0x7FE0, 0xFFF0, 0x0400, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('느', [0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('니', [0x0040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0xF840, 0x0040]),
+    ('닉', [0x8040, 0x8040, 0x8040, 0x8040, 0xF840, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('님', [0x8040, 0x8040, 0x8040, 0x8040, 0xF840, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('다', [0x0080, 0xFC80, 0x8080, 0x8080, 0x8080, 0x8080, 0x80E0, 0x8080, 0x8080, 0x8080, 0xFC80, 0x0080]),
+    ('단', [0xFC80, 0x8080, 0x8080, 0x80E0, 0xFC80, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('달', [0xFC80, 0x8080, 0x8080, 0x80E0, 0xFC80, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('대', [0x0120, 0xFD20, 0x8120, 0x8120, 0x8120, 0x8120, 0x81E0, 0x8120, 0x8120, 0x8120, 0xFD20, 0x0120]),
+    ('데', [0x00A0, 0xF8A0, 0x80A0, 0x80A0, 0x80A0, 0x80A0, 0x83A0, 0x80A0, 0x80A0, 0x80A0, 0xF8A0, 0x00A0]),
+    ('도', [0x7FE0, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('독', [0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('동', [0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('된', [0xFE40, 0x8040, 0x8040, 0xFE40, 0x1040, 0xFE40, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('됨', [0xFE40, 0x8040, 0x8040, 0xFE40, 0x1040, 0xFE40, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('됩', [0xFE40, 0x8040, 0x8040, 0xFE40, 0x1040, 0xFE40, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('뒤', [0xFE40, 0x8040, 0x8040, 0x8040, 0x8040, 0xFE40, 0x0040, 0xFE40, 0x1040, 0x1040, 0x0040, 0x0040]),
+    ('드', [0x7FE0, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('득', [0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('디', [0x0040, 0xF840, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0x8040, 0xF840, 0x0040]),
+    ('뛰', [0xEE40, 0x8840, 0x8840, 0x8840, 0x8840, 0xEE40, 0x0040, 0xFE40, 0x1040, 0x1040, 0x0040, 0x0040]),
+    ('라', [0x0080, 0xFC80, 0x0480, 0x0480, 0x0480, 0x0480, 0xFCE0, 0x8080, 0x8080, 0x8080, 0xFC80, 0x0080]),
+    ('랑', [0xFC80, 0x0480, 0xFC80, 0x80E0, 0xFC80, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('래', [0x0120, 0xFD20, 0x0520, 0x0520, 0x0520, 0x0520, 0xFDE0, 0x8120, 0x8120, 0x8120, 0xFD20, 0x0120]),
+    ('러', [0x0040, 0xFC40, 0x0440, 0x0440, 0x0440, 0x0440, 0xFDC0, 0x8040, 0x8040, 0x8040, 0xFC40, 0x0040]),
+    ('레', [0x00A0, 0xF8A0, 0x08A0, 0x08A0, 0x08A0, 0x08A0, 0xFBA0, 0x80A0, 0x80A0, 0x80A0, 0xF8A0, 0x00A0]),
+    ('로', [0x7FE0, 0x0020, 0x0020, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('록', [0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0, 0x0400, 0xFFF0, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('료', [0x7FE0, 0x0020, 0x0020, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x1080, 0x1080, 0xFFF0, 0x0000, 0x0000]),
+    ('루', [0x7FE0, 0x0020, 0x0020, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('류', [0x7FE0, 0x0020, 0x0020, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x1080, 0x1080, 0x0000]),
+    ('르', [0x7FE0, 0x0020, 0x0020, 0x0020, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('른', [0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('름', [0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('리', [0x0040, 0xF840, 0x0840, 0x0840, 0x0840, 0x0840, 0xF840, 0x8040, 0x8040, 0x8040, 0xF840, 0x0040]),
+    ('림', [0xF840, 0x0840, 0xF840, 0x8040, 0xF840, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('마', [0x0080, 0xFC80, 0x8480, 0x8480, 0x8480, 0x8480, 0x84E0, 0x8480, 0x8480, 0x8480, 0xFC80, 0x0080]),
+    ('막', [0xFC80, 0x8480, 0x8480, 0x84E0, 0xFC80, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('매', [0x0120, 0xFD20, 0x8520, 0x8520, 0x8520, 0x8520, 0x85E0, 0x8520, 0x8520, 0x8520, 0xFD20, 0x0120]),
+    ('면', [0xFC40, 0x8440, 0x85C0, 0x8440, 0xFDC0, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('명', [0xFC40, 0x8440, 0x85C0, 0x8440, 0xFDC0, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('모', [0x7FE0, 0x4020, 0x4020, 0x4020, 0x4020, 0x4020, 0x7FE0, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('무', [0x7FE0, 0x4020, 0x4020, 0x4020, 0x4020, 0x4020, 0x7FE0, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('물', [0x7FE0, 0x4020, 0x4020, 0x7FE0, 0xFFF0, 0x0400, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('반', [0x8480, 0x8480, 0xFC80, 0x84E0, 0xFC80, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('발', [0x8480, 0x8480, 0xFC80, 0x84E0, 0xFC80, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('버', [0x0040, 0x8440, 0x8440, 0x8440, 0x8440, 0x8440, 0xFDC0, 0x8440, 0x8440, 0x8440, 0xFC40, 0x0040]),
+    ('베', [0x00A0, 0x88A0, 0x88A0, 0x88A0, 0x88A0, 0x88A0, 0xFBA0, 0x88A0, 0x88A0, 0x88A0, 0xF8A0, 0x00A0]),
+    ('벽', [0x8440, 0x8440, 0xFDC0, 0x8440, 0xFDC0, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('변', [0x8440, 0x8440, 0xFDC0, 0x8440, 0xFDC0, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('보', [0x4020, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x4020, 0x7FE0, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('복', [0x4020, 0x4020, 0x7FE0, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('부', [0x4020, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x4020, 0x7FE0, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('브', [0x4020, 0x4020, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x4020, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('블', [0x4020, 0x4020, 0x7FE0, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('비', [0x0040, 0x8840, 0x8840, 0x8840, 0x8840, 0x8840, 0xF840, 0x8840, 0x8840, 0x8840, 0xF840, 0x0040]),
+    ('빠', [0x0080, 0xD480, 0xD480, 0xD480, 0xD480, 0xD480, 0xDCE0, 0xD480, 0xD480, 0xD480, 0xDC80, 0x0080]),
+    ('사', [0x0080, 0x1080, 0x1080, 0x3080, 0x2880, 0x2880, 0x48E0, 0x4880, 0x4480, 0x8480, 0x8480, 0x0080]),
+    ('삭', [0x1080, 0x2880, 0x4880, 0x44E0, 0x8480, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('살', [0x1080, 0x2880, 0x4880, 0x44E0, 0x8480, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('상', [0x1080, 0x2880, 0x4880, 0x44E0, 0x8480, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('색', [0x1120, 0x2920, 0x4920, 0x45E0, 0x8520, 0x0120, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('생', [0x1120, 0x2920, 0x4920, 0x45E0, 0x8520, 0x0120, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('서', [0x0040, 0x1040, 0x1040, 0x3040, 0x2840, 0x2840, 0x49C0, 0x4840, 0x4440, 0x8440, 0x8440, 0x0040]),
+    ('선', [0x1040, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('설', [0x1040, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('성', [0x1040, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('세', [0x00A0, 0x20A0, 0x20A0, 0x20A0, 0x50A0, 0x50A0, 0x53A0, 0x50A0, 0x88A0, 0x88A0, 0x88A0, 0x00A0]),
+    ('셔', [0x0040, 0x1040, 0x1040, 0x31C0, 0x2840, 0x2840, 0x4840, 0x4840, 0x45C0, 0x8440, 0x8440, 0x0040]),
+    ('소', [0x0200, 0x0500, 0x0900, 0x1080, 0x1040, 0x2040, 0x4020, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('속', [0x0200, 0x0D00, 0x30C0, 0x4020, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('손', [0x0200, 0x0D00, 0x30C0, 0x4020, 0x0400, 0xFFF0, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('수', [0x0200, 0x0500, 0x0900, 0x1080, 0x1040, 0x2040, 0x4020, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('스', [0x0200, 0x0500, 0x0500, 0x0880, 0x1080, 0x2040, 0x2040, 0x4020, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('습', [0x0200, 0x0D00, 0x30C0, 0x4020, 0x0000, 0xFFF0, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('승', [0x0200, 0x0D00, 0x30C0, 0x4020, 0x0000, 0xFFF0, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('시', [0x0040, 0x2040, 0x2040, 0x2040, 0x5040, 0x5040, 0x5040, 0x5040, 0x8840, 0x8840, 0x8840, 0x0040]),
+    ('식', [0x2040, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('실', [0x2040, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('쌍', [0x4880, 0x4880, 0xD480, 0xD4E0, 0xD480, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('쏘', [0x1080, 0x1080, 0x3140, 0x2940, 0x2940, 0x4A20, 0x4A20, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('아', [0x0080, 0x7880, 0x8480, 0x8480, 0x8480, 0x8480, 0x84E0, 0x8480, 0x8480, 0x8480, 0x7880, 0x0080]),
+    ('안', [0x7880, 0x8480, 0x8480, 0x84E0, 0x7880, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('애', [0x0120, 0x7920, 0x8520, 0x8520, 0x8520, 0x8520, 0x85E0, 0x8520, 0x8520, 0x8520, 0x7920, 0x0120]),
+    ('어', [0x0040, 0x7840, 0x8440, 0x8440, 0x8440, 0x8440, 0x85C0, 0x8440, 0x8440, 0x8440, 0x7840, 0x0040]),
+    ('언', [0x7840, 0x8440, 0x8440, 0x85C0, 0x7840, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('업', [0x7840, 0x8440, 0x8440, 0x85C0, 0x7840, 0x0040, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('에', [0x00A0, 0x70A0, 0x88A0, 0x88A0, 0x88A0, 0x88A0, 0x8BA0, 0x88A0, 0x88A0, 0x88A0, 0x70A0, 0x00A0]),
+    ('연', [0x7840, 0x8440, 0x85C0, 0x8440, 0x79C0, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('열', [0x7840, 0x8440, 0x85C0, 0x8440, 0x79C0, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('오', [0x1F80, 0x2040, 0x4020, 0x4020, 0x4020, 0x2040, 0x1F80, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('온', [0x1F80, 0x6060, 0x6060, 0x1F80, 0x0400, 0xFFF0, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('와', [0x7C80, 0x8280, 0x8280, 0x8280, 0x8280, 0x7C80, 0x10E0, 0x1080, 0xFE80, 0x0080, 0x0080, 0x0080]),
+    ('완', [0x7C80, 0x8280, 0x8280, 0x7CE0, 0x1080, 0xFE80, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('왼', [0x7C40, 0x8240, 0x8240, 0x7C40, 0x1040, 0xFE40, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('요', [0x1F80, 0x2040, 0x4020, 0x4020, 0x4020, 0x2040, 0x1F80, 0x1080, 0x1080, 0xFFF0, 0x0000, 0x0000]),
+    ('우', [0x1F80, 0x2040, 0x4020, 0x4020, 0x4020, 0x2040, 0x1F80, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('운', [0x1F80, 0x6060, 0x6060, 0x1F80, 0xFFF0, 0x0400, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('워', [0x7C40, 0x8240, 0x8240, 0x8240, 0x8240, 0x7C40, 0x01C0, 0xFE40, 0x1040, 0x1040, 0x0040, 0x0040]),
+    ('원', [0x7C40, 0x8240, 0x8240, 0x7DC0, 0xFE40, 0x1040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('웨', [0x7CA0, 0x82A0, 0x82A0, 0x82A0, 0x82A0, 0x7CA0, 0x03A0, 0xFEA0, 0x10A0, 0x10A0, 0x00A0, 0x00A0]),
+    ('위', [0x7C40, 0x8240, 0x8240, 0x8240, 0x8240, 0x7C40, 0x0040, 0xFE40, 0x1040, 0x1040, 0x0040, 0x0040]),
+    ('을', [0x1F80, 0x6060, 0x6060, 0x1F80, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('음', [0x1F80, 0x6060, 0x6060, 0x1F80, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('의', [0x7C40, 0x8240, 0x8240, 0x8240, 0x8240, 0x8240, 0x7C40, 0x0040, 0xFE40, 0x0040, 0x0040, 0x0040]),
+    ('이', [0x0040, 0x7040, 0x8840, 0x8840, 0x8840, 0x8840, 0x8840, 0x8840, 0x8840, 0x8840, 0x7040, 0x0040]),
+    ('인', [0x7040, 0x8840, 0x8840, 0x8840, 0x7040, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('일', [0x7040, 0x8840, 0x8840, 0x8840, 0x7040, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('임', [0x7040, 0x8840, 0x8840, 0x8840, 0x7040, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('자', [0x0080, 0xFC80, 0x1080, 0x3080, 0x2880, 0x2880, 0x48E0, 0x4880, 0x4480, 0x8480, 0x8480, 0x0080]),
+    ('작', [0xFC80, 0x2880, 0x4880, 0x44E0, 0x8480, 0x0080, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('장', [0xFC80, 0x2880, 0x4880, 0x44E0, 0x8480, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('저', [0x0040, 0xFC40, 0x1040, 0x3040, 0x2840, 0x2840, 0x49C0, 0x4840, 0x4440, 0x8440, 0x8440, 0x0040]),
+    ('적', [0xFC40, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('전', [0xFC40, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('점', [0xFC40, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('정', [0xFC40, 0x2840, 0x4840, 0x45C0, 0x8440, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('제', [0x00A0, 0xF8A0, 0x20A0, 0x20A0, 0x50A0, 0x50A0, 0x53A0, 0x50A0, 0x88A0, 0x88A0, 0x88A0, 0x00A0]),
+    ('존', [0x7FE0, 0x0D00, 0x30C0, 0x4020, 0x0400, 0xFFF0, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('좁', [0x7FE0, 0x0D00, 0x30C0, 0x4020, 0x0400, 0xFFF0, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('좌', [0xFE80, 0x2880, 0x2880, 0x4480, 0x4480, 0x8280, 0x10E0, 0x1080, 0xFE80, 0x0080, 0x0080, 0x0080]),
+    ('주', [0x7FE0, 0x0500, 0x0900, 0x1080, 0x1040, 0x2040, 0x4020, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('준', [0x7FE0, 0x0D00, 0x30C0, 0x4020, 0xFFF0, 0x0400, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('줍', [0x7FE0, 0x0D00, 0x30C0, 0x4020, 0xFFF0, 0x0400, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('중', [0x7FE0, 0x0D00, 0x30C0, 0x4020, 0xFFF0, 0x0400, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('즈', [0x7FE0, 0x0500, 0x0500, 0x0880, 0x1080, 0x2040, 0x2040, 0x4020, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('지', [0x0040, 0xF840, 0x2040, 0x2040, 0x5040, 0x5040, 0x5040, 0x5040, 0x8840, 0x8840, 0x8840, 0x0040]),
+    ('진', [0xF840, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('짐', [0xF840, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('집', [0xF840, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('쪽', [0x7BE0, 0x3140, 0x2940, 0x4A20, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('첫', [0x1040, 0xFC40, 0x2840, 0x49C0, 0x8440, 0x0040, 0x0000, 0x0200, 0x0500, 0x1880, 0x2040, 0x4020]),
+    ('체', [0x00A0, 0x20A0, 0x00A0, 0xF8A0, 0x20A0, 0x50A0, 0x53A0, 0x50A0, 0x50A0, 0x88A0, 0x88A0, 0x00A0]),
+    ('초', [0x0200, 0x0000, 0x7FE0, 0x0500, 0x1880, 0x2040, 0x4020, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('최', [0x1040, 0xFE40, 0x2840, 0x4440, 0x4440, 0x8240, 0x1040, 0x1040, 0xFE40, 0x0040, 0x0040, 0x0040]),
+    ('추', [0x0200, 0x0000, 0x7FE0, 0x0500, 0x1880, 0x2040, 0x4020, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('취', [0x1040, 0xFE40, 0x2840, 0x4440, 0x4440, 0x8240, 0x0040, 0xFE40, 0x1040, 0x1040, 0x0040, 0x0040]),
+    ('치', [0x0040, 0x2040, 0x0040, 0xF840, 0x2040, 0x5040, 0x5040, 0x5040, 0x5040, 0x8840, 0x8840, 0x0040]),
+    ('칸', [0xFC80, 0x0480, 0xFC80, 0x04E0, 0x0480, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('켜', [0x0040, 0xFC40, 0x0440, 0x05C0, 0x0440, 0x0440, 0xFC40, 0x0440, 0x05C0, 0x0440, 0x0440, 0x0040]),
+    ('코', [0x7FE0, 0x0020, 0x0020, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0400, 0x0400, 0xFFF0, 0x0000, 0x0000]),
+    ('클', [0x7FE0, 0x0020, 0x7FE0, 0x0020, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('탄', [0xFC80, 0x8080, 0xFC80, 0x80E0, 0xFC80, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('태', [0x0120, 0xFD20, 0x8120, 0x8120, 0x8120, 0x8120, 0xFDE0, 0x8120, 0x8120, 0x8120, 0xFD20, 0x0120]),
+    ('택', [0xFD20, 0x8120, 0xFD20, 0x81E0, 0xFD20, 0x0120, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('터', [0x0040, 0xFC40, 0x8040, 0x8040, 0x8040, 0x8040, 0xFDC0, 0x8040, 0x8040, 0x8040, 0xFC40, 0x0040]),
+    ('테', [0x00A0, 0xF8A0, 0x80A0, 0x80A0, 0x80A0, 0x80A0, 0xFBA0, 0x80A0, 0x80A0, 0x80A0, 0xF8A0, 0x00A0]),
+    ('텔', [0xF8A0, 0x80A0, 0xF8A0, 0x83A0, 0xF8A0, 0x00A0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('통', [0x7FE0, 0x4000, 0x7FE0, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('트', [0x7FE0, 0x4000, 0x4000, 0x4000, 0x7FE0, 0x4000, 0x4000, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('튼', [0x7FE0, 0x4000, 0x7FE0, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('팀', [0xF840, 0x8040, 0xF840, 0x8040, 0xF840, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+    ('파', [0x0080, 0xFC80, 0x3080, 0x3080, 0x3080, 0x3080, 0x30E0, 0x3080, 0x3080, 0x3080, 0xFC80, 0x0080]),
+    ('패', [0x0120, 0xFD20, 0x3120, 0x3120, 0x3120, 0x3120, 0x31E0, 0x3120, 0x3120, 0x3120, 0xFD20, 0x0120]),
+    ('평', [0xFC40, 0x3040, 0x31C0, 0x3040, 0xFDC0, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('폭', [0x7FE0, 0x0900, 0x0900, 0x7FE0, 0x0400, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('프', [0x7FE0, 0x0900, 0x0900, 0x0900, 0x0900, 0x0900, 0x0900, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x0000]),
+    ('플', [0x7FE0, 0x0900, 0x0900, 0x7FE0, 0x0000, 0xFFF0, 0x0000, 0x7FE0, 0x0020, 0x7FE0, 0x4000, 0x7FE0]),
+    ('피', [0x0040, 0xF840, 0x5040, 0x5040, 0x5040, 0x5040, 0x5040, 0x5040, 0x5040, 0x5040, 0xF840, 0x0040]),
+    ('하', [0x0080, 0x1080, 0x0080, 0xFC80, 0x0080, 0x3080, 0x48E0, 0x4880, 0x4880, 0x4880, 0x3080, 0x0080]),
+    ('한', [0x1080, 0xFC80, 0x7880, 0x48E0, 0x3080, 0x0080, 0x0000, 0x4000, 0x4000, 0x4000, 0x4000, 0x7FE0]),
+    ('합', [0x1080, 0xFC80, 0x7880, 0x48E0, 0x3080, 0x0080, 0x0000, 0x4020, 0x4020, 0x7FE0, 0x4020, 0x7FE0]),
+    ('항', [0x1080, 0xFC80, 0x7880, 0x48E0, 0x3080, 0x0080, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('해', [0x0120, 0x1120, 0x0120, 0xFD20, 0x0120, 0x3120, 0x49E0, 0x4920, 0x4920, 0x4920, 0x3120, 0x0120]),
+    ('행', [0x1120, 0xFD20, 0x7920, 0x49E0, 0x3120, 0x0120, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('혀', [0x0040, 0x1040, 0x0040, 0xFDC0, 0x0040, 0x3040, 0x4840, 0x4840, 0x49C0, 0x4840, 0x3040, 0x0040]),
+    ('형', [0x1040, 0xFC40, 0x79C0, 0x4840, 0x31C0, 0x0040, 0x0000, 0x1F80, 0x2040, 0x4020, 0x2040, 0x1F80]),
+    ('화', [0x1080, 0xFE80, 0x3880, 0x4480, 0x4480, 0x3880, 0x10E0, 0x1080, 0xFE80, 0x0080, 0x0080, 0x0080]),
+    ('획', [0x1040, 0xFE40, 0x4440, 0x7C40, 0x1040, 0xFE40, 0x0000, 0x7FE0, 0x0020, 0x0020, 0x0020, 0x0020]),
+    ('후', [0x0200, 0x7FE0, 0x0F00, 0x1080, 0x1080, 0x1080, 0x0F00, 0x0000, 0xFFF0, 0x0400, 0x0400, 0x0000]),
+    ('힘', [0x2040, 0xF840, 0x7040, 0x5040, 0x7040, 0x0040, 0x0000, 0x7FE0, 0x4020, 0x4020, 0x4020, 0x7FE0]),
+];
+// END GENERATED GLYPHS
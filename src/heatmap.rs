@@ -0,0 +1,55 @@
+//! Where obstacles end up during a run, for tuning difficulty from real play.
+//!
+//! The playfield is cut into a coarse grid. An obstacle destroyed by the
+//! ship, a bomb, the barrier or a freeze counts in the cell under its
+//! center; one that gets past the bottom counts in its column. At game over
+//! `dump` prints both over the serial log as comma-separated rows, top row
+//! first, so they paste straight into a spreadsheet.
+
+use core::fmt::Write;
+
+use crate::{HUD_H, SCREEN_H, SCREEN_W};
+
+pub const COLS: usize = 8;
+pub const ROWS: usize = 4;
+
+pub struct Heatmap {
+    kills: [[u16; COLS]; ROWS],
+    leaks: [u16; COLS],
+}
+
+impl Heatmap {
+    pub const fn new() -> Self { Self { kills: [[0; COLS]; ROWS], leaks: [0; COLS] } }
+
+    pub fn clear(&mut self) { *self = Self::new(); }
+
+    /// An obstacle centered at (`x`, `y`) was destroyed.
+    pub fn kill(&mut self, x: i32, y: i32) {
+        let row = ((y - HUD_H).max(0) * ROWS as i32 / (SCREEN_H - HUD_H)) as usize;
+        let cell = &mut self.kills[row.min(ROWS - 1)][col(x)];
+        *cell = cell.saturating_add(1);
+    }
+
+    /// An obstacle centered on `x` got past the bottom.
+    pub fn leak(&mut self, x: i32) {
+        let cell = &mut self.leaks[col(x)];
+        *cell = cell.saturating_add(1);
+    }
+
+    pub fn dump(&self) {
+        log::info!("Heatmap: kills by cell, {} columns x {} rows, top first", COLS, ROWS);
+        for row in &self.kills { log::info!("{}", csv(row)); }
+        log::info!("Heatmap: leaks by column");
+        log::info!("{}", csv(&self.leaks));
+    }
+}
+
+fn col(x: i32) -> usize { (x.clamp(0, SCREEN_W - 1) * COLS as i32 / SCREEN_W) as usize }
+
+fn csv(counts: &[u16; COLS]) -> heapless::String<{ COLS * 6 }> {
+    let mut out = heapless::String::new();
+    for (i, n) in counts.iter().enumerate() {
+        write!(out, "{}{}", if i > 0 { "," } else { "" }, n).ok();
+    }
+    out
+}
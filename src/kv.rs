@@ -0,0 +1,258 @@
+//! Wear-leveled key-value store in the flash area reserved by memory.x, or
+//! on an external I2C FRAM with the `fram` feature (see `fram`).
+//!
+//! The area is split into two banks (two sectors each on flash). The active bank is an
+//! append-only log: each `set` writes a new entry (key, length, value and a
+//! CRC-16) after the last one, and the newest entry for a key wins. When a
+//! bank fills up, the live values are copied into the other bank, which takes
+//! over, so erases alternate between the banks instead of hitting one sector
+//! on every save.
+//!
+//! A bank's header (magic and generation) is written after its entries, so a
+//! power cut during compaction leaves the old bank in charge. An entry cut
+//! short fails its CRC and ends the log; the next write compacts past it.
+
+use embassy_rp::flash::{self, Blocking, Flash, ERASE_SIZE};
+use embassy_rp::peripherals::FLASH;
+
+pub const FLASH_SIZE: usize = 2 * 1024 * 1024;
+/// Must match the gap memory.x leaves at the top of flash
+pub const STORAGE_SIZE: usize = 4 * ERASE_SIZE;
+const STORAGE_OFFSET: u32 = (FLASH_SIZE - STORAGE_SIZE) as u32;
+
+const MAGIC: [u8; 4] = *b"DKV1";
+const BANK_HEADER_LEN: u32 = 4 + 4;
+const ENTRY_HEADER_LEN: usize = 1 + 2;
+const CRC_LEN: usize = 2;
+/// Largest value a key can hold
+pub const VALUE_MAX: usize = 512;
+const ENTRY_MAX: usize = ENTRY_HEADER_LEN + VALUE_MAX + CRC_LEN;
+/// Erased flash; a key byte of this marks the end of the log
+const BLANK: u8 = 0xFF;
+
+pub type StorageFlash<'d> = Flash<'d, FLASH, Blocking, FLASH_SIZE>;
+
+#[cfg(not(feature = "fram"))]
+pub type Backend<'d> = StorageFlash<'d>;
+#[cfg(feature = "fram")]
+pub type Backend<'d> = crate::fram::Fram<'d>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The medium reported a failed access
+    Io,
+    /// Value longer than `VALUE_MAX`
+    TooLarge,
+}
+
+/// Storage the log can live on. Offsets are relative to the start of the area.
+pub trait Medium {
+    /// Size of the whole area; each bank gets half
+    const SIZE: u32;
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error>;
+    /// Only ever writes to bytes erased since their last write
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Error>;
+    /// Set `from..to` back to `BLANK`
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Error>;
+}
+
+impl Medium for StorageFlash<'_> {
+    const SIZE: u32 = STORAGE_SIZE as u32;
+
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error> {
+        self.blocking_read(STORAGE_OFFSET + offset, buf).map_err(io)
+    }
+
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Error> {
+        self.blocking_write(STORAGE_OFFSET + offset, data).map_err(io)
+    }
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Error> {
+        self.blocking_erase(STORAGE_OFFSET + from, STORAGE_OFFSET + to).map_err(io)
+    }
+}
+
+fn io(e: flash::Error) -> Error {
+    log::warn!("Flash access failed: {:?}", e);
+    Error::Io
+}
+
+const BANK_SIZE: u32 = Backend::SIZE / 2;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Key {
+    Settings = 1,
+    Progress = 2,
+    /// A paused run, see `savestate`
+    Run = 3,
+}
+
+impl Key {
+    /// Every key compaction carries over
+    const ALL: [Self; 3] = [Self::Settings, Self::Progress, Self::Run];
+}
+
+pub struct Store<'d> {
+    medium: Backend<'d>,
+    /// Active bank and its generation; `None` until the first write
+    bank: Option<(u32, u32)>,
+    /// Offset of the first free byte in the active bank
+    end: u32,
+    /// The log ends in a damaged entry, so appending must compact first
+    torn: bool,
+}
+
+impl<'d> Store<'d> {
+    /// Pick the newest valid bank and find the end of its log.
+    pub fn mount(mut medium: Backend<'d>) -> Self {
+        let mut bank = None;
+        for b in 0..2 {
+            let mut header = [0u8; BANK_HEADER_LEN as usize];
+            if medium.read(bank_offset(b), &mut header).is_err() || header[..4] != MAGIC { continue; }
+            let generation = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+            if bank.is_none_or(|(_, g)| generation > g) { bank = Some((b, generation)); }
+        }
+        let mut store = Self { medium, bank, end: BANK_HEADER_LEN, torn: false };
+        if let Some((b, _)) = bank {
+            let (end, torn) = store.scan(b, |_, _, _| {});
+            store.end = end;
+            store.torn = torn;
+        }
+        store
+    }
+
+    /// Nothing has been written yet (or the area holds another layout).
+    pub fn is_blank(&self) -> bool { self.bank.is_none() }
+
+    /// Copy the value for `key` into `buf`; returns its length.
+    pub fn get(&mut self, key: Key, buf: &mut [u8]) -> Option<usize> {
+        let (b, _) = self.bank?;
+        let (at, len) = self.find(b, key)?;
+        let len = len.min(buf.len());
+        if len == 0 { return None; }
+        self.medium.read(at, &mut buf[..len]).ok()?;
+        Some(len)
+    }
+
+    /// Append a new value for `key`; takes tens of milliseconds when it has to compact.
+    pub fn set(&mut self, key: Key, value: &[u8]) -> Result<(), Error> {
+        if value.len() > VALUE_MAX { return Err(Error::TooLarge); }
+        let mut entry = [BLANK; ENTRY_MAX];
+        let n = pack(&mut entry, key, value);
+        match self.bank {
+            Some((b, _)) if !self.torn && self.end + n as u32 <= BANK_SIZE => {
+                self.medium.write(bank_offset(b) + self.end, &entry[..n])?;
+                self.end += n as u32;
+                Ok(())
+            }
+            _ => self.compact(key, &entry[..n]),
+        }
+    }
+
+    /// Forget `key`; an empty value stands in until the next compaction drops it.
+    pub fn remove(&mut self, key: Key) -> Result<(), Error> {
+        if self.bank.is_none() { return Ok(()); }
+        self.set(key, &[])
+    }
+
+    /// Erase both banks, leaving a blank store.
+    pub fn wipe(&mut self) -> Result<(), Error> {
+        self.bank = None;
+        self.end = BANK_HEADER_LEN;
+        self.torn = false;
+        self.medium.erase(0, Backend::SIZE)
+    }
+
+    /// Raw read at `offset` into the storage area, for data from older layouts.
+    pub fn read_raw(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error> {
+        self.medium.read(offset, buf)
+    }
+
+    /// Walk the log of bank `b`, calling `f(key, value offset, length)` for each
+    /// intact entry. Returns where the log ends and whether it ends damaged.
+    fn scan(&mut self, b: u32, mut f: impl FnMut(u8, u32, usize)) -> (u32, bool) {
+        let base = bank_offset(b);
+        let mut at = BANK_HEADER_LEN;
+        let mut entry = [0u8; ENTRY_MAX];
+        while at + (ENTRY_HEADER_LEN + CRC_LEN) as u32 <= BANK_SIZE {
+            if self.medium.read(base + at, &mut entry[..ENTRY_HEADER_LEN]).is_err() { return (at, true); }
+            if entry[0] == BLANK { return (at, false); }
+            let len = u16::from_le_bytes([entry[1], entry[2]]) as usize;
+            let n = ENTRY_HEADER_LEN + len + CRC_LEN;
+            if len > VALUE_MAX || at + n as u32 > BANK_SIZE { return (at, true); }
+            if self.medium.read(base + at, &mut entry[..n]).is_err() { return (at, true); }
+            let body = n - CRC_LEN;
+            if crc16(&entry[..body]) != u16::from_le_bytes([entry[body], entry[body + 1]]) { return (at, true); }
+            f(entry[0], base + at + ENTRY_HEADER_LEN as u32, len);
+            at += n as u32;
+        }
+        (at, false)
+    }
+
+    /// Offset and length of the newest value for `key` in bank `b`.
+    fn find(&mut self, b: u32, key: Key) -> Option<(u32, usize)> {
+        let mut found = None;
+        self.scan(b, |k, at, len| if k == key as u8 { found = Some((at, len)); });
+        found
+    }
+
+    /// Move every live value, with `pending` (a packed entry) replacing its
+    /// key's, into the other bank and switch to it.
+    fn compact(&mut self, key: Key, pending: &[u8]) -> Result<(), Error> {
+        let (to, generation) = match self.bank {
+            Some((b, g)) => (1 - b, g.wrapping_add(1)),
+            None => (0, 1),
+        };
+        let base = bank_offset(to);
+        self.medium.erase(base, base + BANK_SIZE)?;
+        let mut at = BANK_HEADER_LEN;
+        for k in Key::ALL {
+            let mut entry = [BLANK; ENTRY_MAX];
+            let n = if k == key {
+                entry[..pending.len()].copy_from_slice(pending);
+                pending.len()
+            } else {
+                let mut value = [0u8; VALUE_MAX];
+                let Some(len) = self.get(k, &mut value) else { continue };
+                pack(&mut entry, k, &value[..len])
+            };
+            // Removed keys are left out
+            if n == ENTRY_HEADER_LEN + CRC_LEN { continue; }
+            self.medium.write(base + at, &entry[..n])?;
+            at += n as u32;
+        }
+        let mut header = [0u8; BANK_HEADER_LEN as usize];
+        header[..4].copy_from_slice(&MAGIC);
+        header[4..].copy_from_slice(&generation.to_le_bytes());
+        self.medium.write(base, &header)?;
+        self.bank = Some((to, generation));
+        self.end = at;
+        self.torn = false;
+        Ok(())
+    }
+}
+
+fn bank_offset(b: u32) -> u32 { b * BANK_SIZE }
+
+/// Lay out an entry for `key` in `entry`; returns its length.
+fn pack(entry: &mut [u8; ENTRY_MAX], key: Key, value: &[u8]) -> usize {
+    let body = ENTRY_HEADER_LEN + value.len();
+    entry[0] = key as u8;
+    entry[1..3].copy_from_slice(&(value.len() as u16).to_le_bytes());
+    entry[ENTRY_HEADER_LEN..body].copy_from_slice(value);
+    let crc = crc16(&entry[..body]);
+    entry[body..body + CRC_LEN].copy_from_slice(&crc.to_le_bytes());
+    body + CRC_LEN
+}
+
+/// CRC-16/CCITT-FALSE
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    for &b in bytes {
+        crc ^= (b as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
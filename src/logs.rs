@@ -0,0 +1,63 @@
+//! Per-subsystem USB log verbosity, switched at runtime from the serial console.
+//!
+//! Chatty logs go through `clog!` with a channel; each channel is off, at
+//! info (the default, as before) or at debug, which adds per-event detail.
+//! Logs outside a channel always print.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Clone, Copy)]
+pub enum Channel {
+    /// Button edges
+    Input = 0,
+    /// Obstacles, bosses and field events coming in
+    Spawn = 1,
+    /// Kills, hits and pickups
+    Collide = 2,
+    /// Display writes and frame timing
+    Render = 3,
+}
+
+impl Channel {
+    pub const ALL: [Self; 4] = [Self::Input, Self::Spawn, Self::Collide, Self::Render];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Spawn => "spawn",
+            Self::Collide => "collide",
+            Self::Render => "render",
+        }
+    }
+}
+
+/// Most verbose level per channel, as a `log::LevelFilter` index
+static LEVELS: [AtomicU8; Channel::ALL.len()] = [const { AtomicU8::new(log::LevelFilter::Info as u8) }; Channel::ALL.len()];
+
+/// Most verbose level `channel` prints.
+pub fn level(channel: Channel) -> log::LevelFilter {
+    match LEVELS[channel as usize].load(Ordering::Relaxed) {
+        0 => log::LevelFilter::Off,
+        1 => log::LevelFilter::Error,
+        2 => log::LevelFilter::Warn,
+        3 => log::LevelFilter::Info,
+        _ => log::LevelFilter::Debug,
+    }
+}
+
+/// Trace is capped to debug, the logger's own ceiling.
+pub fn set_level(channel: Channel, level: log::LevelFilter) {
+    LEVELS[channel as usize].store(level.min(log::LevelFilter::Debug) as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(channel: Channel, level: log::Level) -> bool { level <= self::level(channel) }
+
+/// `log::log!` on a channel, e.g. `clog!(Spawn, Debug, "...")`.
+macro_rules! clog {
+    ($channel:ident, $level:ident, $($arg:tt)+) => {
+        if $crate::logs::enabled($crate::logs::Channel::$channel, log::Level::$level) {
+            log::log!(log::Level::$level, $($arg)+);
+        }
+    };
+}
+pub(crate) use clog;
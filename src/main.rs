@@ -4,131 +4,1404 @@
 //!   A (GP12) = fire left, X (GP14) = fire right
 //!   B (GP13) = move left, Y (GP15) = move right
 //!   A+X simultaneous = bomb (destroy all obstacles)
-//! LED (GP25): ON during gameplay, OFF otherwise
+//!   B+Y+X held 3 s on title = reboot to BOOTSEL (USB mass storage)
+//!   B+Y on title = options menu (saved to flash)
+//!   B+Y held 1 s in a game = pause and save the run to flash
+//!   All four held 1 s = re-init a garbled display, any time
+//!   All four held 5 s on title = erase all saved data, after a confirmation
+//! LED (GP25): ON during gameplay, OFF otherwise, blinking on hits
+//! Optional piezo buzzer on GP22 (PWM), muted in demo mode or from options
+//! RGB LED (GP6-8) flashes and an optional vibration motor (GP3) pulses on game events;
+//! each output can be turned off from options
+//! 60 s idle on title = display off + dormant sleep, any button wakes
+//! 2 min idle on a menu = bouncing-logo screensaver; the in-game HUD drifts a pixel every 3 min
+//! Easter egg: a hot chip (internal sensor) gives obstacles a flame palette
+//! Watchdog: fed every frame; a hang or panic reboots into a
+//! "recovered from crash" notice showing the last known score
+//! Display write errors retry or skip a frame, then re-init the controller;
+//! a steady run of them shows a coded error screen and reboots the same way
+//! Optional SD card on SPI1 (`sd-log`): one CSV line per finished run
 
 #![no_std]
 #![no_main]
 
-use core::fmt::Write as _;
-use defmt::*;
+#[cfg(feature = "fram")]
+mod fram;
+#[cfg(feature = "pio-display")]
+mod pio_display;
+mod achievements;
+mod console;
+mod feedback;
+mod guard;
+mod hangul;
+mod heatmap;
+mod kv;
+mod logs;
+mod missions;
+mod savestate;
+#[cfg(feature = "sd-log")]
+mod sdlog;
+mod settings;
+mod ships;
+mod sound;
+mod strings;
+mod tas;
+mod text;
+mod render;
+mod tiny_font;
+mod tutorial;
+mod zones;
+
+use core::sync::atomic::{AtomicBool, Ordering};
+use defmt::{error, unwrap, Display2Format};
 use embassy_executor::Spawner;
-use embassy_rp::gpio::{Input, Level, Output, Pull};
-use embassy_rp::peripherals::USB;
-use embassy_rp::spi::{self, Spi};
+use embassy_futures::select::{select, select4, Either};
+use embassy_rp::adc::{Adc, Channel as AdcChannel, Config as AdcConfig};
+#[cfg(feature = "overclock")]
+use embassy_rp::clocks::ClockConfig;
+use embassy_rp::gpio::{DormantWakeConfig, Input, Level, Output, Pull};
+use embassy_rp::peripherals::{PIN_16, PIN_17, PIN_18, PIN_19, USB};
+use embassy_rp::pwm::{Config as PwmConfig, Pwm};
+#[cfg(feature = "pio-display")]
+use embassy_rp::peripherals::{DMA_CH0, PIO0};
+#[cfg(not(feature = "pio-display"))]
+use embassy_rp::peripherals::SPI0;
+#[cfg(feature = "pio-display")]
+use embassy_rp::pio::{InterruptHandler as PioInterruptHandler, Pio};
+#[cfg(feature = "fram")]
+use embassy_rp::i2c::I2c;
+#[cfg(not(feature = "pio-display"))]
+use embassy_rp::spi::{self, Blocking, Spi};
 use embassy_rp::usb::{Driver, InterruptHandler as UsbInterruptHandler};
-use embassy_rp::bind_interrupts;
+use embassy_rp::watchdog::{ResetReason, Watchdog};
+use embassy_rp::{bind_interrupts, Peri};
 use embassy_time::{Delay, Duration, Instant, Timer};
-use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_10X20};
+use embedded_graphics::mono_font::ascii::{FONT_6X10, FONT_8X13, FONT_10X20};
 use embedded_graphics::mono_font::MonoTextStyle;
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
-use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle};
-use embedded_graphics::text::{Baseline, Text};
-use embedded_hal_bus::spi::ExclusiveDevice;
+use embedded_graphics::primitives::{Line, PrimitiveStyle, Rectangle, Triangle};
+use embedded_graphics::text::{Alignment, Baseline, Text};
+#[cfg(not(feature = "pio-display"))]
+use embedded_hal_bus::spi::{ExclusiveDevice, NoDelay};
+#[cfg(not(feature = "pio-display"))]
+use mipidsi::interface::SpiInterface;
 use mipidsi::models::ST7789;
 use mipidsi::options::{ColorInversion, Orientation, Rotation};
-use mipidsi::Builder;
+use mipidsi::{Builder, NoResetPin};
+use guard::{Frame, Guarded};
+use render::Renderer;
+use savestate::{SavedObstacle, Snapshot, Timers};
+#[cfg(not(feature = "fram"))]
+use kv::StorageFlash;
+use kv::Store;
+use logs::clog;
+use settings::{Progress, Settings};
+use achievements::{Achievement, Event, Tracker};
+use missions::{Mission, MissionKind};
+use feedback::Feedback;
+use sound::{Buzzer, Sfx};
+use strings::{Lang, Msg};
+use game_core::{aabb_overlap, ship_copies, Pool, Slot};
 use static_cell::StaticCell;
-use {defmt_rtt as _, panic_probe as _};
+use defmt_rtt as _;
 
 // --- Screen ---
 const SCREEN_W: i32 = 240;
 const SCREEN_H: i32 = 135;
 
+// --- Pool sizes (the `big-pools` feature doubles them for harder modes) ---
+struct PoolSizes {
+    obstacles: usize,
+    missiles: usize,
+    gifts: usize,
+    particles: usize,
+    explosions: usize,
+    coins: usize,
+    bullets: usize,
+    wrecks: usize,
+}
+const POOLS: PoolSizes = if cfg!(feature = "big-pools") {
+    PoolSizes { obstacles: 20, missiles: 24, gifts: 4, particles: 72, explosions: 8, coins: 8, bullets: 48, wrecks: 8 }
+} else {
+    PoolSizes { obstacles: 10, missiles: 12, gifts: 2, particles: 36, explosions: 4, coins: 4, bullets: 24, wrecks: 4 }
+};
+const MAX_OBS: usize = POOLS.obstacles;
+const MAX_MISSILES: usize = POOLS.missiles;
+const MAX_GIFTS: usize = POOLS.gifts;
+const MAX_PARTICLES: usize = POOLS.particles;
+const MAX_EXPLOSIONS: usize = POOLS.explosions;
+const MAX_COINS: usize = POOLS.coins;
+const MAX_BULLETS: usize = POOLS.bullets;
+const MAX_WRECKS: usize = POOLS.wrecks;
+
+// --- Timing ---
+// Speeds are in pixels per second and durations in seconds, so FPS can be
+// retuned without changing game balance
+const FPS: u32 = 30;
+const FRAME_US: u64 = 1_000_000 / FPS as u64;
+// Game speed option: logic still steps at FPS, but play frames are paced at
+// these real rates, so the whole game runs slower or faster
+const PACE_FPS: [u32; 3] = [20, 30, 40];
+const PACE_NAMES: [Msg; 3] = [Msg::Slow, Msg::Normal, Msg::Fast];
+
 // --- Player ---
-const PLAYER_W: i32 = 24;
 const PLAYER_H: i32 = 8;
 const PLAYER_Y: i32 = 122;
-const PLAYER_SPEED: i32 = 5;
+const PLAYER_RISE: i32 = 30; // px above PLAYER_Y the ship can climb
+const PLAYER_UP_SPEED: i32 = 80; // px/s while A is held
+const PLAYER_DROP_SPEED: i32 = 50; // px/s drifting back down
+// A held direction starts slow for fine positioning and builds up past the ship's speed; let go
+// and it winds down twice as fast, stopping once under the starting share
+// (3 px/frame up to 6 for Balanced at 30 FPS)
+const HOLD_START_PCT: i32 = 90; // of the ship's speed
+const HOLD_TOP_PCT: i32 = 180;
+const HOLD_RAMP_FRAMES: i32 = 6;
+const DASH_PX: i32 = 30;
+const DASH_IFRAMES: u32 = 6;
+const DASH_COOLDOWN_MS: u32 = 2000;
+const DOUBLE_TAP_MS: u32 = 250; // second press within this counts as a double tap
+const INVINCIBLE_MS: u32 = 1000; // after a hit
+
+// --- Afterimages (a full-speed streak leaves fading copies of the ship behind) ---
+const TRAIL_LEN: usize = 4;
+const TRAIL_STREAK: u32 = 3; // frames at full speed before the copies show
 
 // --- Obstacles ---
 const OBS_W: i32 = 12;
 const OBS_H: i32 = 8;
-const MAX_OBS: usize = 6;
-const INITIAL_SPEED: i32 = 2;
+const INITIAL_SPEED: i32 = 40; // px/s
+const SPEED_STEP: i32 = 20;    // px/s added every 10 points
+const MAX_SPEED: i32 = 120;    // px/s
+const SPAWN_INTERVAL_MS: u32 = 1500;
+const SPAWN_STEP_MS: u32 = 250; // shaved off every 10 points
+const MIN_SPAWN_INTERVAL_MS: u32 = 500;
+// New spawns must leave a ship-wide gap among obstacles this far below the HUD
+const FAIR_BAND_H: i32 = 40;
+const FAIR_GAP: i32 = ships::MAX_WIDTH + 8;
+const FAIR_REROLLS: u32 = 8;
+// Lane mode: spawns drop centered on a fixed column grid and the ship steps lane to lane
+const LANES: i32 = 12;
+const LANE_W: i32 = SCREEN_W / LANES;
+const LANE_REPEAT_MS: u32 = 150; // a held direction steps again this often
+const LANE_SNAP_SPEED: i32 = 400; // px/s toward the lane's center
+// Side sweepers only cover the half they enter from, plus a little
+const SIDE_SPEED: i32 = 90;       // px/s
+const SIDE_WARN_MS: u32 = 1000;   // edge marker before it enters
+const SIDE_REACH: i32 = SCREEN_W / 2 + 12;
+const SIDE_CHANCE: i32 = 15;      // percent of spawns
+const SIDE_MIN_SCORE: u32 = 20;
+const BOUNCE_SPEED: i32 = 60;     // px/s sideways
+const BOUNCE_CHANCE: i32 = 20;    // percent of spawns
+const BOUNCE_MIN_SCORE: u32 = 30;
+const BOUNCER_COLOR: Rgb565 = Rgb565::new(31, 0, 31);
+const METEOR_W: i32 = 20;
+const METEOR_H: i32 = 16;
+const METEOR_SPEED: i32 = 150;    // px/s
+const METEOR_WARN_MS: u32 = 1000; // column marked before it appears
+const METEOR_CHANCE: i32 = 5;     // percent of spawns
+const METEOR_MIN_SCORE: u32 = 40;
+const METEOR_COLOR: Rgb565 = Rgb565::new(20, 24, 10);
+const ARMOR_HP: u8 = 2;
+const ARMOR_CHANCE: i32 = 15;     // percent of spawns
+const ARMOR_MIN_SCORE: u32 = 25;
+const ARMOR_COLOR: Rgb565 = Rgb565::new(18, 38, 20);
+const ARMOR_CRACKED: Rgb565 = Rgb565::new(12, 24, 12);
+const SHOOTER_CHANCE: i32 = 12;   // percent of spawns
+const SHOOTER_MIN_SCORE: u32 = 35;
+const SHOOTER_FIRE_MS: u32 = 1500;
+const SHOOTER_FIRE_Y: i32 = PLAYER_Y - 50; // holds fire once this close to the ship
+const SHOOTER_COLOR: Rgb565 = Rgb565::new(31, 30, 8);
+// Armored obstacles leave a wreck that soaks up one missile
+const WRECK_W: i32 = 6;
+const WRECK_H: i32 = 4;
+const WRECK_MS: u32 = 2000;
+const WRECK_COLOR: Rgb565 = Rgb565::new(10, 18, 10);
+// Scorched ground left where a meteor lands
+const BLAST_W: i32 = 48;
+const BLAST_Y: i32 = PLAYER_Y - 4;
+const BLAST_MS: u32 = 700;
+
+// --- Field events (scheduled every few waves) ---
+const EVENT_EVERY_WAVES: u32 = 3;
+const EVENT_DELAY_MS: u32 = 4000; // into the next wave
+const EVENT_WARN_MS: u32 = 2000;  // banner before it starts
+const ASTEROID_MS: u32 = 10_000;
+const ROCK_SIZE: i32 = 5;
+const ROCK_INTERVAL_MS: u32 = 200;
+const ROCK_COLOR: Rgb565 = Rgb565::new(22, 40, 18);
+const ASTEROID_TINT: u8 = 5;      // blue channel of the background while it lasts
+const SQUEEZE_MIN_WAVE: u32 = 6;  // late game only, alternating with asteroids
+const SQUEEZE_CLOSE_MS: u32 = 10_000;
+const SQUEEZE_OPEN_MS: u32 = 3000;
+const SQUEEZE_MAX: i32 = 20;      // per side at its narrowest
+const SQUEEZE_COLOR: Rgb565 = Rgb565::new(18, 12, 2);
+
+// --- Barrier (drifting cover; missiles pass through it) ---
+const BARRIER_W: i32 = 32;
+const BARRIER_H: i32 = 5;
+const BARRIER_HP: u8 = 4;
+const BARRIER_SPEED: i32 = 8;     // px/s
+const BARRIER_REST_Y: i32 = PLAYER_Y - PLAYER_RISE - 12; // stops here, just over the ship's band
+const BARRIER_MS: u32 = 12_000;   // then crumbles
+const BARRIER_CHANCE: i32 = 8;    // percent of spawns
+const BARRIER_MIN_SCORE: u32 = 10;
+// --- Loops: a while at the speed cap starts the next loop, a little slower but meaner ---
+const LOOP_HOLD_LEVELS: u32 = 2;  // levels' worth of points played at the cap first
+const LOOP_RESET_LEVEL: u32 = 2;  // speed level a new loop restarts from
+const LOOP_CHANCE_PCT: i32 = 8;   // added to the armored and shooter chances per loop
+const LOOP_POINTS_PCT: u32 = 50;  // added to kill points per loop
+
+// --- Bosses (a full one every few waves pauses regular spawns; mini-bosses don't) ---
+const BOSS_EVERY_WAVES: u32 = 5;
+const FINAL_WAVE: u32 = 10;       // beating this wave's boss wins the game
+const MINI_BOSS_POINTS: u32 = 25;
+const BOSS_ENTER_SPEED: i32 = 20; // px/s down to the patrol row
+const BOSS_BOMB_DAMAGE: u8 = 3;
+const BOSS_LASER_MS: u32 = 250;   // laser damage tick
+const BOSS_HIT_MS: u32 = 80;      // white flash per hit
+const PATTERN_PAUSE_MS: u32 = 1200; // before the first pattern and between patterns
+
+// --- Enemy bullets ---
+const BULLET_SIZE: i32 = 3;
+const BULLET_SPEED: i32 = 70;     // px/s
+const BULLET_COLOR: Rgb565 = Rgb565::new(31, 24, 28);
+const CURTAIN_SPACING: i32 = 16;
 
 // --- Missiles ---
 const MISSILE_W: i32 = 3;
 const MISSILE_H: i32 = 6;
-const MISSILE_SPEED: i32 = 4;
-const MAX_MISSILES: usize = 8;
+const MISSILE_SPEED: i32 = 80;  // px/s
+const MISSILE_TURN: i32 = 120;  // px/s, homing steering limit
+
+// --- Freeze bolts (bought in the shop, fired from X while in stock) ---
+const MAX_BOLTS: u8 = 9;
+const BOLTS_PER_BUY: u8 = 3;
+const FROZEN_MS: u32 = 3000;
+const BOLT_COLOR: Rgb565 = Rgb565::new(4, 24, 31);
+const FROZEN_COLOR: Rgb565 = Rgb565::new(4, 10, 18);
 // --- Bombs ---
 const MAX_BOMBS: u8 = 3;
+const BOMB_REGEN_SECS: u32 = 45; // of survival per bomb while short of the max
+
+// --- Special ---
+const CHARGE_KILLS: u32 = 20; // shots and laser kills to fill the gauge; bombs don't count
+const SPECIAL_MISSILES: usize = 8;
+const SPECIAL_SPREAD: i32 = 60; // px/s sideways for the outermost missiles
+const CHARGE_COLOR: Rgb565 = Rgb565::new(31, 16, 31);
 
 // --- Gifts ---
 const GIFT_W: i32 = 10;
 const GIFT_H: i32 = 10;
-const GIFT_SPEED: i32 = 1;
-const MAX_GIFTS: usize = 2;
-const GIFT_MAX_LIFE: u8 = 80;
-const GIFT_FADE_START: u8 = 20;
+const GIFT_SPEED: i32 = 20; // px/s
+const GIFT_MAX_LIFE: u8 = secs_to_frames(4) as u8;
+const GIFT_FADE_START: u8 = secs_to_frames(1) as u8; // then it dithers away
+const GIFT_SPAWN_SECS: u32 = 10;
+const GIFT_CHANCE: i32 = 15; // percent per 50 ms once the spawn delay is up
+// Gifts move in half pixels so the slow fall and sway don't stair-step
+const GIFT_SUBPX: i32 = 2;
+// Horizontal drift under the parachute in half pixels, one entry per 100 ms
+const GIFT_SWAY: [i8; 16] = [0, 3, 6, 7, 8, 7, 6, 3, 0, -3, -6, -7, -8, -7, -6, -3];
+const GIFT_SWAY_MAX: i32 = 4; // px
+// 4x4 ordered dither thresholds: a pixel stays lit while its entry is below the level (0..=16)
+const BAYER4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+const CHUTE_H: i32 = 6; // canopy plus lines above the box
+// Compass: an arrow at the field edge when the nearest gift is this far to one side of the ship
+const COMPASS_MIN_DX: i32 = 60;
+const COMPASS_SIZE: i32 = 4; // half height; the arrow is as deep
+// By remaining life, in thirds: last, middle, first
+const COMPASS_COLORS: [Rgb565; 3] = [Rgb565::RED, Rgb565::YELLOW, Rgb565::GREEN];
+
+// --- Coins (dropped by shot-down obstacles, collected by touching) ---
+const COIN_SIZE: i32 = 5;
+const COIN_SPEED: i32 = 45;      // px/s
+const COIN_DROP_CHANCE: i32 = 30; // percent per kill
 
-// --- Power-up durations (frames at 20 FPS) ---
-const FREEZE_DURATION: u32 = 100;  // 5 seconds
-const HOMING_DURATION: u32 = 200;  // 10 seconds
-const LASER_DURATION: u32 = 100;   // 5 seconds
-const SHIELD_DURATION: u32 = 160;  // 8 seconds
+// --- Power-up durations ---
+const FREEZE_DURATION: u32 = secs_to_frames(5);
+const HOMING_DURATION: u32 = secs_to_frames(10);
+const LASER_DURATION: u32 = secs_to_frames(5);
+const SHIELD_DURATION: u32 = secs_to_frames(8);
+const TWIN_DURATION: u32 = secs_to_frames(8);
+const REVERSE_DURATION: u32 = secs_to_frames(5);
+const MAGNET_DURATION: u32 = secs_to_frames(8);
+const MAGNET_RANGE: i32 = 80; // px either side of the ship's column
+const MAGNET_LINE: Rgb565 = Rgb565::new(6, 10, 14);
+const REVERSE_CHANCE: i32 = 6; // percent of gifts that are the trap
+const CAPPED_GIFT_POINTS: u32 = 15; // a life or bomb gift with nowhere to go
+const POPUP_MS: u32 = 800;
+const POPUP_RISE: i32 = 20; // px/s
+const KILL_POPUP_COLOR: Rgb565 = Rgb565::new(28, 56, 28);
 
 // --- Particles ---
-const MAX_PARTICLES: usize = 36;
-const PARTICLE_LIFE: u8 = 8;
+const PARTICLE_SPEED: i32 = 20; // px/s per unit of the -3..=3 spread
+
+// --- Explosions (4 sprite stages, growing outward) ---
+const EXPLOSION_STAGES: u8 = 4;
+const EXPLOSION_STAGE_FRAMES: u8 = ms_to_frames(70) as u8;
 
 // --- Lives ---
 const MAX_LIVES: u8 = 3;
+const SMOKE_INTERVAL_MS: u32 = 150; // damaged ship trails smoke on its last life
+// Heartbeat on the last life, quicker for each obstacle on screen
+const HEARTBEAT_MS: u32 = 1000;
+const HEARTBEAT_STEP_MS: u32 = 120;
+const HEARTBEAT_MIN_MS: u32 = 400;
+// Chirp when an obstacle in the ship's column will reach it within this many frames
+const COLLISION_WARN_FRAMES: i32 = 10;
+const COLLISION_WARN_COOLDOWN_MS: u32 = 700;
+
+// --- Leak limit (option: this many obstacles past the bottom ends the run, whatever the lives) ---
+const LEAK_LIMIT: u32 = 10;
+const LEAK_WARN: u32 = 7; // the HUD count turns red from here
 
 // --- HUD ---
-const HUD_H: i32 = 24;
+const HUD_H: i32 = 14;
+// Icons that fit between the score and the power-ups, and right of them; past
+// that a single icon and an "x5" count stand in, so caps can grow
+const HUD_BOMB_SLOTS: u8 = 4;
+const HUD_LIFE_SLOTS: u8 = 3;
+
+// 8x8 HUD glyphs, one byte per row, MSB on the left
+const ICON_HEART: [u8; 8] = [
+    0b01100110,
+    0b11111111,
+    0b11111111,
+    0b11111111,
+    0b01111110,
+    0b00111100,
+    0b00011000,
+    0b00000000,
+];
+const ICON_BOMB: [u8; 8] = [
+    0b00000110,
+    0b00001000,
+    0b00111100,
+    0b01111110,
+    0b01111110,
+    0b01111110,
+    0b01111110,
+    0b00111100,
+];
+const ICON_CROSSHAIR: [u8; 8] = [
+    0b00011000,
+    0b00011000,
+    0b00000000,
+    0b11011011,
+    0b11011011,
+    0b00000000,
+    0b00011000,
+    0b00011000,
+];
+const ICON_STAR: [u8; 8] = [
+    0b00011000,
+    0b00011000,
+    0b11111111,
+    0b01111110,
+    0b00111100,
+    0b01111110,
+    0b01100110,
+    0b01000010,
+];
+const ICON_REVERSE: [u8; 8] = [
+    0b00100000,
+    0b01111110,
+    0b00100000,
+    0b00000000,
+    0b00000100,
+    0b01111110,
+    0b00000100,
+    0b00000000,
+];
+
+// --- Speed-up banner ---
+const SPEED_BANNER_MS: u32 = 1200;
+
+// --- Perfect wave: no lives lost and nothing reached the bottom ---
+const PERFECT_BONUS: u32 = 20;
+const PERFECT_BANNER_MS: u32 = 2000;
+
+// --- Danger tint (playfield background goes dark red) ---
+const DANGER_OBSTACLES: usize = 4;
+const DANGER_TINT_MAX: u8 = 16; // ramp steps; red channel is tint / 4
+const HEARTBEAT_TINT: u8 = 24;   // flash above the danger tint on each beat
+
+// --- Assist (optional rubber band) ---
+// Two short runs in a row ease the next run by one level; a good run takes one back
+const ASSIST_SHORT_RUN: u32 = 20;  // points
+const ASSIST_SHORT_RUNS: u32 = 2;
+const ASSIST_MAX_LEVEL: u32 = 3;
+const ASSIST_STEP_PCT: u32 = 15;   // difficulty removed per level
+
+// --- Practice ---
+const PRACTICE_TAP_MS: u32 = 200; // shorter Y presses spawn a gift instead of just moving
+
+// --- Achievement toast (slides down from the top of the play area) ---
+const TOAST_W: i32 = 160;
+const TOAST_H: i32 = 13;
+const TOAST_MS: u32 = 2500;
+const TOAST_SLIDE_MS: u32 = 200;
+const SNIPER_ZONE: i32 = 12; // kills this close to the top count as long shots
+
+// --- Victory screen ---
+const TALLY_MS: u32 = 2000;        // total counts up over this long
+// An untouched game over or victory screen goes back to the title (and its attract loop)
+const END_SCREEN_IDLE_FRAMES: u32 = secs_to_frames(30);
+const CREDITS_TOP: i32 = 66;
+const CREDITS_H: i32 = 54;
+const CREDITS_LINE_H: i32 = 12;
+const CREDITS_SPEED: i32 = 20;     // px/s
+const CREDITS: [Msg; 9] = [
+    Msg::CreditsTitle,
+    Msg::Blank,
+    Msg::CreditsDesign,
+    Msg::CreditsCrew,
+    Msg::Blank,
+    Msg::CreditsBuilt,
+    Msg::CreditsPico,
+    Msg::Blank,
+    Msg::CreditsThanks,
+];
+
+// --- Backlight ---
+// Levels are perceptual: the PWM compare is the square of the level
+const BACKLIGHT_FULL: u16 = 16;
+const FADE_LEVEL_STEP: u16 = 3;
+const FADE_STEP_MS: u64 = 20;
+
+// --- Renderer ---
+// Every pooled entity plus the player fits in one batch (explosion rings and
+// gifts with their parachute take 4 rects, coins, the toast 2, the player and the charge gauge 2 for
+// each of its two copies while it wraps, the border 12, plus the meteor blast zone, a magnet line per gift
+// and coin, the zone's stars and the ship's afterimages)
+const MAX_BATCH_RECTS: usize = MAX_OBS + MAX_MISSILES + MAX_GIFTS * 5 + MAX_PARTICLES
+    + MAX_EXPLOSIONS * 4 + MAX_COINS * 3 + MAX_BULLETS + MAX_WRECKS + 2 * 2 + 2 + 2 * 2 + 12 + 1 + 1 + 4 + 4
+    + zones::MAX_STARS + TRAIL_LEN;
+const STAR_COLOR: Rgb565 = Rgb565::new(10, 20, 10);
+// flush tests every rect on each playfield row, so growing the pools costs frame
+// time; check the `overlay` readout before raising this
+const RENDER_BUDGET_RECTS: usize = 320;
+const _: () = assert!(MAX_BATCH_RECTS <= RENDER_BUDGET_RECTS, "pools outgrow the renderer budget");
+const MAX_SPEED_LEVEL: u32 = ((MAX_SPEED - INITIAL_SPEED) / SPEED_STEP) as u32;
+// Latency probe (serial `latency`): the corner square lit on the frame that answers a press
+const LATENCY_FLASH: i32 = 12;
+
+// --- Burn-in guard ---
+// The HUD steps through these offsets, one every few minutes; it never moves down, where the bars would meet the playfield
+const HUD_SHIFTS: [Point; 4] = [Point::new(0, 0), Point::new(1, 0), Point::new(0, -1), Point::new(-1, 0)];
+const HUD_SHIFT_SECS: u64 = 180;
+/// A menu left this long hands the panel to the screensaver
+const SAVER_IDLE_FRAMES: u32 = secs_to_frames(120);
+/// Screens the screensaver covers; the title sleeps, and the others time out or move on their own
+const SAVER_STATES: [GameState; 9] = [
+    GameState::Options, GameState::Unlocks, GameState::Hangar, GameState::Briefing, GameState::Shop, GameState::GetReady,
+    GameState::Versus, GameState::Resume, GameState::FactoryReset,
+];
+const SAVER_LOGO: &str = "DODGE!";
+const SAVER_SPEED: i32 = 24;       // px/s on each axis
+const SAVER_BACKLIGHT: u16 = 6;
+/// The logo changes color at every wall
+const SAVER_COLORS: [Rgb565; 5] = [Rgb565::YELLOW, Rgb565::CYAN, Rgb565::MAGENTA, Rgb565::GREEN, Rgb565::WHITE];
+
+// --- Watchdog ---
+const WATCHDOG_TIMEOUT_MS: u64 = 1000;
+// Scratch registers survive a watchdog reset (the bootrom only uses 4..7)
+const SCRATCH_MAGIC: usize = 0;
+const SCRATCH_SCORE: usize = 1;
+const SCRATCH_CAUSE: usize = 2;
+const SCRATCH_BOOT: usize = 3;
+const MAGIC_VALUE: u32 = 0x444F_4447; // "DODG"
+const CAUSE_PANIC: u32 = 1;
+const CAUSE_DISPLAY: u32 = 2;
+const BOOT_DISPLAY_INIT: u32 = 1;
+const BOOT_WATCHDOG_MS: u64 = 8000; // covers the USB wait and crash notice
+
+// --- Clocks ---
+const DISPLAY_SPI_MAX_HZ: u32 = 62_500_000;
+#[cfg(feature = "overclock")]
+const OVERCLOCK_HZ: u32 = 250_000_000;
+
+// --- Title combos ---
+const BOOTSEL_HOLD_FRAMES: u32 = secs_to_frames(3);
+const TITLE_IDLE_FRAMES: u32 = secs_to_frames(60); // then dormant sleep
+/// B+Y held this long mid-run pauses and saves the run
+const PAUSE_HOLD_FRAMES: u32 = secs_to_frames(1);
+/// No buttons this long mid-run pauses it the same way, rather than letting it die
+const AFK_PAUSE_SECS: u32 = 10;
+/// A pause left this long goes back to the title; the run stays saved for the next boot
+const PAUSE_TIMEOUT_FRAMES: u32 = secs_to_frames(120);
+/// All four buttons held this long re-init the display, in any state
+const REINIT_HOLD_FRAMES: u32 = secs_to_frames(1);
+/// ...and this long on the title asks to erase everything saved
+const FACTORY_RESET_HOLD_FRAMES: u32 = secs_to_frames(5);
+
+// --- Chip temperature easter egg ---
+const OVERHEAT_C: i32 = 40;
+const TEMP_SAMPLE_FRAMES: u32 = secs_to_frames(5);
+
+// --- Color themes ---
+struct Theme {
+    name: Msg,
+    player: Rgb565,
+    obstacle: Rgb565,
+    missile: Rgb565,
+    homing: Rgb565,
+    laser: Rgb565,
+    hud: Rgb565,
+    life_on: Rgb565,
+    life_off: Rgb565,
+    bomb_on: Rgb565,
+    bomb_off: Rgb565,
+}
+// The last theme stays hidden until its unlock is earned
+const THEMES: [Theme; 5] = [
+    Theme {
+        name: Msg::ThemeClassic,
+        player: Rgb565::CYAN, obstacle: Rgb565::RED, missile: Rgb565::YELLOW,
+        homing: Rgb565::new(31, 40, 0), laser: Rgb565::new(0, 63, 31), hud: Rgb565::WHITE,
+        life_on: Rgb565::RED, life_off: Rgb565::new(4, 8, 4),
+        bomb_on: Rgb565::new(0, 31, 0), bomb_off: Rgb565::new(2, 8, 2),
+    },
+    Theme {
+        name: Msg::ThemeCrt,
+        player: Rgb565::new(8, 63, 8), obstacle: Rgb565::new(0, 40, 0), missile: Rgb565::new(16, 63, 16),
+        homing: Rgb565::new(0, 50, 12), laser: Rgb565::new(20, 63, 20), hud: Rgb565::new(0, 63, 0),
+        life_on: Rgb565::new(0, 63, 0), life_off: Rgb565::new(0, 12, 0),
+        bomb_on: Rgb565::new(0, 48, 0), bomb_off: Rgb565::new(0, 10, 0),
+    },
+    Theme {
+        name: Msg::ThemeContrast,
+        player: Rgb565::WHITE, obstacle: Rgb565::RED, missile: Rgb565::YELLOW,
+        homing: Rgb565::new(31, 32, 0), laser: Rgb565::CYAN, hud: Rgb565::WHITE,
+        life_on: Rgb565::RED, life_off: Rgb565::new(10, 20, 10),
+        bomb_on: Rgb565::GREEN, bomb_off: Rgb565::new(10, 20, 10),
+    },
+    Theme {
+        name: Msg::ThemePastel,
+        player: Rgb565::new(20, 50, 31), obstacle: Rgb565::new(31, 36, 24), missile: Rgb565::new(31, 60, 20),
+        homing: Rgb565::new(31, 48, 20), laser: Rgb565::new(20, 60, 31), hud: Rgb565::new(28, 56, 28),
+        life_on: Rgb565::new(31, 40, 40), life_off: Rgb565::new(10, 20, 12),
+        bomb_on: Rgb565::new(18, 60, 20), bomb_off: Rgb565::new(6, 16, 8),
+    },
+    Theme {
+        name: Msg::ThemeSunset,
+        player: Rgb565::new(31, 44, 0), obstacle: Rgb565::new(20, 0, 16), missile: Rgb565::new(31, 58, 10),
+        homing: Rgb565::new(31, 24, 8), laser: Rgb565::new(31, 20, 20), hud: Rgb565::new(31, 50, 16),
+        life_on: Rgb565::new(31, 16, 8), life_off: Rgb565::new(8, 6, 6),
+        bomb_on: Rgb565::new(31, 36, 0), bomb_off: Rgb565::new(8, 8, 2),
+    },
+];
+
+// --- Unlocks (earned by lifetime kills, kept in flash) ---
+#[derive(PartialEq, Clone, Copy)]
+enum Unlock {
+    ArrowShip,
+    SunsetTheme,
+    BombBay,
+}
+const UNLOCKS: [Unlock; 3] = [Unlock::ArrowShip, Unlock::SunsetTheme, Unlock::BombBay];
+impl Unlock {
+    fn name(self) -> Msg {
+        match self {
+            Self::ArrowShip => Msg::UnlockArrow,
+            Self::SunsetTheme => Msg::UnlockSunset,
+            Self::BombBay => Msg::UnlockBombBay,
+        }
+    }
+
+    fn kills(self) -> u32 {
+        match self {
+            Self::ArrowShip => 100,
+            Self::SunsetTheme => 250,
+            Self::BombBay => 500,
+        }
+    }
+
+    fn earned(self, p: &Progress) -> bool { p.kills >= self.kills() }
+}
+
+const SHIP_NAMES: [Msg; 2] = [Msg::ShipClassic, Msg::ShipArrow];
+
+// --- Options menu ---
+#[derive(PartialEq, Clone, Copy)]
+enum OptionItem {
+    Theme,
+    Flip,
+    Sound,
+    Light,
+    Rumble,
+    StatusLed,
+    Ship,
+    Assist,
+    Hard,
+    Lanes,
+    Wrap,
+    Leaks,
+    Pace,
+    Lang,
+    Back,
+}
+const OPTION_ITEMS: [OptionItem; 15] = [
+    OptionItem::Theme, OptionItem::Flip, OptionItem::Sound, OptionItem::Light, OptionItem::Rumble, OptionItem::StatusLed,
+    OptionItem::Ship, OptionItem::Assist, OptionItem::Hard, OptionItem::Lanes, OptionItem::Wrap, OptionItem::Leaks,
+    OptionItem::Pace, OptionItem::Lang, OptionItem::Back,
+];
+// Rows shown at once; the list scrolls to keep the selection on screen
+const OPTION_ROWS: usize = 8;
+impl OptionItem {
+    fn label(self) -> Msg {
+        match self {
+            Self::Theme => Msg::Theme,
+            Self::Flip => Msg::Screen,
+            Self::Sound => Msg::Sound,
+            Self::Light => Msg::Light,
+            Self::Rumble => Msg::Rumble,
+            Self::StatusLed => Msg::StatusLed,
+            Self::Ship => Msg::Ship,
+            Self::Assist => Msg::Assist,
+            Self::Hard => Msg::HardMode,
+            Self::Lanes => Msg::LaneMode,
+            Self::Wrap => Msg::EdgeWrap,
+            Self::Leaks => Msg::LeakLimit,
+            Self::Pace => Msg::GameSpeed,
+            Self::Lang => Msg::Language,
+            Self::Back => Msg::Back,
+        }
+    }
+
+    fn value(self, s: &Settings) -> Msg {
+        let on_off = |on| if on { Msg::On } else { Msg::Off };
+        match self {
+            Self::Theme => THEMES[s.theme as usize % THEMES.len()].name,
+            Self::Flip => if s.flipped { Msg::Flipped } else { Msg::Normal },
+            Self::Sound => on_off(s.sound),
+            Self::Light => on_off(s.outputs & feedback::RGB != 0),
+            Self::Rumble => on_off(s.outputs & feedback::VIBE != 0),
+            Self::StatusLed => on_off(s.outputs & feedback::LED != 0),
+            Self::Ship => SHIP_NAMES[s.ship as usize % SHIP_NAMES.len()],
+            Self::Assist => on_off(s.assist),
+            Self::Hard => on_off(s.hard),
+            Self::Lanes => on_off(s.lanes),
+            Self::Wrap => on_off(s.wrap),
+            Self::Leaks => on_off(s.leak_limit),
+            Self::Pace => PACE_NAMES[s.pace as usize % PACE_NAMES.len()],
+            Self::Lang => Msg::LangName, // each language names itself
+            Self::Back => Msg::Blank,
+        }
+    }
+
+    /// Step the value by `dir` (+1 or -1), wrapping around and skipping locked choices.
+    fn cycle(self, s: &mut Settings, dir: i32, p: &Progress) {
+        match self {
+            Self::Theme => {
+                let n = THEMES.len() - !Unlock::SunsetTheme.earned(p) as usize;
+                s.theme = (s.theme as i32 + dir).rem_euclid(n as i32) as u8;
+            }
+            Self::Flip => s.flipped = !s.flipped,
+            Self::Sound => s.sound = !s.sound,
+            Self::Light => s.outputs ^= feedback::RGB,
+            Self::Rumble => s.outputs ^= feedback::VIBE,
+            Self::StatusLed => s.outputs ^= feedback::LED,
+            Self::Ship => if Unlock::ArrowShip.earned(p) { s.ship = (s.ship + 1) % SHIP_NAMES.len() as u8 },
+            Self::Assist => s.assist = !s.assist,
+            Self::Hard => s.hard = !s.hard,
+            Self::Lanes => s.lanes = !s.lanes,
+            Self::Wrap => s.wrap = !s.wrap,
+            Self::Leaks => s.leak_limit = !s.leak_limit,
+            Self::Pace => s.pace = (s.pace as i32 + dir).rem_euclid(PACE_FPS.len() as i32) as u8,
+            Self::Lang => s.lang = (s.lang as i32 + dir).rem_euclid(Lang::ALL.len() as i32) as u8,
+            Self::Back => {}
+        }
+    }
+}
+
+// --- Shop (between waves, paid in coins) ---
+const WAVE_POINTS: u32 = 30;
+// Starting from a later wave keeps its difficulty but only this share of its points
+const START_SCORE_PCT: u32 = 50;
+
+#[derive(PartialEq, Clone, Copy)]
+enum ShopItem {
+    Bomb,
+    Life,
+    Twin,
+    Bolts,
+    Done,
+}
+const SHOP_ITEMS: [ShopItem; 5] = [ShopItem::Bomb, ShopItem::Life, ShopItem::Twin, ShopItem::Bolts, ShopItem::Done];
+impl ShopItem {
+    fn label(self) -> Msg {
+        match self {
+            Self::Bomb => Msg::BuyBomb,
+            Self::Life => Msg::BuyLife,
+            Self::Twin => Msg::BuyTwin,
+            Self::Bolts => Msg::BuyBolts,
+            Self::Done => Msg::NextWave,
+        }
+    }
+
+    fn cost(self) -> u32 {
+        match self {
+            Self::Bomb => 3,
+            Self::Life => 5,
+            Self::Twin => 4,
+            Self::Bolts => 4,
+            Self::Done => 0,
+        }
+    }
+}
+
+/// Timed events that take over the spawner for a while.
+#[derive(Clone, Copy, PartialEq)]
+enum FieldEvent {
+    Asteroids,
+    /// Walls close in from both sides, then pull back
+    Squeeze,
+}
+impl FieldEvent {
+    fn banner(self) -> Msg {
+        match self {
+            Self::Asteroids => Msg::Asteroids,
+            Self::Squeeze => Msg::Squeeze,
+        }
+    }
+
+    fn duration_ms(self) -> u32 {
+        match self {
+            Self::Asteroids => ASTEROID_MS,
+            Self::Squeeze => SQUEEZE_CLOSE_MS + SQUEEZE_OPEN_MS,
+        }
+    }
+}
+
+/// At most one field event at a time: a countdown that ends in a warning, then the event.
+struct Scheduler {
+    pending: Option<(FieldEvent, u32)>,
+    active: Option<(FieldEvent, u32)>,
+}
+impl Scheduler {
+    const fn new() -> Self { Self { pending: None, active: None } }
+
+    fn clear(&mut self) { *self = Self::new(); }
+
+    /// Ignored while another event is queued or running.
+    fn schedule(&mut self, ev: FieldEvent, delay_ms: u32) {
+        if self.pending.is_none() && self.active.is_none() { self.pending = Some((ev, ms_to_frames(delay_ms))); }
+    }
+
+    /// Advance one frame of play; returns the event that just started.
+    fn tick(&mut self) -> Option<FieldEvent> {
+        if let Some((_, left)) = &mut self.active {
+            *left -= 1;
+            if *left == 0 { self.active = None; }
+        }
+        let (ev, left) = self.pending?;
+        if left > 1 { self.pending = Some((ev, left - 1)); return None; }
+        self.pending = None;
+        self.active = Some((ev, ms_to_frames(ev.duration_ms())));
+        Some(ev)
+    }
+
+    fn warning(&self) -> Option<FieldEvent> {
+        self.pending.filter(|&(_, left)| left <= ms_to_frames(EVENT_WARN_MS)).map(|(ev, _)| ev)
+    }
+
+    fn active(&self) -> Option<FieldEvent> { self.active.map(|(ev, _)| ev) }
+
+    /// Time since the running event started.
+    fn elapsed_ms(&self) -> u32 {
+        self.active.map_or(0, |(ev, left)| (ms_to_frames(ev.duration_ms()) - left) * 1000 / FPS)
+    }
+}
+
+/// Width of each squeeze wall `elapsed_ms` in: a slow close, then a quicker retreat.
+fn squeeze_inset(elapsed_ms: u32) -> i32 {
+    if elapsed_ms < SQUEEZE_CLOSE_MS {
+        SQUEEZE_MAX * elapsed_ms as i32 / SQUEEZE_CLOSE_MS as i32
+    } else {
+        let left = (SQUEEZE_CLOSE_MS + SQUEEZE_OPEN_MS).saturating_sub(elapsed_ms);
+        SQUEEZE_MAX * left as i32 / SQUEEZE_OPEN_MS as i32
+    }
+}
 
 // --- Game states ---
 #[derive(PartialEq, Clone, Copy)]
 enum GameState {
     Title,
+    Options,
+    Unlocks,
+    /// Before a game: pick a ship class
+    Hangar,
+    Briefing,
     Playing,
+    Shop,
     GameOver,
+    /// Final boss beaten: tally and credits
+    Victory,
+    /// 2P: between the first player's run and the second's
+    GetReady,
+    /// 2P: both scores side by side
+    Versus,
+    /// Held B+Y mid-run: the run is saved to flash
+    Paused,
+    /// At boot, when a paused run was saved
+    Resume,
+    /// All four held on the title: confirm wiping the store
+    FactoryReset,
+    /// A menu left alone: a logo bounces on a dimmed panel until a press
+    Screensaver,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ObstacleKind {
+    Falling,
+    /// Sweeps in from a side edge at ship height
+    Side,
+    /// Falls while ricocheting off the side walls
+    Bouncer,
+    /// Small and fast, only during an asteroid field; double points
+    Rock,
+    /// Big, shrugs off missiles, and scorches the ground where it lands
+    Meteor,
+    /// Takes `ARMOR_HP` missiles and leaves a wreck behind
+    Armored,
+    /// Takes aimed shots at the ship on the way down
+    Shooter,
+}
+impl ObstacleKind {
+    /// In savestate index order
+    const ALL: [Self; 7] =
+        [Self::Falling, Self::Side, Self::Bouncer, Self::Rock, Self::Meteor, Self::Armored, Self::Shooter];
+
+    /// Points for a kill, worth more each endless-mode loop.
+    fn kill_points(self, loop_count: u32) -> u32 {
+        let base = match self {
+            Self::Falling | Self::Side => 2,
+            Self::Bouncer | Self::Rock => 3,
+            Self::Shooter => 4,
+            Self::Meteor | Self::Armored => 5,
+        };
+        base * (100 + (loop_count - 1) * LOOP_POINTS_PCT) / 100
+    }
+
+    fn size(self) -> (i32, i32) {
+        match self {
+            Self::Rock => (ROCK_SIZE, ROCK_SIZE),
+            Self::Meteor => (METEOR_W, METEOR_H),
+            _ => (OBS_W, OBS_H),
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 struct Obstacle {
     x: i32,
     y: i32,
+    dx: i32, // px/s, sideways movers only
+    kind: ObstacleKind,
+    frozen: u32, // frames left pinned by a freeze bolt
+    hp: u8,
     active: bool,
 }
+impl Slot for Obstacle {
+    fn active(&self) -> bool { self.active }
+}
 impl Obstacle {
-    const fn new() -> Self { Self { x: 0, y: 0, active: false } }
+    const fn new() -> Self { Self { x: 0, y: 0, dx: 0, kind: ObstacleKind::Falling, frozen: 0, hp: 1, active: false } }
+
+    const fn falling(x: i32) -> Self { Self { x, y: HUD_H, dx: 0, kind: ObstacleKind::Falling, frozen: 0, hp: 1, active: true } }
+
+    const fn armored(x: i32) -> Self {
+        Self { x, y: HUD_H, dx: 0, kind: ObstacleKind::Armored, frozen: 0, hp: ARMOR_HP, active: true }
+    }
+
+    const fn shooter(x: i32) -> Self {
+        Self { x, y: HUD_H, dx: 0, kind: ObstacleKind::Shooter, frozen: 0, hp: 1, active: true }
+    }
+
+    const fn rock(x: i32) -> Self { Self { x, y: HUD_H, dx: 0, kind: ObstacleKind::Rock, frozen: 0, hp: 1, active: true } }
+
+    /// Starts above the play area so its target column is marked before it shows.
+    const fn meteor(x: i32) -> Self {
+        let y = HUD_H - METEOR_H - METEOR_SPEED * METEOR_WARN_MS as i32 / 1000;
+        Self { x, y, dx: 0, kind: ObstacleKind::Meteor, frozen: 0, hp: 1, active: true }
+    }
+
+    const fn bouncer(x: i32, right: bool) -> Self {
+        let dx = if right { BOUNCE_SPEED } else { -BOUNCE_SPEED };
+        Self { x, y: HUD_H, dx, kind: ObstacleKind::Bouncer, frozen: 0, hp: 1, active: true }
+    }
+
+    /// Starts off-screen so the edge marker shows before it arrives.
+    const fn side(from_left: bool) -> Self {
+        let lead = OBS_W + SIDE_SPEED * SIDE_WARN_MS as i32 / 1000;
+        let (x, dx) = if from_left { (-lead, SIDE_SPEED) } else { (SCREEN_W + lead - OBS_W, -SIDE_SPEED) };
+        Self { x, y: PLAYER_Y, dx, kind: ObstacleKind::Side, frozen: 0, hp: 1, active: true }
+    }
+
+    fn w(&self) -> i32 { self.kind.size().0 }
+    fn h(&self) -> i32 { self.kind.size().1 }
+    fn cx(&self) -> i32 { self.x + self.w() / 2 }
+    fn cy(&self) -> i32 { self.y + self.h() / 2 }
+
+    /// Inside the play area: sweepers start off either side and meteors above the HUD.
+    fn on_screen(&self) -> bool {
+        self.x + self.w() > 0 && self.x < SCREEN_W && self.y + self.h() > HUD_H && self.y < SCREEN_H
+    }
+
+    /// A side sweeper burns out once its leading edge is this far in.
+    fn swept(&self) -> bool {
+        if self.dx > 0 { self.x + OBS_W >= SIDE_REACH } else { self.x <= SCREEN_W - SIDE_REACH }
+    }
 }
 
 #[derive(Clone, Copy)]
 struct Missile {
     x: i32,
     y: i32,
+    dx: i32, // px/s sideways, only for the special's fan
     active: bool,
     homing: bool,
+    bolt: bool, // freeze bolt: pins what it hits instead of destroying it
+}
+impl Slot for Missile {
+    fn active(&self) -> bool { self.active }
 }
 impl Missile {
-    const fn new() -> Self { Self { x: 0, y: 0, active: false, homing: false } }
+    const fn new() -> Self { Self { x: 0, y: 0, dx: 0, active: false, homing: false, bolt: false } }
+}
+
+/// Score an obstacle that made it to the bottom: `pts` normally, one point
+/// off in hard mode (never below zero). Counts toward the wave's leaks either way.
+fn pass_through(score: &mut u32, leaks: &mut u32, pts: u32, hard: bool) {
+    *score = if hard { score.saturating_sub(1) } else { *score + pts };
+    *leaks += 1;
+}
+
+const fn lane_center(lane: i32) -> i32 { lane * LANE_W + LANE_W / 2 }
+
+/// A random x for a `w`-wide spawn in [wall, wall + field_w), centered on a lane
+/// in lane mode, that still leaves a `FAIR_GAP` among the obstacles in the top
+/// band, or None if every roll walls it off.
+fn fair_x(rng: &mut Rng, obstacles: &[Obstacle], wall: i32, field_w: i32, w: i32, lanes: bool) -> Option<i32> {
+    let band: heapless::Vec<(i32, i32), { MAX_OBS + 1 }> = obstacles.iter()
+        .filter(|o| o.active && o.y < HUD_H + FAIR_BAND_H)
+        .map(|o| (o.x, o.x + o.w()))
+        .collect();
+    for _ in 0..FAIR_REROLLS {
+        let x = if lanes { (lane_center(rng.range(LANES)) - w / 2).clamp(wall, wall + field_w - w) }
+            else { wall + rng.range(field_w - w) };
+        let mut spans = band.clone();
+        spans.push((x, x + w)).ok();
+        spans.sort_unstable();
+        // Widest free run between the spans, walls included
+        let mut edge = wall;
+        let mut widest = 0;
+        for &(l, r) in &spans {
+            widest = widest.max(l - edge);
+            edge = edge.max(r);
+        }
+        if widest.max(wall + field_w - edge) >= FAIR_GAP { return Some(x); }
+    }
+    None
+}
+
+/// Launch up to `SPECIAL_MISSILES` from (`cx`, `y`), fanned evenly across
+/// `SPECIAL_SPREAD` either side.
+fn fire_fan(missiles: &mut [Missile], cx: i32, y: i32) {
+    let n = SPECIAL_MISSILES as i32;
+    for (i, m) in missiles.free_slots(SPECIAL_MISSILES).enumerate() {
+        let dx = -SPECIAL_SPREAD + 2 * SPECIAL_SPREAD * i as i32 / (n - 1);
+        *m = Missile { x: cx - MISSILE_W / 2, y, dx, active: true, homing: false, bolt: false };
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ParticleKind {
+    Debris,
+    Spark,
+    Smoke,
+    Star,
+}
+
+/// Per-kind motion and look. Colors run young to old.
+struct ParticleSpec {
+    speed: i32,   // multiplier on PARTICLE_SPEED
+    gravity: i32, // px/s², negative rises
+    drag: i32,    // percent of velocity lost per second
+    sizes: (i32, i32),
+    life: u8,     // frames
+    colors: [Rgb565; 3],
+}
+impl ParticleKind {
+    const fn spec(self) -> ParticleSpec {
+        match self {
+            Self::Debris => ParticleSpec {
+                speed: 1, gravity: 160, drag: 0, sizes: (1, 2), life: ms_to_frames(400) as u8,
+                colors: [Rgb565::WHITE, Rgb565::YELLOW, Rgb565::RED],
+            },
+            Self::Spark => ParticleSpec {
+                speed: 2, gravity: 0, drag: 300, sizes: (1, 1), life: ms_to_frames(250) as u8,
+                colors: [Rgb565::WHITE, Rgb565::YELLOW, Rgb565::new(31, 32, 0)],
+            },
+            Self::Smoke => ParticleSpec {
+                speed: 0, gravity: -40, drag: 100, sizes: (2, 3), life: ms_to_frames(800) as u8,
+                colors: [Rgb565::new(20, 40, 20), Rgb565::new(14, 28, 14), Rgb565::new(8, 16, 8)],
+            },
+            Self::Star => ParticleSpec {
+                speed: 1, gravity: 0, drag: 150, sizes: (1, 2), life: ms_to_frames(600) as u8,
+                colors: [Rgb565::WHITE, Rgb565::CYAN, Rgb565::GREEN],
+            },
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 struct Particle {
     x: i32,
     y: i32,
-    dx: i32,
-    dy: i32,
+    dx: i32, // px/s
+    dy: i32, // px/s
     life: u8,
+    size: i32,
+    kind: ParticleKind,
+}
+impl Slot for Particle {
+    fn active(&self) -> bool { self.life > 0 }
 }
 impl Particle {
-    const fn new() -> Self { Self { x: 0, y: 0, dx: 0, dy: 0, life: 0 } }
+    const fn new() -> Self {
+        Self { x: 0, y: 0, dx: 0, dy: 0, life: 0, size: 0, kind: ParticleKind::Debris }
+    }
+
+    fn color(&self) -> Rgb565 {
+        let spec = self.kind.spec();
+        if self.life > spec.life * 5 / 8 { spec.colors[0] }
+        else if self.life > spec.life / 4 { spec.colors[1] }
+        else { spec.colors[2] }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Explosion {
+    x: i32, // center
+    y: i32,
+    age: u8,
+    active: bool,
+}
+impl Slot for Explosion {
+    fn active(&self) -> bool { self.active }
+}
+impl Explosion {
+    const fn new() -> Self { Self { x: 0, y: 0, age: 0, active: false } }
+}
+
+#[derive(Clone, Copy)]
+struct Coin {
+    x: i32,
+    y: i32,
+    active: bool,
+}
+impl Slot for Coin {
+    fn active(&self) -> bool { self.active }
+}
+impl Coin {
+    const fn new() -> Self { Self { x: 0, y: 0, active: false } }
+}
+
+/// Points floating up from where they were scored; one at a time, the newest wins.
+struct Popup {
+    at: Point,
+    pts: u32,
+    color: Rgb565,
+    timer: u32, // frames
+}
+impl Popup {
+    const fn new() -> Self { Self { at: Point::zero(), pts: 0, color: Rgb565::WHITE, timer: 0 } }
+
+    fn show(&mut self, at: Point, pts: u32, color: Rgb565) {
+        *self = Self { at, pts, color, timer: ms_to_frames(POPUP_MS) };
+    }
+}
+
+/// Inert debris from an armored obstacle; blocks one missile, never the ship.
+#[derive(Clone, Copy)]
+struct Wreck {
+    x: i32,
+    y: i32,
+    life: u32, // frames
+    active: bool,
+}
+impl Slot for Wreck {
+    fn active(&self) -> bool { self.active }
+}
+impl Wreck {
+    const fn new() -> Self { Self { x: 0, y: 0, life: 0, active: false } }
+}
+
+#[derive(Clone, Copy)]
+struct Bullet {
+    x: i32,
+    y: i32,
+    dx: i32, // px/s
+    dy: i32, // px/s
+    active: bool,
+}
+impl Slot for Bullet {
+    fn active(&self) -> bool { self.active }
+}
+impl Bullet {
+    const fn new() -> Self { Self { x: 0, y: 0, dx: 0, dy: 0, active: false } }
+}
+
+/// One volley of an attack pattern.
+#[derive(Clone, Copy)]
+enum Volley {
+    /// `count` bullets at the ship, `spread` px/s apart sideways
+    Aimed { count: u8, spread: i32 },
+    /// `count` bullets fanned evenly over -`spread`..=`spread` px/s sideways
+    Fan { count: u8, spread: i32 },
+    /// A row across the field falling at `speed`, with a `gap` px opening somewhere
+    Curtain { gap: i32, speed: i32 },
+}
+
+/// `volleys` repeats of `volley`, `interval_ms` apart.
+struct Pattern {
+    volley: Volley,
+    volleys: u8,
+    interval_ms: u32,
+}
+
+/// Boss attacks, cycled in order.
+const BOSS_PATTERNS: [Pattern; 3] = [
+    Pattern { volley: Volley::Aimed { count: 3, spread: 20 }, volleys: 4, interval_ms: 500 },
+    Pattern { volley: Volley::Fan { count: 5, spread: 50 }, volleys: 3, interval_ms: 800 },
+    Pattern { volley: Volley::Curtain { gap: 36, speed: 45 }, volleys: 2, interval_ms: 1800 },
+];
+
+const MINI_BOSS_PATTERNS: [Pattern; 1] = [
+    Pattern { volley: Volley::Aimed { count: 1, spread: 0 }, volleys: 2, interval_ms: 900 },
+];
+
+/// Size, toughness and attack script of a boss tier.
+struct BossSpec {
+    w: i32,
+    h: i32,
+    y: i32,      // patrol row
+    hp: u8,
+    speed: i32,  // px/s sideways
+    points: u32, // for the kill, on top of 1 per hit
+    color: Rgb565,
+    patterns: &'static [Pattern],
+}
+const BOSS: BossSpec = BossSpec {
+    w: 40, h: 14, y: HUD_H + 6, hp: 20, speed: 30, points: 10, color: Rgb565::new(24, 8, 28), patterns: &BOSS_PATTERNS,
+};
+const MINI_BOSS: BossSpec = BossSpec {
+    w: 20, h: 10, y: HUD_H + 24, hp: 3, speed: 45, points: 5, color: Rgb565::new(8, 40, 24),
+    patterns: &MINI_BOSS_PATTERNS,
+};
+
+struct Boss {
+    spec: &'static BossSpec,
+    x: i32,
+    y: i32,
+    dx: i32, // px/s
+    hp: u8,
+    pattern: usize, // index into spec.patterns
+    volleys_left: u8,
+    timer: u32,     // frames to the next volley
+    flash: u32,     // frames of hit flash left
+    active: bool,
+}
+impl Boss {
+    const fn new() -> Self {
+        Self { spec: &BOSS, x: 0, y: 0, dx: 0, hp: 0, pattern: 0, volleys_left: 0, timer: 0, flash: 0, active: false }
+    }
+
+    /// Drops in from above the play area.
+    const fn spawn(spec: &'static BossSpec) -> Self {
+        Self {
+            spec, x: (SCREEN_W - spec.w) / 2, y: HUD_H - spec.h, dx: spec.speed, hp: spec.hp, pattern: 0,
+            volleys_left: spec.patterns[0].volleys, timer: ms_to_frames(PATTERN_PAUSE_MS), flash: 0, active: true,
+        }
+    }
+
+    fn cx(&self) -> i32 { self.x + self.spec.w / 2 }
+    fn cy(&self) -> i32 { self.y + self.spec.h / 2 }
+    fn arrived(&self) -> bool { self.y >= self.spec.y }
+
+    fn hit(&mut self, damage: u8) {
+        self.hp = self.hp.saturating_sub(damage);
+        self.flash = ms_to_frames(BOSS_HIT_MS);
+    }
+
+    /// Step the attack script one frame; returns the volley due now, if any.
+    fn next_volley(&mut self) -> Option<Volley> {
+        self.timer = self.timer.saturating_sub(1);
+        if self.timer > 0 { return None; }
+        let patterns = self.spec.patterns;
+        let p = &patterns[self.pattern];
+        self.volleys_left -= 1;
+        if self.volleys_left == 0 {
+            self.pattern = (self.pattern + 1) % patterns.len();
+            self.volleys_left = patterns[self.pattern].volleys;
+            self.timer = ms_to_frames(PATTERN_PAUSE_MS);
+        } else {
+            self.timer = ms_to_frames(p.interval_ms);
+        }
+        Some(p.volley)
+    }
+}
+
+/// Cover that soaks up obstacles; player missiles fly straight through.
+#[derive(Clone, Copy)]
+struct Barrier {
+    x: i32,
+    y: i32,
+    hp: u8,
+    life: u32, // frames left once it has come to rest
+    active: bool,
+}
+impl Barrier {
+    const fn new() -> Self { Self { x: 0, y: 0, hp: 0, life: 0, active: false } }
+
+    const fn spawn(x: i32) -> Self { Self { x, y: HUD_H, hp: BARRIER_HP, life: ms_to_frames(BARRIER_MS), active: true } }
+
+    /// Fades from steel toward rust as it takes hits.
+    fn color(&self) -> Rgb565 {
+        let lost = BARRIER_HP - self.hp;
+        Rgb565::new(16 + lost * 3, 36 - lost * 6, 20 - lost * 4)
+    }
 }
 
 #[derive(Clone, Copy)]
 struct Gift {
     x: i32,
     y: i32,
+    base_x: i32, // x before sway
+    y2: i32,     // y in half pixels; `y` is this rounded down
     life: u8,
+    kind: GiftKind,
     active: bool,
 }
+impl Slot for Gift {
+    fn active(&self) -> bool { self.active }
+}
 impl Gift {
-    const fn new() -> Self { Self { x: 0, y: 0, life: 0, active: false } }
+    const fn new() -> Self { Self { x: 0, y: 0, base_x: 0, y2: 0, life: 0, kind: GiftKind::Bomb, active: false } }
+    const fn spawn(base_x: i32, y: i32, kind: GiftKind) -> Self {
+        Self { x: base_x, y, base_x, y2: y * GIFT_SUBPX, life: GIFT_MAX_LIFE, kind, active: true }
+    }
+}
+
+/// Power-up carried by a gift, rolled at spawn so it shows before pickup.
+#[derive(Clone, Copy, PartialEq)]
+enum GiftKind {
+    Bomb,
+    Life,
+    Freeze,
+    Homing,
+    Laser,
+    Shield,
+    Twin,
+    /// Pulls nearby gifts and coins toward the ship's column
+    Magnet,
+    /// Trap: swaps left and right for a while
+    Reverse,
+}
+impl GiftKind {
+    /// The regular power-ups; the trap is rolled separately so it stays rare.
+    const ALL: [Self; 8] = [
+        Self::Bomb, Self::Life, Self::Freeze, Self::Homing, Self::Laser, Self::Shield, Self::Twin, Self::Magnet,
+    ];
+
+    fn random(rng: &mut Rng) -> Self {
+        if rng.range(100) < REVERSE_CHANCE { return Self::Reverse; }
+        Self::ALL[rng.range(Self::ALL.len() as i32) as usize]
+    }
+
+    fn color(self) -> Rgb565 {
+        match self {
+            Self::Bomb => Rgb565::new(0, 40, 0),
+            Self::Life => Rgb565::new(31, 20, 20),
+            Self::Freeze => Rgb565::BLUE,
+            Self::Homing => Rgb565::new(31, 40, 0),
+            Self::Laser => Rgb565::new(0, 63, 31),
+            Self::Shield => Rgb565::WHITE,
+            Self::Twin => Rgb565::MAGENTA,
+            Self::Magnet => Rgb565::new(16, 24, 31),
+            Self::Reverse => Rgb565::new(31, 0, 10),
+        }
+    }
+
+    /// Letter drawn on the box; the life gift gets a heart glyph instead.
+    fn label(self) -> &'static str {
+        match self {
+            Self::Bomb => "B",
+            Self::Life => "",
+            Self::Freeze => "F",
+            Self::Homing => "H",
+            Self::Laser => "L",
+            Self::Shield => "S",
+            Self::Twin => "W",
+            Self::Magnet => "M",
+            Self::Reverse => "R",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FillRect {
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    color: Rgb565,
+}
+
+/// Solid rects for one frame, composited row by row and streamed through a
+/// single address window (one CASET/RASET/RAMWR instead of three per rect).
+/// Later pushes draw on top; every pixel in the area is written exactly once.
+struct RectBatch {
+    rects: heapless::Vec<FillRect, MAX_BATCH_RECTS>,
+}
+impl RectBatch {
+    const fn new() -> Self { Self { rects: heapless::Vec::new() } }
+
+    fn push(&mut self, x: i32, y: i32, w: i32, h: i32, color: Rgb565) {
+        // Dropped when full; MAX_BATCH_RECTS covers every pool at once
+        let _ = self.rects.push(FillRect { x, y, w, h, color });
+    }
+
+    /// Hollow rect with a border `t` pixels thick, as four fills.
+    #[allow(clippy::too_many_arguments)]
+    fn push_ring(&mut self, x: i32, y: i32, w: i32, h: i32, t: i32, color: Rgb565) {
+        self.push(x, y, w, t, color);
+        self.push(x, y + h - t, w, t, color);
+        self.push(x, y + t, t, h - 2 * t, color);
+        self.push(x + w - t, y + t, t, h - 2 * t, color);
+    }
+
+    fn flush<D: DrawTarget<Color = Rgb565>>(&mut self, target: &mut D, area: Rectangle, bg: Rgb565) -> Result<(), D::Error> {
+        let x0 = area.top_left.x;
+        let y0 = area.top_left.y;
+        let w = area.size.width as usize;
+        let rects = &self.rects;
+        let pixels = (y0..y0 + area.size.height as i32).flat_map(move |y| {
+            let mut row = [bg; SCREEN_W as usize];
+            for r in rects.iter() {
+                if y < r.y || y >= r.y + r.h { continue; }
+                let sx = (r.x - x0).clamp(0, w as i32) as usize;
+                let ex = (r.x + r.w - x0).clamp(0, w as i32) as usize;
+                row[sx..ex.max(sx)].fill(r.color);
+            }
+            row.into_iter().take(w)
+        });
+        let result = target.fill_contiguous(&area, pixels);
+        self.rects.clear();
+        result
+    }
 }
 
 // --- xorshift32 PRNG ---
@@ -143,263 +1416,1607 @@ impl Rng {
     fn range(&mut self, max: i32) -> i32 { (self.next_u32() % max as u32) as i32 }
 }
 
-fn aabb_overlap(ax: i32, ay: i32, aw: i32, ah: i32, bx: i32, by: i32, bw: i32, bh: i32) -> bool {
-    ax < bx + bw && ax + aw > bx && ay < by + bh && ay + ah > by
+const fn secs_to_frames(s: u32) -> u32 { s * FPS }
+const fn ms_to_frames(ms: u32) -> u32 { ms * FPS / 1000 }
+
+/// Whole pixels to move this frame at `px_per_sec`. Steps are spread over
+/// each second so their sum is exact whatever the frame rate.
+fn step(px_per_sec: i32, frame: u32) -> i32 {
+    let f = (frame % FPS) as i32;
+    px_per_sec * (f + 1) / FPS as i32 - px_per_sec * f / FPS as i32
+}
+
+/// On for the first half of every `period_ms`, independent of FPS.
+fn blink(frame: u32, period_ms: u32) -> bool {
+    (frame as u64 * 1000 / FPS as u64) % (period_ms as u64) < period_ms as u64 / 2
+}
+
+/// `aabb_overlap` against the ship at (`px`, `py`), `pw` wide, counting both halves of a wrapped ship.
+fn ship_overlap(px: i32, py: i32, pw: i32, x: i32, y: i32, w: i32, h: i32) -> bool {
+    ship_copies(px, pw, SCREEN_W).any(|sx| aabb_overlap(sx, py, pw, PLAYER_H, x, y, w, h))
+}
+
+/// Raw temperature-sensor ADC reading to whole degrees Celsius
+/// (datasheet: 0.706 V at 27 C, -1.721 mV per C, 3.3 V reference).
+fn adc_to_celsius(raw: u16) -> i32 {
+    let mv = raw as i32 * 3300 / 4096;
+    27 - (mv - 706) * 1000 / 1721
 }
 
-fn spawn_particles(particles: &mut [Particle], rng: &mut Rng, cx: i32, cy: i32, count: u8) {
-    let mut spawned = 0u8;
-    for p in particles.iter_mut() {
-        if p.life == 0 && spawned < count {
-            p.x = cx + rng.range(10) - 5;
-            p.y = cy + rng.range(10) - 5;
-            p.dx = rng.range(7) - 3;
-            p.dy = rng.range(7) - 3;
-            if p.dx == 0 && p.dy == 0 { p.dy = -1; }
-            p.life = PARTICLE_LIFE;
-            spawned += 1;
+/// HAL config: stock 125 MHz, or `OVERCLOCK_HZ` with the `overclock` feature
+/// unless the previous boot died while initializing the display.
+fn hal_config() -> embassy_rp::config::Config {
+    #[cfg(feature = "overclock")]
+    {
+        // SAFETY: only reads scratch registers, before embassy_rp::init takes ownership
+        let mut watchdog = Watchdog::new(unsafe { embassy_rp::peripherals::WATCHDOG::steal() });
+        let display_failed = watchdog.reset_reason().is_some()
+            && watchdog.get_scratch(SCRATCH_BOOT) == BOOT_DISPLAY_INIT;
+        match ClockConfig::system_freq(OVERCLOCK_HZ) {
+            Ok(clocks) if !display_failed => return embassy_rp::config::Config::new(clocks),
+            Ok(_) => defmt::warn!("Display failed to init when overclocked, using stock clock"),
+            Err(_) => defmt::warn!("No PLL setting for {} Hz, using stock clock", OVERCLOCK_HZ),
         }
     }
+    embassy_rp::config::Config::default()
 }
 
-// --- Embassy bindings ---
-bind_interrupts!(struct Irqs {
-    USBCTRL_IRQ => UsbInterruptHandler<USB>;
-});
-
-#[embassy_executor::task]
-async fn logger_task(driver: Driver<'static, USB>) {
-    embassy_usb_logger::run!(1024, log::LevelFilter::Info, driver);
+/// Fastest SPI clock within the display's limit that clk_peri divides down to
+/// (the SPI prescaler is an even divider).
+fn display_spi_hz() -> u32 {
+    let peri = embassy_rp::clocks::clk_peri_freq();
+    peri / (2 * peri.div_ceil(2 * DISPLAY_SPI_MAX_HZ))
 }
 
-// --- Main ---
+/// Horizontal bar that only rewrites the columns whose fill changed.
+struct ProgressBar {
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    color: Rgb565,
+    filled: i32, // -1 forces a full redraw
+}
+impl ProgressBar {
+    const fn new(x: i32, y: i32, w: i32, h: i32, color: Rgb565) -> Self {
+        Self { x, y, w, h, color, filled: -1 }
+    }
+
+    /// Call after the screen under the bar was cleared.
+    fn invalidate(&mut self) { self.filled = -1; }
+
+    fn draw<D: DrawTarget<Color = Rgb565>>(&mut self, target: &mut D, value: u32, max: u32) -> Result<(), D::Error> {
+        let fill = if max == 0 { 0 } else { (value.min(max) as u64 * self.w as u64 / max as u64) as i32 };
+        if fill == self.filled { return Ok(()); }
+        let (from, to) = if self.filled < 0 { (0, self.w) } else { (fill.min(self.filled), fill.max(self.filled)) };
+        if fill > from {
+            Rectangle::new(Point::new(self.x + from, self.y), Size::new((fill - from) as u32, self.h as u32))
+                .into_styled(PrimitiveStyle::with_fill(self.color))
+                .draw(target)?;
+        }
+        let empty_from = from.max(fill);
+        if to > empty_from {
+            Rectangle::new(Point::new(self.x + empty_from, self.y), Size::new((to - empty_from) as u32, self.h as u32))
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
+                .draw(target)?;
+        }
+        self.filled = fill;
+        Ok(())
+    }
+}
+
+/// Backdrop for text drawn over a busy playfield.
+#[derive(Clone, Copy)]
+enum TextFx {
+    /// One copy offset down-right
+    Shadow,
+    /// Copies in all eight directions
+    Outline,
+}
+
+/// Draw `text` (top baseline) with a 1-px `fx` backdrop in `back` so it stays
+/// readable over particles and obstacles.
+fn draw_text_fx<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, text: &str, pos: Point, style: MonoTextStyle<'_, Rgb565>, fx: TextFx, back: Rgb565,
+) -> Result<(), D::Error> {
+    let mut back_style = style;
+    back_style.text_color = Some(back);
+    let offsets: &[(i32, i32)] = match fx {
+        TextFx::Shadow => &[(1, 1)],
+        TextFx::Outline => &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)],
+    };
+    for &(dx, dy) in offsets {
+        text::draw(target, text, pos + Point::new(dx, dy), back_style)?;
+    }
+    text::draw(target, text, pos, style)?;
+    Ok(())
+}
+
+/// Draw an 8x8 glyph over a solid background in one window write.
+fn draw_icon<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, icon: &[u8; 8], x: i32, y: i32, fg: Rgb565, bg: Rgb565,
+) -> Result<(), D::Error> {
+    let area = Rectangle::new(Point::new(x, y), Size::new(8, 8));
+    let pixels = icon.iter().flat_map(|row| (0..8).map(move |b| if row & (0x80 >> b) != 0 { fg } else { bg }));
+    target.fill_contiguous(&area, pixels)
+}
+
+/// `draw_icon` filling from the bottom: the lowest `rows` in `fg`, the rest in `dim`.
+#[allow(clippy::too_many_arguments)]
+fn draw_icon_fill<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, icon: &[u8; 8], x: i32, y: i32, fg: Rgb565, dim: Rgb565, bg: Rgb565, rows: usize,
+) -> Result<(), D::Error> {
+    let area = Rectangle::new(Point::new(x, y), Size::new(8, 8));
+    let pixels = icon.iter().enumerate().flat_map(|(r, row)| {
+        let c = if r + rows >= 8 { fg } else { dim };
+        (0..8).map(move |b| if row & (0x80 >> b) != 0 { c } else { bg })
+    });
+    target.fill_contiguous(&area, pixels)
+}
+
+/// Solid rect thinned by `BAYER4`: only `level` pixels in 16 are drawn, so whatever is
+/// under the rest shows through. The pattern is anchored to the screen so it holds still
+/// as the rect moves.
+fn fill_dithered<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, x: i32, y: i32, w: i32, h: i32, fg: Rgb565, level: u8,
+) -> Result<(), D::Error> {
+    let pixels = (y..y + h).flat_map(|py| {
+        (x..x + w).filter(move |&px| BAYER4[(py & 3) as usize][(px & 3) as usize] < level)
+            .map(move |px| Pixel(Point::new(px, py), fg))
+    });
+    target.draw_iter(pixels)
+}
+
+/// `c` at `pct` percent brightness.
+fn dim(c: Rgb565, pct: u32) -> Rgb565 {
+    let ch = |v: u8| (v as u32 * pct / 100) as u8;
+    Rgb565::new(ch(c.r()), ch(c.g()), ch(c.b()))
+}
+
+/// "x5" next to a HUD icon, padded with blanks to erase a longer count.
+fn draw_hud_count<D: DrawTarget<Color = Rgb565>>(target: &mut D, n: u8, x: i32, fg: Rgb565) -> Result<(), D::Error> {
+    let mut s = heapless::String::<4>::new();
+    s.push('x').ok();
+    push_u32(&mut s, n as u32);
+    while s.push(' ').is_ok() {}
+    tiny_font::draw(target, &s, x, 4, 1, fg, Rgb565::BLACK)
+}
+
+/// Append `n` in decimal. Avoids core::fmt, which is large and slow on the
+/// M0+; the HUD only ever prints unsigned integers after fixed prefixes.
+fn push_u32<const N: usize>(s: &mut heapless::String<N>, mut n: u32) {
+    let mut digits = [0u8; 10];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 { break; }
+    }
+    for &d in &digits[i..] {
+        if s.push(d as char).is_err() { break; }
+    }
+}
+
+// --- Display ---
+#[cfg(not(feature = "pio-display"))]
+type DisplayInterface<'a> =
+    SpiInterface<'a, ExclusiveDevice<Spi<'static, SPI0, Blocking>, Output<'static>, NoDelay>, Output<'static>>;
+#[cfg(feature = "pio-display")]
+type DisplayInterface<'a> = pio_display::PioInterface<'a, PIO0, 0>;
+type Screen<'a> = mipidsi::Display<DisplayInterface<'a>, ST7789, NoResetPin>;
+
+/// Peripherals owned by the display write path (SPI0, or PIO0 + DMA with `pio-display`)
+struct DisplayHw {
+    #[cfg(not(feature = "pio-display"))]
+    spi: Peri<'static, SPI0>,
+    #[cfg(feature = "pio-display")]
+    pio: Peri<'static, PIO0>,
+    #[cfg(feature = "pio-display")]
+    dma: Peri<'static, DMA_CH0>,
+    clk: Peri<'static, PIN_18>,
+    mosi: Peri<'static, PIN_19>,
+    cs: Peri<'static, PIN_17>,
+    dc: Peri<'static, PIN_16>,
+}
+
+fn init_display(hw: DisplayHw, buf: &mut [u8], flipped: bool) -> Screen<'_> {
+    let cs = Output::new(hw.cs, Level::High);
+    let dc = Output::new(hw.dc, Level::Low);
+    #[cfg(not(feature = "pio-display"))]
+    let di = {
+        let mut spi_config = spi::Config::default();
+        spi_config.frequency = display_spi_hz();
+        let spi_bus = Spi::new_blocking_txonly(hw.spi, hw.clk, hw.mosi, spi_config);
+        let spi_device = ExclusiveDevice::new_no_delay(spi_bus, cs).unwrap();
+        SpiInterface::new(spi_device, dc, buf)
+    };
+    #[cfg(feature = "pio-display")]
+    let di = {
+        let pio = Pio::new(hw.pio, Irqs);
+        pio_display::PioInterface::new(
+            pio.common, pio.sm0, hw.clk, hw.mosi, cs, dc, hw.dma.into(), buf, DISPLAY_SPI_MAX_HZ,
+        )
+    };
+    build_screen(di, flipped)
+}
+
+/// Run the controller init sequence on `di`. Orientation is only set here
+/// because mipidsi derives the panel offset from the builder's orientation.
+fn build_screen(di: DisplayInterface<'_>, flipped: bool) -> Screen<'_> {
+    let rotation = if flipped { Rotation::Deg270 } else { Rotation::Deg90 };
+    Builder::new(ST7789, di)
+        .display_size(135, 240)
+        .display_offset(52, 40)
+        .invert_colors(ColorInversion::Inverted)
+        .orientation(Orientation::new().rotate(rotation))
+        .init(&mut Delay)
+        .unwrap()
+}
+
+/// Full controller re-init (soft reset and init sequence) on a running display.
+fn reinit_screen(screen: Screen<'_>, flipped: bool) -> Screen<'_> {
+    let (di, _, _) = screen.release();
+    build_screen(di, flipped)
+}
+
+/// Logical (A, B, X, Y). Upside down, the A/B pair sits on the right, so the
+/// sides swap to keep B/A on the left and Y/X on the right.
+fn read_buttons(a: &Input, b: &Input, x: &Input, y: &Input, flipped: bool) -> (bool, bool, bool, bool) {
+    let (a, b, x, y) = (a.is_low(), b.is_low(), x.is_low(), y.is_low());
+    if flipped { (x, y, a, b) } else { (a, b, x, y) }
+}
+
+/// Spots a second press of the same button within `DOUBLE_TAP_MS`.
+struct DoubleTap {
+    left: u32, // frames the second press may still land in
+}
+impl DoubleTap {
+    const fn new() -> Self { Self { left: 0 } }
+
+    /// Feed this frame's just-pressed edge; true on the second tap.
+    fn update(&mut self, just: bool) -> bool {
+        self.left = self.left.saturating_sub(1);
+        if !just { return false; }
+        let double = self.left > 0;
+        self.left = if double { 0 } else { ms_to_frames(DOUBLE_TAP_MS) };
+        double
+    }
+}
+
+/// Backlight on PWM so state changes can fade through black.
+struct Backlight<'d> {
+    pwm: Pwm<'d>,
+    cfg: PwmConfig,
+    level: u16,
+}
+impl<'d> Backlight<'d> {
+    fn config() -> PwmConfig {
+        let mut cfg = PwmConfig::default();
+        // A compare of top + 1 holds the output high
+        cfg.top = BACKLIGHT_FULL * BACKLIGHT_FULL - 1;
+        cfg.compare_a = BACKLIGHT_FULL * BACKLIGHT_FULL;
+        cfg
+    }
+
+    fn new(pwm: Pwm<'d>) -> Self { Self { pwm, cfg: Self::config(), level: BACKLIGHT_FULL } }
+
+    fn set(&mut self, level: u16) {
+        self.level = level.min(BACKLIGHT_FULL);
+        self.cfg.compare_a = self.level * self.level;
+        self.pwm.set_config(&self.cfg);
+    }
+
+    /// Ramp to `level` a few steps at a time; returns at once if already there.
+    async fn fade(&mut self, level: u16) {
+        while self.level != level {
+            let next = if self.level < level { (self.level + FADE_LEVEL_STEP).min(level) }
+                else { self.level.saturating_sub(FADE_LEVEL_STEP).max(level) };
+            self.set(next);
+            Timer::after(Duration::from_millis(FADE_STEP_MS)).await;
+        }
+    }
+}
+
+/// Play-area frame with corner brackets. The brackets pulse faster and shift
+/// from green to red as the speed level rises; `flash` turns them white.
+fn push_border(batch: &mut RectBatch, level: u32, frame: u32, flash: bool, accent: Rgb565) {
+    let (x0, y0, w, h) = (0, HUD_H, SCREEN_W, SCREEN_H - HUD_H);
+    batch.push_ring(x0, y0, w, h, 1, accent);
+
+    let level = level.min(MAX_SPEED_LEVEL);
+    let period = 1200 - level * 200; // ms
+    let t = ((frame as u64 * 1000 / FPS as u64) % period as u64) as u32;
+    let pulse = (t.min(period - t) * 8 / period) as i32; // 0..=4
+    let r = (8 + level * 5) as i32 * (4 + pulse) / 8;
+    let g = (48 - level * 10) as i32 * (4 + pulse) / 8;
+    let c = if flash { Rgb565::WHITE } else { Rgb565::new(r as u8, g as u8, 4) };
+
+    let arm = 6 + pulse;
+    let (x1, y1) = (x0 + w, y0 + h);
+    batch.push(x0, y0, arm, 2, c);
+    batch.push(x0, y0, 2, arm, c);
+    batch.push(x1 - arm, y0, arm, 2, c);
+    batch.push(x1 - 2, y0, 2, arm, c);
+    batch.push(x0, y1 - 2, arm, 2, c);
+    batch.push(x0, y1 - arm, 2, arm, c);
+    batch.push(x1 - arm, y1 - 2, arm, 2, c);
+    batch.push(x1 - 2, y1 - arm, 2, arm, c);
+}
+
+/// `count` background stars drifting down at three speeds, spread by a hash of their index.
+fn push_stars(batch: &mut RectBatch, count: usize, frame: u32) {
+    let ms = frame as u64 * 1000 / FPS as u64;
+    let field_h = (SCREEN_H - HUD_H) as u64;
+    for i in 0..count as u32 {
+        let h = i.wrapping_mul(0x9E37_79B9);
+        let x = (h >> 8) % SCREEN_W as u32;
+        let speed = 8 + (i % 3) as u64 * 8; // px/s
+        let y = ((h >> 20) as u64 + ms * speed / 1000) % field_h;
+        batch.push(x as i32, HUD_H + y as i32, 1, 1, STAR_COLOR);
+    }
+}
+
+/// Up to `count` particles of one kind scattered around (cx, cy); free slots only.
+fn emit_burst(particles: &mut [Particle], rng: &mut Rng, kind: ParticleKind, cx: i32, cy: i32, count: u8) {
+    let spec = kind.spec();
+    for p in particles.free_slots(count as usize) {
+        p.x = cx + rng.range(10) - 5;
+        p.y = cy + rng.range(10) - 5;
+        p.dx = (rng.range(7) - 3) * PARTICLE_SPEED * spec.speed;
+        p.dy = (rng.range(7) - 3) * PARTICLE_SPEED * spec.speed;
+        if p.dx == 0 && p.dy == 0 && spec.speed > 0 { p.dy = -PARTICLE_SPEED; }
+        p.size = spec.sizes.0 + rng.range(spec.sizes.1 - spec.sizes.0 + 1);
+        p.life = spec.life;
+        p.kind = kind;
+    }
+}
+
+fn spawn_explosion(explosions: &mut [Explosion], cx: i32, cy: i32) {
+    if let Some(e) = explosions.free_slot() {
+        *e = Explosion { x: cx, y: cy, age: 0, active: true };
+    }
+}
+
+/// Leave a wreck where an armored obstacle went down.
+fn spawn_wreck(wrecks: &mut [Wreck], obs: &Obstacle) {
+    if obs.kind != ObstacleKind::Armored { return; }
+    if let Some(w) = wrecks.free_slot() {
+        *w = Wreck { x: obs.cx() - WRECK_W / 2, y: obs.cy() - WRECK_H / 2, life: ms_to_frames(WRECK_MS), active: true };
+    }
+}
+
+/// Sometimes leave a coin where an obstacle was shot down.
+fn drop_coin(coins: &mut [Coin], rng: &mut Rng, cx: i32, cy: i32) {
+    if rng.range(100) >= COIN_DROP_CHANCE { return; }
+    if let Some(c) = coins.free_slot() {
+        *c = Coin { x: cx - COIN_SIZE / 2, y: cy - COIN_SIZE / 2, active: true };
+    }
+}
+
+/// Fire one volley from (`cx`, `cy`) at the ship's nose `target`, inside
+/// the field `wall` px in from each side. Bullets past the pool size are dropped.
+fn fire_volley(bullets: &mut [Bullet], rng: &mut Rng, volley: Volley, cx: i32, cy: i32, target: Point, wall: i32) {
+    let mut fire = |x: i32, y: i32, dx: i32, dy: i32| {
+        if let Some(b) = bullets.free_slot() { *b = Bullet { x, y, dx, dy, active: true }; }
+    };
+    match volley {
+        Volley::Aimed { count, spread } => {
+            // Sideways speed that reaches the ship's column by the time it gets down there
+            let aim = ((target.x - cx) * BULLET_SPEED / (target.y - cy).max(1)).clamp(-2 * BULLET_SPEED, 2 * BULLET_SPEED);
+            for i in 0..count as i32 {
+                fire(cx - BULLET_SIZE / 2, cy, aim + (2 * i - (count as i32 - 1)) * spread / 2, BULLET_SPEED);
+            }
+        }
+        Volley::Fan { count, spread } => {
+            for i in 0..count as i32 {
+                let dx = if count > 1 { -spread + 2 * spread * i / (count as i32 - 1) } else { 0 };
+                fire(cx - BULLET_SIZE / 2, cy, dx, BULLET_SPEED);
+            }
+        }
+        Volley::Curtain { gap, speed } => {
+            let gap_x = wall + rng.range(SCREEN_W - 2 * wall - gap);
+            for x in (wall..SCREEN_W - wall - BULLET_SIZE).step_by(CURTAIN_SPACING as usize) {
+                if x + BULLET_SIZE > gap_x && x < gap_x + gap { continue; }
+                fire(x, HUD_H, 0, speed);
+            }
+        }
+    }
+}
+
+// --- Panic: draw an error screen, then reboot through the watchdog ---
+static PANICKING: AtomicBool = AtomicBool::new(false);
+/// Mirror of the flip setting for the panic screen
+static FLIPPED: AtomicBool = AtomicBool::new(false);
+
+#[panic_handler]
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    error!("{}", Display2Format(info));
+    // SAFETY: the game loop never resumes after a panic, so nothing else touches these
+    let p = unsafe { embassy_rp::Peripherals::steal() };
+    let mut watchdog = Watchdog::new(p.WATCHDOG);
+    watchdog.start(Duration::from_secs(4));
+    watchdog.set_scratch(SCRATCH_CAUSE, CAUSE_PANIC);
+
+    // A panic while drawing the error screen skips straight to the reset
+    if !PANICKING.load(Ordering::Relaxed) {
+        PANICKING.store(true, Ordering::Relaxed);
+        let mut buf = [0u8; 64];
+        let hw = DisplayHw {
+            #[cfg(not(feature = "pio-display"))]
+            spi: p.SPI0,
+            #[cfg(feature = "pio-display")]
+            pio: p.PIO0,
+            #[cfg(feature = "pio-display")]
+            dma: p.DMA_CH0,
+            clk: p.PIN_18,
+            mosi: p.PIN_19,
+            cs: p.PIN_17,
+            dc: p.PIN_16,
+        };
+        let mut display = init_display(hw, &mut buf, FLIPPED.load(Ordering::Relaxed));
+        display.clear(Rgb565::RED).unwrap();
+        let big = MonoTextStyle::new(&FONT_10X20, Rgb565::WHITE);
+        let small = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+        Text::with_baseline("CRASH!", Point::new(90, 20), big, Baseline::Top)
+            .draw(&mut display).unwrap();
+        let mut msg = heapless::String::<40>::new();
+        if let Some(loc) = info.location() {
+            msg.push_str(loc.file()).ok();
+            msg.push(':').ok();
+            push_u32(&mut msg, loc.line());
+        }
+        Text::with_baseline(&msg, Point::new(4, 60), small, Baseline::Top)
+            .draw(&mut display).unwrap();
+        Text::with_baseline("Rebooting...", Point::new(84, 100), small, Baseline::Top)
+            .draw(&mut display).unwrap();
+        embassy_time::block_for(Duration::from_secs(2));
+    }
+
+    watchdog.trigger_reset();
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
+/// Reboot through the watchdog without the crash notice.
+fn reboot(watchdog: &mut Watchdog) -> ! {
+    watchdog.set_scratch(SCRATCH_MAGIC, 0);
+    watchdog.trigger_reset();
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
+/// Show a red error screen with `code` and reboot through the watchdog, like
+/// the panic path; the crash notice on the next boot names the display.
+fn display_fault<D: DrawTarget<Color = Rgb565>>(display: &mut D, watchdog: &mut Watchdog, code: u8) -> ! {
+    log::error!("Display writes keep failing (code {}), rebooting", code);
+    watchdog.set_scratch(SCRATCH_CAUSE, CAUSE_DISPLAY);
+    let big = MonoTextStyle::new(&FONT_10X20, Rgb565::WHITE);
+    let small = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+    let mut msg = heapless::String::<16>::new();
+    msg.push_str("Code E").ok();
+    push_u32(&mut msg, code as u32);
+    // Best effort: the display is what's failing
+    let _ = display.clear(Rgb565::RED);
+    let _ = Text::with_baseline("DISPLAY ERROR", Point::new(55, 20), big, Baseline::Top).draw(display);
+    let _ = Text::with_baseline(&msg, Point::new(99, 60), small, Baseline::Top).draw(display);
+    let _ = Text::with_baseline("Rebooting...", Point::new(84, 100), small, Baseline::Top).draw(display);
+    watchdog.feed();
+    embassy_time::block_for(Duration::from_secs(2));
+    watchdog.trigger_reset();
+    loop {
+        cortex_m::asm::nop();
+    }
+}
+
+// --- Embassy bindings ---
+bind_interrupts!(struct Irqs {
+    USBCTRL_IRQ => UsbInterruptHandler<USB>;
+    #[cfg(feature = "pio-display")]
+    PIO0_IRQ_0 => PioInterruptHandler<PIO0>;
+});
+
+#[embassy_executor::task]
+async fn logger_task(driver: Driver<'static, USB>) {
+    use embassy_usb_logger::ReceiverHandler as _;
+    // Room for a full `dump` line of the largest value; debug is gated per channel by `logs`
+    embassy_usb_logger::run!(2048, log::LevelFilter::Debug, driver, console::Commands);
+}
+
+// --- Main ---
 #[embassy_executor::main]
 async fn main(spawner: Spawner) {
-    let p = embassy_rp::init(Default::default());
+    let p = embassy_rp::init(hal_config());
+
+    // A watchdog reset means the last run hung or panicked
+    let mut watchdog = Watchdog::new(p.WATCHDOG);
+    let crashed = watchdog.get_scratch(SCRATCH_MAGIC) == MAGIC_VALUE;
+    let recovered = match watchdog.reset_reason() {
+        Some(ResetReason::TimedOut) if crashed => Some(Msg::WatchdogTimeout),
+        Some(ResetReason::Forced) if crashed && watchdog.get_scratch(SCRATCH_CAUSE) == CAUSE_PANIC => {
+            Some(Msg::Panic)
+        }
+        Some(ResetReason::Forced) if crashed && watchdog.get_scratch(SCRATCH_CAUSE) == CAUSE_DISPLAY => {
+            Some(Msg::DisplayFault)
+        }
+        _ => None,
+    };
+    let last_score = watchdog.get_scratch(SCRATCH_SCORE);
+    watchdog.pause_on_debug(true);
+    watchdog.start(Duration::from_millis(BOOT_WATCHDOG_MS));
 
     let usb_driver = Driver::new(p.USB, Irqs);
     unwrap!(spawner.spawn(logger_task(usb_driver)));
     Timer::after(Duration::from_secs(2)).await;
     log::info!("=== Dodge & Shoot Game ===");
+    log::info!(
+        "System clock {} MHz, display SPI {} MHz",
+        embassy_rp::clocks::clk_sys_freq() / 1_000_000,
+        display_spi_hz() / 1_000_000
+    );
 
-    let mut led = Output::new(p.PIN_25, Level::Low);
+    let buzzer = Buzzer::new(Pwm::new_output_a(p.PWM_SLICE3, p.PIN_22, Buzzer::config()));
+    // The RGB LED's blue leg shares GP8 with the SD card
+    #[cfg(not(feature = "sd-log"))]
+    let rgb_blue = Some(Output::new(p.PIN_8, Level::High));
+    #[cfg(feature = "sd-log")]
+    let rgb_blue = None;
+    let rgb = [Some(Output::new(p.PIN_6, Level::High)), Some(Output::new(p.PIN_7, Level::High)), rgb_blue];
+    let vibe = Some(Output::new(p.PIN_3, Level::Low));
+    let mut feedback = Feedback::new(buzzer, rgb, vibe, Output::new(p.PIN_25, Level::Low));
 
-    let _bl = Output::new(p.PIN_20, Level::High);
-    let mut spi_config = spi::Config::default();
-    spi_config.frequency = 62_500_000;
-    let spi_bus = Spi::new_blocking_txonly(p.SPI0, p.PIN_18, p.PIN_19, spi_config);
-    let cs_display = Output::new(p.PIN_17, Level::High);
-    let dc = Output::new(p.PIN_16, Level::Low);
-    let spi_device = ExclusiveDevice::new_no_delay(spi_bus, cs_display).unwrap();
+    let mut backlight = Backlight::new(Pwm::new_output_a(p.PWM_SLICE2, p.PIN_20, Backlight::config()));
     static DISPLAY_BUF: StaticCell<[u8; 1024]> = StaticCell::new();
     let display_buf = DISPLAY_BUF.init([0u8; 1024]);
-    let di = mipidsi::interface::SpiInterface::new(spi_device, dc, display_buf);
-    let mut display = Builder::new(ST7789, di)
-        .display_size(135, 240)
-        .display_offset(52, 40)
-        .invert_colors(ColorInversion::Inverted)
-        .orientation(Orientation::new().rotate(Rotation::Deg90))
-        .init(&mut Delay)
-        .unwrap();
-    display.clear(Rgb565::BLACK).unwrap();
+    // Everything persistent lives in the store memory.x keeps clear of the program
+    #[cfg(not(feature = "fram"))]
+    let mut store = Store::mount(StorageFlash::new_blocking(p.FLASH));
+    // ...or on the external FRAM
+    #[cfg(feature = "fram")]
+    let mut store = Store::mount(fram::Fram::new(I2c::new_blocking(p.I2C0, p.PIN_5, p.PIN_4, fram::Fram::config())));
+    settings::migrate(&mut store);
+    #[cfg(feature = "sd-log")]
+    let mut sd_log = sdlog::SdLog::new(p.SPI1, p.PIN_10, p.PIN_11, p.PIN_8, p.PIN_9);
+    let mut settings = Settings::load(&mut store);
+    let mut saved_settings = settings;
+    let mut progress = Progress::load(&mut store);
+    let mut saved_progress = progress;
+    // A run paused before the last power-off, offered before the title
+    let mut saved_run = Snapshot::load(&mut store);
+    let mut tracker = Tracker::new(progress.achievements);
+    // Gameplay events for this frame, drained into the achievement tracker
+    let mut events = heapless::Vec::<Event, 32>::new();
+    let mut toasts = heapless::Deque::<Achievement, 4>::new();
+    let mut toast: Option<Achievement> = None;
+    let mut toast_timer: u32 = 0;
+    let mut popup = Popup::new();
+    let mut missions = [Mission::new(MissionKind::Destroy); missions::PER_RUN];
+    FLIPPED.store(settings.flipped, Ordering::Relaxed);
+
+    // Flag the display init so a crash here falls back to the stock clock next boot
+    watchdog.set_scratch(SCRATCH_BOOT, BOOT_DISPLAY_INIT);
+    let display_hw = DisplayHw {
+        #[cfg(not(feature = "pio-display"))]
+        spi: p.SPI0,
+        #[cfg(feature = "pio-display")]
+        pio: p.PIO0,
+        #[cfg(feature = "pio-display")]
+        dma: p.DMA_CH0,
+        clk: p.PIN_18,
+        mosi: p.PIN_19,
+        cs: p.PIN_17,
+        dc: p.PIN_16,
+    };
+    let mut display = Guarded::new(init_display(display_hw, display_buf, settings.flipped));
+    display.erase_all();
+    watchdog.set_scratch(SCRATCH_BOOT, 0);
+
+    if let Some(reason) = recovered {
+        log::warn!("Recovered from crash ({}), last score: {}", reason.text(Lang::En), last_score);
+        let lang = Lang::from_index(settings.lang);
+        let big = MonoTextStyle::new(&FONT_10X20, Rgb565::YELLOW);
+        let small = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+        text::draw_aligned(&mut display, Msg::RecoveredFrom.text(lang), Point::new(SCREEN_W / 2, 15), big,
+            Alignment::Center).unwrap();
+        text::draw_aligned(&mut display, Msg::Crash.text(lang), Point::new(SCREEN_W / 2, 37), big,
+            Alignment::Center).unwrap();
+        text::draw(&mut display, reason.text(lang), Point::new(70, 70), small).unwrap();
+        let mut msg = heapless::String::<32>::new();
+        msg.push_str(Msg::LastScore.text(lang)).ok();
+        push_u32(&mut msg, last_score);
+        text::draw(&mut display, &msg, Point::new(70, 90), small).unwrap();
+        Timer::after(Duration::from_secs(3)).await;
+    }
+
+    watchdog.set_scratch(SCRATCH_MAGIC, MAGIC_VALUE);
+    watchdog.set_scratch(SCRATCH_CAUSE, 0);
+    watchdog.set_scratch(SCRATCH_SCORE, 0);
+    watchdog.start(Duration::from_millis(WATCHDOG_TIMEOUT_MS));
 
     // Buttons: [A][X] top, [B][Y] bottom
-    let btn_a = Input::new(p.PIN_12, Pull::Up);
-    let btn_b = Input::new(p.PIN_13, Pull::Up);
-    let btn_x = Input::new(p.PIN_14, Pull::Up);
-    let btn_y = Input::new(p.PIN_15, Pull::Up);
+    let mut btn_a = Input::new(p.PIN_12, Pull::Up);
+    let mut btn_b = Input::new(p.PIN_13, Pull::Up);
+    let mut btn_x = Input::new(p.PIN_14, Pull::Up);
+    let mut btn_y = Input::new(p.PIN_15, Pull::Up);
+
+    // Internal temperature sensor
+    let mut adc = Adc::new_blocking(p.ADC, AdcConfig::default());
+    let mut temp_sensor = AdcChannel::new_temp_sensor(p.ADC_TEMP_SENSOR);
+    let mut chip_temp: i32 = 0;
+    let mut overheat = false;
+    let mut prev_title_temp: i32 = i32::MAX;
 
     // --- Game variables ---
-    let mut game_state = GameState::Title;
+    let mut game_state = if saved_run.is_some() { GameState::Resume } else { GameState::Title };
     let mut prev_state = GameState::Playing;
-    let mut player_x: i32 = (SCREEN_W - PLAYER_W) / 2;
+    // Ship class of the run, an index into `ships::PROFILES`
+    let mut profile: usize = 0;
+    let mut player_x: i32 = (SCREEN_W - ships::PROFILES[0].width) / 2;
+    let mut player_y: i32 = PLAYER_Y;
     let mut obstacles = [Obstacle::new(); MAX_OBS];
     let mut missiles = [Missile::new(); MAX_MISSILES];
     let mut particles = [Particle::new(); MAX_PARTICLES];
+    let mut explosions = [Explosion::new(); MAX_EXPLOSIONS];
     let mut gifts = [Gift::new(); MAX_GIFTS];
+    let mut coins = [Coin::new(); MAX_COINS];
+    let mut barrier = Barrier::new();
+    let mut boss = Boss::new();
+    let mut mini = Boss::new();
+    let mut next_mini_score: u32 = MINI_BOSS_POINTS;
+    let mut boss_wave: u32 = 0;
+    let mut tally: u32 = 0;
+    let mut end_idle: u32 = 0;
+    // Wall-clock start of the current session: from the first run after the title back to the title
+    let mut session_start: Option<Instant> = None;
+    let mut credits_scroll: i32 = 0;
+    let mut bullets = [Bullet::new(); MAX_BULLETS];
+    let mut wrecks = [Wreck::new(); MAX_WRECKS];
     let mut score: u32 = 0;
     let mut lives: u8 = MAX_LIVES;
     let mut bombs: u8 = MAX_BOMBS;
+    let mut max_bombs: u8 = MAX_BOMBS;
+    let mut run_kills: u32 = 0;
+    // Where this run's obstacles were destroyed or got through, dumped at game over
+    let mut heat = heatmap::Heatmap::new();
+    // Per-run stats for the game over log line
+    let mut run_shots: u32 = 0;
+    let mut run_hits: u32 = 0;
+    let mut run_frames: u32 = 0;
+    let mut run_seed: u32 = 0;
+    // Serial test cheats: `god` blocks all damage; any cheat keeps the run off the records
+    let mut god = false;
+    let mut run_cheated = false;
     let mut spawn_timer: u32 = 0;
+    // Lane mode: the lane the ship is headed for, and frames a direction has been held
+    let mut lane: i32 = LANES / 2;
+    let mut lane_repeat: u32 = 0;
+    // Where the ship was on the last few frames of a full-speed streak, oldest first
+    let mut trail = heapless::Deque::<(i32, i32), TRAIL_LEN>::new();
+    let mut fast_streak: u32 = 0;
+    // Left and right strafe velocity, as a share of the ship's speed
+    let mut hold_vel = [0i32; 2];
+    // Frames a due spawn has waited on a full obstacle pool
+    let mut spawn_held: u32 = 0;
     let mut gift_spawn_timer: u32 = 0;
     let mut freeze_timer: u32 = 0;
     let mut homing_timer: u32 = 0;
     let mut laser_timer: u32 = 0;
     let mut shield_timer: u32 = 0;
+    let mut twin_timer: u32 = 0;
+    let mut magnet_timer: u32 = 0;
+    let mut reverse_timer: u32 = 0;
     let mut rng = Rng::new(12345);
     let mut rng_seeded = false;
     let mut invincible: u32 = 0;
+    let mut dash_cooldown: u32 = 0;
+    let mut charge: u32 = 0;
+    let mut bomb_regen: u32 = 0;
     let mut frame: u32 = 0;
+    // Debug overlay (serial `overlay`): smoothed frame period and busy time
+    let mut overlay = false;
+    // Collision rectangle outlines (serial `hitbox`)
+    let mut hitboxes = false;
+    // Latency probe (serial `latency`): when the last button edge landed, and whether the corner is lit
+    let mut latency = false;
+    let mut edge_at: Option<Instant> = None;
+    let mut latency_lit = false;
+    // Serial `tas` script: fed in place of the buttons while `tas_play`; `tas_run` keeps the run off the records
+    let mut script = tas::Script::new();
+    let mut tas_play = false;
+    let mut tas_run = false;
+    let mut prev_frame_start = Instant::now();
+    let mut period_us: u64 = FRAME_US;
+    let mut busy_us: u64 = 0;
     let mut demo_mode = false;
+    let mut tutorial: Option<tutorial::Step> = None;
+    let mut tutorial_moved = (false, false);
+    let mut tutorial_done_timer: u32 = 0;
+    let mut practice = false;
+    let mut practice_y_hold: u32 = 0;
     let mut prev_score: u32 = u32::MAX;
     let mut prev_lives: u8 = u8::MAX;
     let mut prev_bombs: u8 = u8::MAX;
-    let mut prev_power: u8 = u8::MAX;
+    let mut prev_regen_rows = usize::MAX;
+    let mut prev_power = [u32::MAX; 8];
+    // Forces a full HUD repaint, e.g. after a dropped frame
+    let mut hud_stale = false;
+    // Timer bars along the bottom of the HUD, under the power-up list
+    let mut shield_bar = ProgressBar::new(122, HUD_H - 2, 37, 2, Rgb565::WHITE);
+    let mut twin_bar = ProgressBar::new(162, HUD_H - 2, 37, 2, GiftKind::Twin.color());
+    // Boss health along the bottom of the HUD under the score, a mini-boss's at half scale under the bombs
+    let mut boss_bar = ProgressBar::new(2, HUD_H - 2, 76, 2, BOSS.color);
+    let mut mini_bar = ProgressBar::new(80, HUD_H - 2, 38, 2, MINI_BOSS.color);
+    let mut dash_bar = ProgressBar::new(204, HUD_H - 2, 34, 2, Rgb565::CYAN);
     let mut prev_a = false;
     let mut prev_b = false;
     let mut prev_x = false;
     let mut prev_y = false;
-    let mut buf = heapless::String::<32>::new();
-    let mut high_score: u32 = 0;
+    let mut tap_b = DoubleTap::new();
+    let mut tap_y = DoubleTap::new();
+    let mut buf = heapless::String::<64>::new();
+    let mut batch = RectBatch::new();
+    let mut high_score = progress.best_score;
+    let mut title_armed = false;
+    let mut options_armed = false;
+    let mut unlocks_armed = false;
+    let mut tutorial_armed = false;
+    let mut practice_armed = false;
+    let mut two_player_armed = false;
+    // Hot-seat 2P: players take whole runs in turn
+    let mut two_player = false;
+    let mut player: usize = 0;
+    let mut player_scores = [0u32; 2];
+    let mut start_run = false;
+    let mut hangar_sel: usize = 0;
+    let mut hangar_dirty = false;
+    let mut opt_sel: usize = 0;
+    let mut opt_dirty = false;
+    let mut opt_redraw = false;
+    let mut bootsel_hold: u32 = 0;
+    let mut reset_hold: u32 = 0;
+    let mut erase_armed = false;
+    let mut reinit_hold: u32 = 0;
+    let mut pause_hold: u32 = 0;
+    let mut afk_frames: u32 = 0;
+    let mut pause_idle: u32 = 0;
+    let mut title_idle: u32 = 0;
+    let mut hud_shift = HUD_SHIFTS[0];
+    let mut screen_idle: u32 = 0;
+    let mut saver_from = GameState::Title;
+    let mut saver_pos = Point::zero();
+    let mut saver_dir = Point::new(1, 1);
+    let mut saver_color: usize = 0;
     let mut speed_base_score: u32 = 0;
+    let mut danger_tint: u8 = 0;
+    let mut heartbeat: u32 = 0;
+    let mut warn_cooldown: u32 = 0;
+    let mut speed_level: u32 = 0;
+    let mut speed_banner: u32 = 0;
+    let mut loop_count: u32 = 1;
+    let mut loop_banner: u32 = 0;
+    // Score zone (see `zones`) and frames to the next bass pulse
+    let mut zone_idx: usize = 0;
+    let mut beat_timer: u32 = 0;
+    let mut perfect_banner: u32 = 0;
+    // Per-wave tracking for the perfect bonus, which doesn't count toward wave progress
+    let mut wave_leaks: u32 = 0;
+    // Obstacles past the bottom this run, and whether the leak limit option applies to it
+    let mut run_leaks: u32 = 0;
+    let mut leak_limit = false;
+    let mut prev_leaks = u32::MAX;
+    let mut wave_hit = false;
+    let mut perfect_points: u32 = 0;
+    let mut wallet: u32 = 0;
+    let mut bolts: u8 = 0;
+    let mut next_shop_score: u32 = WAVE_POINTS;
+    // Difficulty points a late-wave start has that the score doesn't show
+    let mut score_offset: u32 = 0;
+    let mut start_wave: u32 = 1;
+    let mut wave_dirty = false;
+    let mut assist_level: u32 = 0;
+    let mut short_runs: u32 = 0;
+    let mut scheduler = Scheduler::new();
+    let mut rock_timer: u32 = 0;
+    let mut blast_x: i32 = 0;
+    let mut blast_timer: u32 = 0;
+    let mut shop_sel: usize = 0;
+    let mut shop_dirty = false;
 
     // Text styles
     let big_yellow = MonoTextStyle::new(&FONT_10X20, Rgb565::YELLOW);
     let big_white = MonoTextStyle::new(&FONT_10X20, Rgb565::WHITE);
     let big_red = MonoTextStyle::new(&FONT_10X20, Rgb565::RED);
 
-    // Colors
-    let player_color = Rgb565::CYAN;
-    let obs_color = Rgb565::RED;
-    let missile_color = Rgb565::YELLOW;
-    let homing_color = Rgb565::new(31, 40, 0);
-    let laser_color = Rgb565::new(0, 63, 31);
-    let life_on = Rgb565::RED;
-    let life_off = Rgb565::new(4, 8, 4);
-    let bomb_on = Rgb565::new(0, 31, 0);
-    let bomb_off = Rgb565::new(2, 8, 2);
-
     log::info!("Entering game loop");
 
     loop {
         let frame_start = Instant::now();
+        display.begin_frame();
+        period_us = (period_us * 7 + (frame_start - prev_frame_start).as_micros()) / 8;
+        prev_frame_start = frame_start;
+        let theme = &THEMES[settings.theme as usize % THEMES.len()];
+        let ship = &ships::PROFILES[profile];
+        let lang = Lang::from_index(settings.lang);
+
+        // Burn-in guard: a menu nobody has touched in a while gives way to the screensaver
+        if SAVER_STATES.contains(&game_state) && game_state == prev_state {
+            screen_idle = if prev_a || prev_b || prev_x || prev_y { 0 } else { screen_idle + 1 };
+            if screen_idle >= SAVER_IDLE_FRAMES {
+                saver_from = game_state;
+                game_state = GameState::Screensaver;
+            }
+        } else {
+            screen_idle = 0;
+        }
 
-        let a_down = btn_a.is_low();
-        let b_down = btn_b.is_low();
-        let x_down = btn_x.is_low();
-        let y_down = btn_y.is_low();
+        // Fade out before a state's entry code clears and redraws the screen
+        if game_state != prev_state { backlight.fade(0).await; }
+
+        let mut pins = read_buttons(&btn_a, &btn_b, &btn_x, &btn_y, settings.flipped);
+        if tas_play && !matches!(game_state, GameState::Playing | GameState::Paused) {
+            tas_play = false;
+            log::info!("TAS run over at frame {} with {} points", frame, score);
+        } else if tas_play {
+            match script.next() {
+                Some(held) => pins = held,
+                None => {
+                    tas_play = false;
+                    log::info!("TAS script done at frame {}; the buttons are back", frame);
+                }
+            }
+        }
+        let (a_down, b_down, x_down, y_down) = pins;
         let a_just = a_down && !prev_a;
         let b_just = b_down && !prev_b;
         let x_just = x_down && !prev_x;
         let y_just = y_down && !prev_y;
+        if a_down != prev_a || b_down != prev_b || x_down != prev_x || y_down != prev_y {
+            clog!(Input, Debug, "f={} A{} B{} X{} Y{}", frame, a_down as u8, b_down as u8, x_down as u8, y_down as u8);
+        }
         prev_a = a_down;
         prev_b = b_down;
         prev_x = x_down;
         prev_y = y_down;
+        let b_double = tap_b.update(b_just);
+        let y_double = tap_y.update(y_just);
+        // Hold all four to recover a garbled display (e.g. after a brown-out) mid-run
+        reinit_hold = if a_down && b_down && x_down && y_down { reinit_hold + 1 } else { 0 };
+        let mut reinit = reinit_hold == REINIT_HOLD_FRAMES;
+
+        // A USB serial command (store backup and restore) between frames
+        if let Some(line) = console::take() {
+            match console::run(&line, &mut store) {
+                console::Action::Reboot => {
+                    log::info!("Rebooting");
+                    reboot(&mut watchdog);
+                }
+                console::Action::ToggleOverlay => {
+                    overlay = !overlay;
+                    log::info!("Overlay {}", if overlay { "on" } else { "off" });
+                }
+                console::Action::ToggleHitboxes => {
+                    hitboxes = !hitboxes;
+                    log::info!("Hitboxes {}", if hitboxes { "on" } else { "off" });
+                }
+                console::Action::ToggleLatency => {
+                    latency = !latency;
+                    edge_at = None;
+                    log::info!("Latency probe {}", if latency { "on" } else { "off" });
+                }
+                console::Action::ToggleGod => {
+                    god = !god;
+                    log::info!("God mode {}", if god { "on" } else { "off" });
+                }
+                console::Action::Spawn(_, _) if game_state != GameState::Playing => log::warn!("Spawn only works in a run"),
+                console::Action::Spawn(entity, x) => {
+                    run_cheated = true;
+                    let col = x.unwrap_or(player_x + ship.width / 2);
+                    match entity {
+                        console::Entity::Obstacle(kind) => {
+                            let x = (col - kind.size().0 / 2).clamp(0, SCREEN_W - kind.size().0);
+                            if let Some(obs) = obstacles.free_slot() {
+                                *obs = match kind {
+                                    ObstacleKind::Falling => Obstacle::falling(x),
+                                    ObstacleKind::Side => Obstacle::side(col < SCREEN_W / 2),
+                                    ObstacleKind::Bouncer => Obstacle::bouncer(x, col < SCREEN_W / 2),
+                                    ObstacleKind::Rock => Obstacle::rock(x),
+                                    ObstacleKind::Meteor => Obstacle::meteor(x),
+                                    ObstacleKind::Armored => Obstacle::armored(x),
+                                    ObstacleKind::Shooter => Obstacle::shooter(x),
+                                };
+                            } else {
+                                log::warn!("Obstacle pool full");
+                            }
+                        }
+                        console::Entity::Gift(kind) => {
+                            let base_x = (col - GIFT_W / 2).clamp(GIFT_SWAY_MAX, SCREEN_W - GIFT_W - GIFT_SWAY_MAX);
+                            if let Some(g) = gifts.free_slot() {
+                                *g = Gift::spawn(base_x, HUD_H, kind);
+                            } else {
+                                log::warn!("Gift pool full");
+                            }
+                        }
+                    }
+                }
+                console::Action::Tas(tas::Cmd::Seed(seed)) => {
+                    script.clear(seed);
+                    log::info!("TAS script cleared, seed {:08x}", seed);
+                }
+                console::Action::Tas(tas::Cmd::Step(f, held)) => {
+                    if let Err(e) = script.push(f, held) { log::warn!("TAS step at frame {}: {}", f, e); }
+                }
+                console::Action::Tas(tas::Cmd::Play) if game_state != GameState::Title => log::warn!("TAS plays from the title"),
+                console::Action::Tas(tas::Cmd::Play) if script.is_empty() => log::warn!("TAS script is empty"),
+                console::Action::Tas(tas::Cmd::Play) => {
+                    demo_mode = false;
+                    practice = false;
+                    tutorial = None;
+                    two_player = false;
+                    player = 0;
+                    player_scores = [0; 2];
+                    tas_play = true;
+                    start_run = true;
+                }
+                console::Action::Tas(tas::Cmd::Stop) => {
+                    if tas_play { log::info!("TAS stopped at frame {}", frame); }
+                    tas_play = false;
+                }
+                console::Action::Tas(tas::Cmd::Status) => {
+                    log::info!("TAS seed {:08x}, {} steps to frame {}", script.seed, script.len(), script.end().unwrap_or(0));
+                }
+                console::Action::None => {}
+            }
+        }
 
         if !rng_seeded && (a_down || b_down || x_down || y_down) {
             rng = Rng::new(Instant::now().as_ticks() as u32);
             rng_seeded = true;
         }
 
+        if frame.is_multiple_of(TEMP_SAMPLE_FRAMES) {
+            if let Ok(raw) = adc.blocking_read(&mut temp_sensor) {
+                chip_temp = adc_to_celsius(raw);
+            }
+            if overheat != (chip_temp >= OVERHEAT_C) {
+                overheat = chip_temp >= OVERHEAT_C;
+                log::info!("Chip temperature {} C, overheat {}", chip_temp, overheat);
+            }
+        }
+
         match game_state {
             // ==================== TITLE ====================
             GameState::Title => {
                 if prev_state != GameState::Title {
-                    display.clear(Rgb565::BLACK).unwrap();
-                    Text::with_baseline("DODGE!", Point::new(80, 15), big_yellow, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    Text::with_baseline("B:Left Y:Right", Point::new(50, 45), big_white, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    Text::with_baseline("A:Fire X:Fire", Point::new(50, 70), big_white, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    Text::with_baseline("Press any button", Point::new(20, 105), big_white, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    led.set_low();
+                    display.erase_all();
+                    draw_text_fx(&mut display, "DODGE!", Point::new(80, 15), big_yellow, TextFx::Outline, Rgb565::RED)
+                        .unwrap();
+                    if progress.cleared {
+                        draw_icon(&mut display, &ICON_STAR, 146, 21, Rgb565::YELLOW, Rgb565::BLACK).unwrap();
+                    }
+                    text::draw(&mut display, Msg::MoveHelp.text(lang), Point::new(50, 45), big_white).unwrap();
+                    text::draw(&mut display, Msg::FireHelp.text(lang), Point::new(50, 70), big_white).unwrap();
+                    // Small font so every title combo fits on one line
+                    let combos = Msg::Combos.text(lang);
+                    let x = text::place(text::tiny_width(combos), SCREEN_W / 2, Alignment::Center);
+                    text::draw_tiny(&mut display, combos, x, 95, Rgb565::new(16, 32, 16), Rgb565::BLACK)
+                        .unwrap();
+                    text::draw(&mut display, Msg::PressAny.text(lang), Point::new(20, 107), big_white).unwrap();
+                    feedback.set_led(false);
+                    title_armed = false;
+                    options_armed = false;
+                    unlocks_armed = false;
+                    tutorial_armed = false;
+                    practice_armed = false;
+                    two_player_armed = false;
+                    bootsel_hold = 0;
+                    title_idle = 0;
+                    prev_title_temp = i32::MAX;
                     prev_state = GameState::Title;
                     log::info!("Title screen");
+                    if let Some(t) = session_start.take() { log::info!("Session lasted {} s", t.elapsed().as_secs()); }
+                }
+
+                // Overheat easter egg: show the chip temperature next to the logo
+                let title_temp = if overheat { chip_temp } else { 0 };
+                if title_temp != prev_title_temp {
+                    display.erase(Rectangle::new(Point::new(146, 15), Size::new(94, 20)));
+                    if overheat {
+                        buf.clear();
+                        buf.push_str(Msg::Hot.text(lang)).ok();
+                        push_u32(&mut buf, chip_temp.max(0) as u32);
+                        buf.push('C').ok();
+                        let s = MonoTextStyle::new(&FONT_6X10, Rgb565::new(31, 32, 0));
+                        text::draw(&mut display, &buf, Point::new(150, 20), s).unwrap();
+                    }
+                    prev_title_temp = title_temp;
+                }
+
+                // Keep holding all four past the display re-init for a factory reset
+                if a_down && b_down && x_down && y_down {
+                    title_armed = false;
+                    options_armed = false;
+                    unlocks_armed = false;
+                    tutorial_armed = false;
+                    practice_armed = false;
+                    two_player_armed = false;
+                    reset_hold += 1;
+                    // The re-init has just redrawn the title
+                    if reset_hold == REINIT_HOLD_FRAMES + 1 {
+                        display.erase(Rectangle::new(Point::new(0, 100), Size::new(SCREEN_W as u32, 35)));
+                        text::draw(&mut display, Msg::HoldErase.text(lang), Point::new(20, 107), big_red).unwrap();
+                    }
+                    if reset_hold >= FACTORY_RESET_HOLD_FRAMES { game_state = GameState::FactoryReset; }
+                } else if reset_hold > 0 {
+                    if reset_hold > REINIT_HOLD_FRAMES {
+                        display.erase(Rectangle::new(Point::new(0, 100), Size::new(SCREEN_W as u32, 35)));
+                        text::draw(&mut display, Msg::PressAny.text(lang), Point::new(20, 107), big_white).unwrap();
+                    }
+                    reset_hold = 0;
+                }
+
+                // Hold B+Y+X to reboot into the ROM's USB bootloader (with A it's the display re-init)
+                if b_down && y_down && x_down && !a_down {
+                    title_armed = false;
+                    options_armed = false;
+                    unlocks_armed = false;
+                    tutorial_armed = false;
+                    practice_armed = false;
+                    two_player_armed = false;
+                    bootsel_hold += 1;
+                    if bootsel_hold == 1 {
+                        display.erase(Rectangle::new(Point::new(0, 100), Size::new(SCREEN_W as u32, 35)));
+                        text::draw(&mut display, Msg::HoldBootsel.text(lang), Point::new(20, 107), big_red).unwrap();
+                    }
+                    if bootsel_hold >= BOOTSEL_HOLD_FRAMES {
+                        display.erase_all();
+                        text::draw_aligned(&mut display, Msg::BootselMode.text(lang), Point::new(SCREEN_W / 2, 55), big_yellow,
+                            Alignment::Center).unwrap();
+                        log::info!("Rebooting to BOOTSEL");
+                        Timer::after(Duration::from_millis(100)).await;
+                        embassy_rp::rom_data::reset_to_usb_boot(1 << 25, 0);
+                    }
+                } else if bootsel_hold > 0 {
+                    bootsel_hold = 0;
+                    display.erase(Rectangle::new(Point::new(0, 100), Size::new(SCREEN_W as u32, 35)));
+                    text::draw(&mut display, Msg::PressAny.text(lang), Point::new(20, 107), big_white).unwrap();
                 }
 
+                // Start on release so multi-button combos don't start a game
+                if a_just || b_just || x_just || y_just { title_armed = true; }
+                let any_down = a_down || b_down || x_down || y_down;
                 let start_demo = a_down && x_down;
-                let start_game = !start_demo && (a_just || b_just || x_just || y_just);
-                if start_demo || start_game {
+                if b_down && y_down && !a_down && !x_down { options_armed = true; }
+                if a_down && b_down && !x_down && !y_down { unlocks_armed = true; }
+                if x_down && y_down && !a_down && !b_down { tutorial_armed = true; }
+                if a_down && y_down && !b_down && !x_down { practice_armed = true; }
+                if b_down && x_down && !a_down && !y_down { two_player_armed = true; }
+                let released = !start_demo && title_armed && !any_down;
+                if released && options_armed {
+                    game_state = GameState::Options;
+                } else if released && unlocks_armed {
+                    game_state = GameState::Unlocks;
+                }
+                let start_tutorial = released && tutorial_armed;
+                let start_practice = released && practice_armed;
+                let start_2p = released && two_player_armed;
+                let start_game = released && !options_armed && !unlocks_armed && !tutorial_armed && !practice_armed
+                    && !two_player_armed;
+
+                // Low-power idle: display off, clocks stopped until a button edge
+                title_idle = if any_down { 0 } else { title_idle + 1 };
+                if title_idle >= TITLE_IDLE_FRAMES {
+                    log::info!("Idle, entering dormant sleep");
+                    Timer::after(Duration::from_millis(100)).await;
+                    backlight.set(0);
+                    display.inner_mut().sleep(&mut Delay).unwrap();
+                    watchdog.feed();
+                    {
+                        let wake = DormantWakeConfig { edge_low: true, ..Default::default() };
+                        let _wake_a = btn_a.dormant_wake(wake);
+                        let _wake_b = btn_b.dormant_wake(wake);
+                        let _wake_x = btn_x.dormant_wake(wake);
+                        let _wake_y = btn_y.dormant_wake(wake);
+                        embassy_rp::clocks::dormant_sleep();
+                    }
+                    watchdog.feed();
+                    display.inner_mut().wake(&mut Delay).unwrap();
+                    // Swallow the wake press and redraw the title from scratch; it fades in when drawn
+                    (prev_a, prev_b, prev_x, prev_y) = read_buttons(&btn_a, &btn_b, &btn_x, &btn_y, settings.flipped);
+                    prev_state = GameState::GameOver;
+                    log::info!("Woke from dormant sleep");
+                    continue;
+                }
+                if start_demo || start_game || start_tutorial || start_practice || start_2p {
                     demo_mode = start_demo;
-                    player_x = (SCREEN_W - PLAYER_W) / 2;
-                    for o in obstacles.iter_mut() { o.active = false; }
-                    for m in missiles.iter_mut() { m.active = false; }
-                    for p in particles.iter_mut() { p.life = 0; }
-                    for g in gifts.iter_mut() { g.active = false; }
-                    score = 0;
-                    lives = MAX_LIVES;
-                    bombs = MAX_BOMBS;
-                    freeze_timer = 0;
-                    homing_timer = 0;
-                    laser_timer = 0;
-                    shield_timer = 0;
-                    spawn_timer = 0;
-                    gift_spawn_timer = 0;
-                    invincible = 0;
-                    speed_base_score = 0;
-                    prev_score = u32::MAX;
-                    prev_lives = u8::MAX;
-                    prev_bombs = u8::MAX;
-                    prev_power = u8::MAX;
+                    practice = start_practice;
+                    tutorial = start_tutorial.then_some(tutorial::Step::Move);
+                    two_player = start_2p;
+                    player = 0;
+                    player_scores = [0; 2];
+                    // Games pick a ship first; the rest fly the standard one or the last picked
+                    if start_game || start_2p { game_state = GameState::Hangar; } else { start_run = true; }
+                }
+            }
+
+            // ==================== OPTIONS ====================
+            GameState::Options => {
+                if prev_state != GameState::Options {
+                    opt_sel = 0;
+                    opt_redraw = true;
+                    prev_state = GameState::Options;
+                    log::info!("Options");
+                }
+                if opt_redraw {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::Options.text(lang), Point::new(SCREEN_W / 2, 4), big_yellow,
+                        Alignment::Center).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::OptionsHint.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    opt_dirty = true;
+                    opt_redraw = false;
+                }
+
+                let n = OPTION_ITEMS.len();
+                if b_just { opt_sel = (opt_sel + n - 1) % n; opt_dirty = true; }
+                if y_just { opt_sel = (opt_sel + 1) % n; opt_dirty = true; }
+                if b_just || y_just || a_just || x_just { feedback.play(Sfx::MenuMove); }
+                let item = OPTION_ITEMS[opt_sel];
+                if a_just || x_just {
+                    if item == OptionItem::Back {
+                        if settings != saved_settings {
+                            match settings.save(&mut store) {
+                                Ok(()) => { saved_settings = settings; log::info!("Settings saved"); }
+                                Err(_) => log::warn!("Settings save failed"),
+                            }
+                        }
+                        game_state = GameState::Title;
+                    } else {
+                        item.cycle(&mut settings, if x_just { 1 } else { -1 }, &progress);
+                        opt_dirty = true;
+                        // The title and hint switch language along with the rows
+                        if item == OptionItem::Lang { opt_redraw = true; }
+                        if item == OptionItem::Flip {
+                            FLIPPED.store(settings.flipped, Ordering::Relaxed);
+                            display = display.rebuild(|screen| reinit_screen(screen, settings.flipped));
+                            opt_redraw = true;
+                        }
+                    }
+                }
+
+                if opt_dirty && !opt_redraw && game_state == GameState::Options {
+                    display.erase(Rectangle::new(Point::new(0, 26), Size::new(SCREEN_W as u32, 98)));
+                    // Rows use the theme being edited, so a palette change previews itself
+                    let theme = &THEMES[settings.theme as usize % THEMES.len()];
+                    let lang = Lang::from_index(settings.lang);
+                    let first = opt_sel.saturating_sub(OPTION_ROWS - 1);
+                    for (i, it) in OPTION_ITEMS.iter().enumerate().skip(first).take(OPTION_ROWS) {
+                        buf.clear();
+                        buf.push_str(if i == opt_sel { "> " } else { "  " }).ok();
+                        buf.push_str(it.label().text(lang)).ok();
+                        if it.value(&settings) != Msg::Blank {
+                            buf.push_str(": ").ok();
+                            buf.push_str(it.value(&settings).text(lang)).ok();
+                        }
+                        let c = if i == opt_sel { theme.player } else { theme.hud };
+                        text::draw(&mut display, &buf, Point::new(20, 26 + (i - first) as i32 * 12),
+                            MonoTextStyle::new(&FONT_8X13, c)).unwrap();
+                    }
+                    opt_dirty = false;
+                }
+            }
+
+            // ==================== UNLOCKS ====================
+            GameState::Unlocks => {
+                if prev_state != GameState::Unlocks {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::Unlocks.text(lang), Point::new(SCREEN_W / 2, 4), big_yellow,
+                        Alignment::Center).unwrap();
+                    buf.clear();
+                    buf.push_str(Msg::LifetimeKills.text(lang)).ok();
+                    push_u32(&mut buf, progress.kills);
+                    text::draw(&mut display, &buf, Point::new(20, 30), MonoTextStyle::new(&FONT_8X13, theme.hud)).unwrap();
+                    for (i, u) in UNLOCKS.iter().enumerate() {
+                        let (c, mark) = if u.earned(&progress) { (Rgb565::GREEN, "* ") }
+                            else { (Rgb565::new(12, 24, 12), "  ") };
+                        buf.clear();
+                        buf.push_str(mark).ok();
+                        buf.push_str(u.name().text(lang)).ok();
+                        text::draw(&mut display, &buf, Point::new(20, 50 + i as i32 * 15), MonoTextStyle::new(&FONT_8X13, c))
+                            .unwrap();
+                        buf.clear();
+                        push_u32(&mut buf, u.kills());
+                        Text::with_baseline(&buf, Point::new(180, 50 + i as i32 * 15),
+                            MonoTextStyle::new(&FONT_8X13, c), Baseline::Top).draw(&mut display).unwrap();
+                    }
+                    buf.clear();
+                    buf.push_str(Msg::Achievements.text(lang)).ok();
+                    push_u32(&mut buf, progress.achievements.count_ones());
+                    buf.push('/').ok();
+                    push_u32(&mut buf, Achievement::ALL.len() as u32);
+                    text::draw(&mut display, &buf, Point::new(20, 100), MonoTextStyle::new(&FONT_8X13, theme.hud)).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::AnyBack.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    prev_state = GameState::Unlocks;
+                    log::info!("Unlocks, lifetime kills: {}", progress.kills);
+                }
+                if a_just || b_just || x_just || y_just { game_state = GameState::Title; }
+            }
+
+            // ==================== HANGAR ====================
+            GameState::Hangar => {
+                if prev_state != GameState::Hangar {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::ChooseShip.text(lang), Point::new(SCREEN_W / 2, 4), big_yellow,
+                        Alignment::Center).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::HangarHint.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    hangar_sel = settings.profile as usize % ships::PROFILES.len();
+                    if settings.lanes && !ships::PROFILES[hangar_sel].fits_lanes() { hangar_sel = 0; }
+                    hangar_dirty = true;
+                    prev_state = GameState::Hangar;
+                    log::info!("Hangar");
+                }
+
+                // Lane mode greys out hulls too wide to dodge a lane over, and the selection skips them
+                let n = ships::PROFILES.len();
+                let flyable = |i: usize| !settings.lanes || ships::PROFILES[i].fits_lanes();
+                let dir = if b_just { n - 1 } else if y_just { 1 } else { 0 };
+                if dir != 0 {
+                    hangar_sel = (hangar_sel + dir) % n;
+                    while !flyable(hangar_sel) { hangar_sel = (hangar_sel + dir) % n; }
+                    hangar_dirty = true;
+                    feedback.play(Sfx::MenuMove);
+                }
+                if hangar_dirty {
+                    display.erase(Rectangle::new(Point::new(0, 26), Size::new(SCREEN_W as u32, 96)));
+                    // The three hulls side by side at their real width, the picked one framed
+                    for (i, p) in ships::PROFILES.iter().enumerate() {
+                        let cx = SCREEN_W * (2 * i as i32 + 1) / (2 * n as i32);
+                        let c = if i == hangar_sel { theme.player } else if flyable(i) { Rgb565::new(12, 24, 12) }
+                            else { Rgb565::new(5, 10, 5) };
+                        Rectangle::new(Point::new(cx - p.width / 2, 38), Size::new(p.width as u32, PLAYER_H as u32))
+                            .into_styled(PrimitiveStyle::with_fill(c)).draw(&mut display).unwrap();
+                        text::draw_aligned(&mut display, p.name.text(lang), Point::new(cx, 52),
+                            MonoTextStyle::new(&FONT_6X10, c), Alignment::Center).unwrap();
+                        if i == hangar_sel {
+                            Rectangle::new(Point::new(cx - 36, 30), Size::new(72, 36))
+                                .into_styled(PrimitiveStyle::with_stroke(Rgb565::YELLOW, 1)).draw(&mut display).unwrap();
+                        }
+                    }
+                    // Stat bars for the picked class; bombs count the Bomb bay unlock too
+                    let p = &ships::PROFILES[hangar_sel];
+                    let bombs = MAX_BOMBS + Unlock::BombBay.earned(&progress) as u8 + p.extra_bombs;
+                    // Each row is `count` blocks `w` px wide: one long bar, or a pip per bomb
+                    let stats = [(Msg::StatSpeed, 1, p.speed / 2), (Msg::StatWidth, 1, p.width * 2), (Msg::StatBombs, bombs as i32, 7)];
+                    for (row, (label, count, w)) in stats.into_iter().enumerate() {
+                        let y = 74 + row as i32 * 14;
+                        text::draw(&mut display, label.text(lang), Point::new(20, y), MonoTextStyle::new(&FONT_8X13, theme.hud))
+                            .unwrap();
+                        for k in 0..count {
+                            Rectangle::new(Point::new(100 + k * (w + 3), y + 3), Size::new(w as u32, 7))
+                                .into_styled(PrimitiveStyle::with_fill(Rgb565::YELLOW)).draw(&mut display).unwrap();
+                        }
+                    }
+                    hangar_dirty = false;
+                }
+
+                if a_just || x_just {
+                    settings.profile = hangar_sel as u8;
+                    if settings != saved_settings {
+                        match settings.save(&mut store) {
+                            Ok(()) => saved_settings = settings,
+                            Err(_) => log::warn!("Settings save failed"),
+                        }
+                    }
+                    log::info!("Ship: {}", ships::PROFILES[hangar_sel].name.text(Lang::En));
+                    feedback.play(Sfx::MenuMove);
+                    start_run = true;
+                }
+            }
+
+            // ==================== BRIEFING ====================
+            GameState::Briefing => {
+                if prev_state != GameState::Briefing {
+                    display.erase_all();
+                    let title = if two_player { [Msg::P1Missions, Msg::P2Missions][player] } else { Msg::Missions };
+                    let title = title.text(lang);
+                    text::draw_aligned(&mut display, title, Point::new(SCREEN_W / 2, 4), big_yellow, Alignment::Center)
+                        .unwrap();
+                    for (i, m) in missions.iter().enumerate() {
+                        let (pre, n, post) = m.describe();
+                        buf.clear();
+                        buf.push_str(pre.text(lang)).ok();
+                        push_u32(&mut buf, n);
+                        buf.push_str(post.text(lang)).ok();
+                        text::draw(&mut display, &buf, Point::new(20, 34 + i as i32 * 20),
+                            MonoTextStyle::new(&FONT_8X13, theme.hud)).unwrap();
+                    }
+                    buf.clear();
+                    buf.push_str(Msg::BonusPre.text(lang)).ok();
+                    push_u32(&mut buf, missions::BONUS);
+                    buf.push_str(Msg::BonusPost.text(lang)).ok();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw(&mut display, &buf, Point::new(20, 80), hint).unwrap();
+                    let prompt = if progress.best_wave > 1 { Msg::StartPrompt } else { Msg::PressAny }.text(lang);
+                    text::draw_aligned(&mut display, prompt, Point::new(SCREEN_W / 2, 112), big_white,
+                        Alignment::Center).unwrap();
+                    start_wave = start_wave.clamp(1, progress.best_wave as u32);
+                    wave_dirty = progress.best_wave > 1;
+                    prev_state = GameState::Briefing;
+                }
+
+                // Waves already reached can be picked as the starting point
+                let choose = progress.best_wave > 1;
+                if choose && b_just && start_wave > 1 { start_wave -= 1; wave_dirty = true; feedback.play(Sfx::MenuMove); }
+                if choose && y_just && start_wave < progress.best_wave as u32 {
+                    start_wave += 1;
+                    wave_dirty = true;
+                    feedback.play(Sfx::MenuMove);
+                }
+                if wave_dirty {
+                    buf.clear();
+                    buf.push_str(Msg::StartWave.text(lang)).ok();
+                    push_u32(&mut buf, start_wave);
+                    buf.push('/').ok();
+                    push_u32(&mut buf, progress.best_wave as u32);
+                    display.erase(Rectangle::new(Point::new(0, 92), Size::new(SCREEN_W as u32, 14)));
+                    text::draw(&mut display, &buf, Point::new(20, 93), MonoTextStyle::new(&FONT_8X13, Rgb565::YELLOW))
+                        .unwrap();
+                    wave_dirty = false;
+                }
+
+                if a_just || x_just || (!choose && (b_just || y_just)) {
+                    if start_wave > 1 {
+                        let wave_points = (start_wave - 1) * WAVE_POINTS;
+                        score = wave_points * START_SCORE_PCT / 100;
+                        score_offset = wave_points - score;
+                        next_shop_score = wave_points + WAVE_POINTS;
+                        speed_level = (wave_points / 10).min(MAX_SPEED_LEVEL);
+                        log::info!("Starting at wave {}", start_wave);
+                        next_mini_score = (wave_points / MINI_BOSS_POINTS + 1) * MINI_BOSS_POINTS;
+                        if start_wave.is_multiple_of(BOSS_EVERY_WAVES) {
+                            boss = Boss::spawn(&BOSS);
+                            boss_wave = start_wave;
+                        }
+                    }
                     game_state = GameState::Playing;
-                    log::info!("{} start!", if demo_mode { "Demo" } else { "Game" });
+                    feedback.play(Sfx::Start);
                 }
             }
 
             // ==================== PLAYING ====================
             GameState::Playing => {
                 if prev_state != GameState::Playing {
-                    display.clear(Rgb565::BLACK).unwrap();
-                    led.set_high();
-                    if demo_mode {
-                        let ds = MonoTextStyle::new(&FONT_6X10, Rgb565::new(8, 16, 8));
-                        Text::with_baseline("DEMO", Point::new(105, 7), ds, Baseline::Top)
-                            .draw(&mut display).unwrap();
-                    }
+                    display.erase_all();
+                    hud_stale = true;
+                    afk_frames = 0;
+                    feedback.set_led(true);
                     prev_state = GameState::Playing;
                 }
+                // Burn-in guard: the HUD creeps a pixel every few minutes, redrawn whole at its new spot
+                let shift = HUD_SHIFTS[(Instant::now().as_secs() / HUD_SHIFT_SECS) as usize % HUD_SHIFTS.len()];
+                if shift != hud_shift {
+                    display.erase(Rectangle::new(Point::zero(), Size::new(SCREEN_W as u32, HUD_H as u32)));
+                    hud_shift = shift;
+                    hud_stale = true;
+                }
+                if hud_stale {
+                    prev_score = u32::MAX;
+                    prev_lives = u8::MAX;
+                    prev_bombs = u8::MAX;
+                    prev_power = [u32::MAX; 8];
+                    prev_leaks = u32::MAX;
+                    shield_bar.invalidate();
+                    twin_bar.invalidate();
+                    boss_bar.invalidate();
+                    mini_bar.invalidate();
+                    dash_bar.invalidate();
+                    hud_stale = false;
+                }
 
                 // Demo exit
                 if demo_mode && (a_just || b_just || x_just || y_just) {
                     game_state = GameState::Title;
                     frame = frame.wrapping_add(1);
-                    Timer::at(frame_start + Duration::from_millis(50)).await;
+                    Timer::at(frame_start + Duration::from_micros(FRAME_US)).await;
+                    continue;
+                }
+
+                // Tutorial skip / practice exit
+                if (tutorial.is_some() || practice) && b_down && y_down && (b_just || y_just) {
+                    game_state = GameState::Title;
+                    frame = frame.wrapping_add(1);
+                    Timer::at(frame_start + Duration::from_micros(FRAME_US)).await;
+                    continue;
+                }
+
+                // Long B+Y pauses a single-player game and saves it
+                let can_pause = !demo_mode && tutorial.is_none() && !practice && !two_player;
+                pause_hold = if can_pause && b_down && y_down && !a_down && !x_down { pause_hold + 1 } else { 0 };
+                // So does leaving the buttons alone; a script's quiet stretches are input too
+                afk_frames = if can_pause && !tas_play && !(a_down || b_down || x_down || y_down) { afk_frames + 1 } else { 0 };
+                if afk_frames == secs_to_frames(AFK_PAUSE_SECS) { log::info!("No input for {} s", AFK_PAUSE_SECS); }
+                if pause_hold == PAUSE_HOLD_FRAMES || afk_frames == secs_to_frames(AFK_PAUSE_SECS) {
+                    pause_hold = 0;
+                    afk_frames = 0;
+                    game_state = GameState::Paused;
+                    frame = frame.wrapping_add(1);
+                    Timer::at(frame_start + Duration::from_micros(FRAME_US)).await;
                     continue;
                 }
 
                 // --- Input ---
-                let (mv_l, mv_r, fire_l, fire_r, use_bomb) = if demo_mode {
-                    let pcx = player_x + PLAYER_W / 2;
+                let charged = charge >= CHARGE_KILLS;
+                let (mv_l, mv_r, mv_up, fire_l, fire_r, use_bomb, use_special) = if demo_mode {
+                    let pcx = player_x + ship.width / 2;
                     let mut al = false;
                     let mut ar = false;
+                    let mut au = false;
                     let mut fl = false;
-                    let mut fr = frame % 8 == 0;
+                    let mut fr = frame.is_multiple_of(ms_to_frames(400));
                     let mut ab = false;
                     let mut oc = 0u8;
                     let mut ny = -1i32;
                     let mut nx = 0i32;
-                    for obs in obstacles.iter() {
-                        if obs.active {
-                            oc += 1;
-                            if obs.y > ny { nx = obs.x + OBS_W / 2; ny = obs.y; }
-                        }
+                    for obs in obstacles.iter().filter(|o| o.active && o.kind != ObstacleKind::Side) {
+                        oc += 1;
+                        if obs.y > ny { nx = obs.cx(); ny = obs.y; }
                     }
                     if oc >= 4 && bombs > 0 { ab = true; }
-                    if ny >= 0 {
+                    let asp = charged && oc >= 3 && !ab;
+                    // With the field clear, line up under a boss
+                    if ny < 0 && let Some(b) = [&boss, &mini].into_iter().find(|b| b.active) { nx = b.cx(); ny = HUD_H; }
+                    // Bullets about to land on the ship
+                    let bullet = bullets.iter()
+                        .filter(|b| b.active && b.y > player_y - 30 && b.x + BULLET_SIZE > player_x - 4 && b.x < player_x + ship.width + 4)
+                        .max_by_key(|b| b.y);
+                    // A sweeper outranks everything: get to the half it can't reach
+                    let sweeper = obstacles.iter().find(|o| o.active && o.kind == ObstacleKind::Side);
+                    // Then a meteor: keep out of the ground it will scorch
+                    let meteor = obstacles.iter().find(|o| o.active && o.kind == ObstacleKind::Meteor);
+                    let under = |cx: i32| player_x < cx + BLAST_W / 2 && player_x + ship.width > cx - BLAST_W / 2;
+                    if let Some(s) = sweeper {
+                        // Climb over it too, in case the far half is out of reach
+                        au = true;
+                        if s.dx > 0 && player_x < SIDE_REACH + 4 { ar = true; }
+                        if s.dx < 0 && player_x + ship.width > SCREEN_W - SIDE_REACH - 4 { al = true; }
+                    } else if let Some(m) = meteor
+                        && under(m.cx())
+                    {
+                        if pcx < m.cx() && player_x > 0 { al = true; } else { ar = true; }
+                    } else if let Some(b) = bullet {
+                        if b.x + BULLET_SIZE / 2 >= pcx { al = true; } else { ar = true; }
+                    } else if ny >= 0 {
                         let dx = nx - pcx;
-                        if ny > PLAYER_Y - 30 && dx.abs() < PLAYER_W + 4 {
+                        // In lane mode the next lane over is already clear of it
+                        let near = if settings.lanes { LANE_W } else { ship.width + 4 };
+                        if ny > player_y - 30 && dx.abs() < near {
                             if dx >= 0 { al = true; } else { ar = true; }
                         } else {
                             if dx > 4 { ar = true; }
                             else if dx < -4 { al = true; }
-                            else { fl = frame % 2 == 0; fr = frame % 2 != 0; }
+                            else { fl = frame.is_multiple_of(2); fr = !frame.is_multiple_of(2); }
                         }
                     }
-                    (al, ar, fl, fr, ab)
+                    // Reversed controls: press the other way so the ship still goes where intended
+                    if reverse_timer > 0 { core::mem::swap(&mut al, &mut ar); }
+                    (al, ar, au, fl, fr, ab, asp)
                 } else {
-                    let both = a_down && x_down;
+                    // Not with B+Y also down, which is on the way to the display re-init combo
+                    let both = a_down && x_down && !(b_down && y_down);
                     let bj = both && (a_just || x_just);
-                    (b_down, y_down, !both && a_just, !both && x_just, bj)
+                    // B+X only means the special once the gauge is full; until then X fires as usual
+                    let sp = charged && b_down && x_down && (b_just || x_just);
+                    // Practice takes X for spawning, so only A fires
+                    // Holding A also lifts the ship
+                    (b_down, y_down, a_down, !both && a_just, !both && !sp && x_just && !practice, bj, sp)
                 };
+                let (mv_l, mv_r) = if reverse_timer > 0 { (mv_r, mv_l) } else { (mv_l, mv_r) };
+                // A double tap of B/Y dashes that way; the demo never does
+                let (dash_l, dash_r) = if demo_mode { (false, false) } else { (b_double, y_double) };
+                let (dash_l, dash_r) = if reverse_timer > 0 { (dash_r, dash_l) } else { (dash_l, dash_r) };
 
-                if mv_l { player_x = (player_x - PLAYER_SPEED).max(0); }
-                if mv_r { player_x = (player_x + PLAYER_SPEED).min(SCREEN_W - PLAYER_W); }
-
-                // --- Bomb ---
+                // --- Practice: X drops an obstacle, a tap of Y a gift, above the ship ---
+                if practice {
+                    let col = player_x + ship.width / 2;
+                    if x_just && !a_down && let Some(obs) = obstacles.free_slot() {
+                        *obs = Obstacle::falling((col - OBS_W / 2).clamp(0, SCREEN_W - OBS_W));
+                    }
+                    if y_down {
+                        practice_y_hold += 1;
+                    } else {
+                        if (1..=ms_to_frames(PRACTICE_TAP_MS)).contains(&practice_y_hold)
+                            && let Some(g) = gifts.free_slot()
+                        {
+                            let base_x = (col - GIFT_W / 2).clamp(GIFT_SWAY_MAX, SCREEN_W - GIFT_W - GIFT_SWAY_MAX);
+                            let kind = GiftKind::random(&mut rng);
+                            *g = Gift::spawn(base_x, HUD_H, kind);
+                        }
+                        practice_y_hold = 0;
+                    }
+                }
+
+                // Squeeze walls narrow the field to [wall, SCREEN_W - wall)
+                let wall = if scheduler.active() == Some(FieldEvent::Squeeze) { squeeze_inset(scheduler.elapsed_ms()) } else { 0 };
+                let field_w = SCREEN_W - 2 * wall;
+
+                let moved_from = (player_x, player_y);
+                if settings.lanes {
+                    // A lane per press, repeating while held
+                    let dir = mv_r as i32 - mv_l as i32;
+                    if dir == 0 {
+                        lane_repeat = 0;
+                    } else {
+                        if lane_repeat == 0 { lane += dir; }
+                        lane_repeat = (lane_repeat + 1) % ms_to_frames(LANE_REPEAT_MS);
+                    }
+                } else {
+                    let ramp = (HOLD_TOP_PCT - HOLD_START_PCT) / HOLD_RAMP_FRAMES;
+                    for (vel, held) in hold_vel.iter_mut().zip([mv_l, mv_r]) {
+                        *vel = if held { (*vel + ramp).clamp(HOLD_START_PCT, HOLD_TOP_PCT) }
+                            else if *vel - 2 * ramp < HOLD_START_PCT { 0 } else { *vel - 2 * ramp };
+                    }
+                    player_x += step(ship.speed * hold_vel[1] / 100, frame) - step(ship.speed * hold_vel[0] / 100, frame);
+                }
+                dash_cooldown = dash_cooldown.saturating_sub(1);
+                if (dash_l || dash_r) && dash_cooldown == 0 {
+                    emit_burst(&mut particles, &mut rng, ParticleKind::Smoke, player_x + ship.width / 2, player_y + PLAYER_H / 2, 3);
+                    // Lane mode dashes skip a lane
+                    if settings.lanes { lane += if dash_l { -2 } else { 2 }; }
+                    else { player_x += if dash_l { -DASH_PX } else { DASH_PX }; }
+                    invincible = invincible.max(DASH_IFRAMES);
+                    dash_cooldown = ms_to_frames(DASH_COOLDOWN_MS);
+                }
+                if settings.lanes {
+                    lane = lane.clamp(wall / LANE_W, LANES - 1 - wall / LANE_W);
+                    let snap = step(LANE_SNAP_SPEED, frame);
+                    player_x += (lane_center(lane) - ship.width / 2 - player_x).clamp(-snap, snap);
+                }
+                // Closing walls shove the ship along with them; with edge wrap and the field open it comes round the other side
+                player_x = if settings.wrap && !settings.lanes && wall == 0 { player_x.rem_euclid(SCREEN_W) }
+                    else { player_x.clamp(wall, SCREEN_W - wall - ship.width) };
+                player_y = if mv_up { player_y - step(PLAYER_UP_SPEED, frame) } else { player_y + step(PLAYER_DROP_SPEED, frame) }
+                    .clamp(PLAYER_Y - PLAYER_RISE, PLAYER_Y);
+                // A streak at top strafe speed (or faster: dashes, lane snaps) grows the trail; anything less lets it fade out
+                let top = step(ship.speed * HOLD_TOP_PCT / 100, frame).max(1);
+                fast_streak = if (player_x - moved_from.0).abs() >= top { fast_streak + 1 } else { 0 };
+                if fast_streak >= TRAIL_STREAK {
+                    if trail.is_full() { trail.pop_front(); }
+                    trail.push_back(moved_from).ok();
+                } else {
+                    trail.pop_front();
+                }
+
+                // --- Bomb ---
                 if use_bomb && bombs > 0 {
                     bombs -= 1;
+                    let cleared = obstacles.iter().filter(|o| o.active).count() as u32;
+                    events.push(Event::Bomb(cleared)).ok();
                     for obs in obstacles.iter_mut() {
                         if obs.active {
-                            spawn_particles(&mut particles, &mut rng, obs.x + OBS_W / 2, obs.y + OBS_H / 2, 4);
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Debris, obs.cx(), obs.cy(), 4);
+                            spawn_explosion(&mut explosions, obs.cx(), obs.cy());
+                            heat.kill(obs.cx(), obs.cy());
                             obs.active = false;
-                            score += 2;
+                            score += obs.kind.kill_points(loop_count);
+                            run_kills += 1;
                         }
                     }
-                    speed_base_score = score;
-                    log::info!("BOMB! left: {}, speed reset", bombs);
+                    // Bombs wipe out bullets and hurt bosses that have arrived
+                    for b in bullets.iter_mut() { b.active = false; }
+                    for b in [&mut boss, &mut mini] {
+                        if !b.active || !b.arrived() { continue; }
+                        b.hit(BOSS_BOMB_DAMAGE);
+                        score += BOSS_BOMB_DAMAGE as u32;
+                    }
+                    speed_base_score = (score + score_offset).saturating_sub(perfect_points);
+                    clog!(Collide, Info, "BOMB! left: {}, speed reset", bombs);
+                    feedback.play(Sfx::Bomb);
                 }
 
                 // --- Laser beam (auto-target nearest obstacle) ---
@@ -408,214 +3025,822 @@ async fn main(spawner: Spawner) {
                 let mut laser_ty = 0i32;
                 let mut laser_hit = false;
                 if laser_on {
-                    let pcx = player_x + PLAYER_W / 2;
+                    let pcx = player_x + ship.width / 2;
                     let mut best = i32::MAX;
                     let mut ti: Option<usize> = None;
                     for (i, obs) in obstacles.iter().enumerate() {
-                        if !obs.active { continue; }
-                        let d = (obs.x + OBS_W / 2 - pcx).abs() + (obs.y + OBS_H / 2 - PLAYER_Y).abs();
+                        if !obs.active || !obs.on_screen() { continue; }
+                        let d = (obs.cx() - pcx).abs() + (obs.cy() - player_y).abs();
                         if d < best { best = d; ti = Some(i); }
                     }
                     if let Some(i) = ti {
-                        laser_tx = obstacles[i].x + OBS_W / 2;
-                        laser_ty = obstacles[i].y + OBS_H / 2;
+                        laser_tx = obstacles[i].cx();
+                        laser_ty = obstacles[i].cy();
                         laser_hit = true;
-                        spawn_particles(&mut particles, &mut rng, laser_tx, laser_ty, 3);
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Spark, laser_tx, laser_ty, 3);
+                        spawn_explosion(&mut explosions, laser_tx, laser_ty);
+                        drop_coin(&mut coins, &mut rng, laser_tx, laser_ty);
+                        spawn_wreck(&mut wrecks, &obstacles[i]);
+                        heat.kill(laser_tx, laser_ty);
                         obstacles[i].active = false;
-                        score += 2;
+                        feedback.play(Sfx::Destroy);
+                        score += obstacles[i].kind.kill_points(loop_count);
+                        popup.show(Point::new(laser_tx, laser_ty), obstacles[i].kind.kill_points(loop_count), KILL_POPUP_COLOR);
+                        run_kills += 1;
+                        events.push(Event::Kill { high: laser_ty < HUD_H + SNIPER_ZONE, slot: i as u8 }).ok();
+                    } else if let Some(b) = [&mut boss, &mut mini].into_iter().find(|b| b.active) {
+                        // Nothing else in range: burn a boss a tick at a time
+                        laser_tx = b.cx();
+                        laser_ty = b.cy();
+                        laser_hit = true;
+                        if frame.is_multiple_of(ms_to_frames(BOSS_LASER_MS)) {
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Spark, laser_tx, laser_ty, 2);
+                            b.hit(1);
+                            score += 1;
+                        }
                     }
                 }
 
+                // --- Special: a full gauge fans out a volley ---
+                if use_special && charged {
+                    charge = 0;
+                    fire_fan(&mut missiles, (player_x + ship.width / 2).rem_euclid(SCREEN_W), player_y - MISSILE_H);
+                    feedback.play(Sfx::Bomb);
+                    events.push(Event::Fire).ok();
+                }
+
                 // --- Fire missiles (A=left, X=right) ---
+                // Twin fires both sides from either button
+                let twin = twin_timer > 0;
+                let mut fired = false;
                 if !laser_on {
-                    if fire_l {
-                        for m in missiles.iter_mut() {
-                            if !m.active {
-                                m.x = player_x + 2;
-                                m.y = PLAYER_Y - MISSILE_H;
-                                m.active = true;
-                                m.homing = homing_timer > 0;
-                                break;
-                            }
-                        }
+                    if (fire_l || (twin && fire_r)) && let Some(m) = missiles.free_slot() {
+                        // A wrapped ship's guns can be on opposite edges
+                        m.x = (player_x + 2).rem_euclid(SCREEN_W);
+                        m.y = player_y - MISSILE_H;
+                        m.dx = 0;
+                        m.bolt = false;
+                        m.active = true;
+                        m.homing = homing_timer > 0;
+                        fired = true;
                     }
-                    if fire_r {
-                        for m in missiles.iter_mut() {
-                            if !m.active {
-                                m.x = player_x + PLAYER_W - 2 - MISSILE_W;
-                                m.y = PLAYER_Y - MISSILE_H;
-                                m.active = true;
-                                m.homing = homing_timer > 0;
-                                break;
-                            }
+                    if fire_r && bolts > 0 {
+                        // Bolts in stock take X's shot, one at a time even with twin
+                        if let Some(m) = missiles.free_slot() {
+                            *m = Missile {
+                                x: (player_x + ship.width - 2 - MISSILE_W).rem_euclid(SCREEN_W), y: player_y - MISSILE_H, dx: 0,
+                                active: true, homing: false, bolt: true,
+                            };
+                            bolts -= 1;
+                            fired = true;
                         }
+                    } else if (fire_r || (twin && fire_l)) && let Some(m) = missiles.free_slot() {
+                        m.x = (player_x + ship.width - 2 - MISSILE_W).rem_euclid(SCREEN_W);
+                        m.y = player_y - MISSILE_H;
+                        m.dx = 0;
+                        m.bolt = false;
+                        m.active = true;
+                        m.homing = homing_timer > 0;
+                        fired = true;
                     }
                 }
+                if fired { feedback.play(Sfx::Fire); events.push(Event::Fire).ok(); }
 
                 // --- Obstacle speed (0 when frozen, reset by bomb) ---
-                let progress = score.saturating_sub(speed_base_score);
+                let wave_score = (score + score_offset).saturating_sub(perfect_points);
+                let progress = wave_score.saturating_sub(speed_base_score);
+                // Assist scales both difficulty tables by the same percentage
+                let difficulty_pct = if settings.assist { 100 - assist_level * ASSIST_STEP_PCT } else { 100 };
                 let speed = if freeze_timer > 0 { 0 } else {
-                    (INITIAL_SPEED + (progress / 10) as i32).min(6)
+                    (INITIAL_SPEED + (progress / 10) as i32 * SPEED_STEP).min(MAX_SPEED) * difficulty_pct as i32 / 100
                 };
 
+                // --- Announce speed-ups (a bomb's reset lowers the level silently) ---
+                let level = (progress / 10).min(MAX_SPEED_LEVEL);
+                if level > speed_level {
+                    speed_banner = ms_to_frames(SPEED_BANNER_MS);
+                    log::info!("Speed up! level {}", level);
+                    feedback.play(Sfx::SpeedUp);
+                }
+                speed_level = level;
+                speed_banner = speed_banner.saturating_sub(1);
+                // Enough points at the cap: ease the speed back and start the next loop
+                if progress / 10 >= MAX_SPEED_LEVEL + LOOP_HOLD_LEVELS {
+                    loop_count += 1;
+                    speed_base_score = wave_score - LOOP_RESET_LEVEL * 10;
+                    speed_level = LOOP_RESET_LEVEL;
+                    loop_banner = ms_to_frames(SPEED_BANNER_MS);
+                    log::info!("Loop {}", loop_count);
+                    feedback.play(Sfx::Fanfare);
+                }
+                loop_banner = loop_banner.saturating_sub(1);
+
+                // --- Zones: score milestones change the backdrop and the pulse tempo ---
+                let now = zones::index(score);
+                if now > zone_idx { log::info!("Zone {}", now + 1); }
+                zone_idx = now;
+                let zone = &zones::ZONES[zone_idx];
+                beat_timer = beat_timer.saturating_sub(1);
+                if beat_timer == 0 {
+                    beat_timer = ms_to_frames(zone.beat_ms);
+                    feedback.play(Sfx::Beat);
+                }
+                perfect_banner = perfect_banner.saturating_sub(1);
+
                 // --- Spawn obstacles ---
                 spawn_timer += 1;
-                let interval = 30u32.saturating_sub((progress / 10) * 5).max(10);
-                if spawn_timer >= interval {
+                let interval = ms_to_frames(
+                    SPAWN_INTERVAL_MS.saturating_sub((progress / 10) * SPAWN_STEP_MS).max(MIN_SPAWN_INTERVAL_MS)
+                        * 100 / difficulty_pct,
+                );
+                // --- Field events: an asteroid field replaces the regular spawns ---
+                if let Some(ev) = scheduler.tick() {
+                    clog!(Spawn, Info, "Field event: {}", ev.banner().text(Lang::En));
+                    feedback.play(Sfx::Warning);
+                }
+                let asteroids = scheduler.active() == Some(FieldEvent::Asteroids);
+                if asteroids {
+                    rock_timer += 1;
+                    if rock_timer >= ms_to_frames(ROCK_INTERVAL_MS)
+                        && let Some(x) = fair_x(&mut rng, &obstacles, wall, field_w, ROCK_SIZE, settings.lanes)
+                        && let Some(obs) = obstacles.free_slot()
+                    {
+                        rock_timer = 0;
+                        *obs = Obstacle::rock(x);
+                    }
+                }
+
+                // A due spawn waits for a free slot instead of being dropped
+                let due = tutorial.is_none() && !practice && !asteroids && !boss.active && spawn_timer >= interval;
+                let pool_full = obstacles.iter().all(|o| o.active);
+                if due && pool_full {
+                    if spawn_held == 0 { clog!(Spawn, Info, "Obstacle pool full ({} slots), holding spawns", MAX_OBS); }
+                    spawn_held += 1;
+                }
+                if due && !pool_full {
+                    if spawn_held > 0 {
+                        clog!(Spawn, Info, "Spawns resumed after {} ms", spawn_held as u64 * FRAME_US / 1000);
+                        spawn_held = 0;
+                    }
                     spawn_timer = 0;
-                    for obs in obstacles.iter_mut() {
-                        if !obs.active {
-                            obs.x = rng.range(SCREEN_W - OBS_W);
-                            obs.y = HUD_H;
-                            obs.active = true;
-                            break;
+                    // One sweeper at a time, from a random side
+                    let sweeping = obstacles.iter().any(|o| o.active && o.kind == ObstacleKind::Side);
+                    // Sweepers would have to come through the squeeze walls
+                    let side = wave_score >= SIDE_MIN_SCORE && !sweeping && wall == 0 && rng.range(100) < SIDE_CHANCE;
+                    let bounce = !side && wave_score >= BOUNCE_MIN_SCORE && rng.range(100) < BOUNCE_CHANCE;
+                    let falling_meteor = obstacles.iter().any(|o| o.active && o.kind == ObstacleKind::Meteor);
+                    let meteor = !side && !bounce && !falling_meteor && blast_timer == 0
+                        && wave_score >= METEOR_MIN_SCORE && rng.range(100) < METEOR_CHANCE;
+                    let meaner = (loop_count - 1) as i32 * LOOP_CHANCE_PCT;
+                    let armored = !side && !bounce && !meteor && wave_score >= ARMOR_MIN_SCORE
+                        && rng.range(100) < ARMOR_CHANCE + meaner;
+                    let shooter = !side && !bounce && !meteor && !armored
+                        && wave_score >= SHOOTER_MIN_SCORE && rng.range(100) < SHOOTER_CHANCE + meaner;
+                    // Sweepers come in at ship height; everything else drops in where a gap stays open
+                    let x = if side { Some(0) } else {
+                        fair_x(&mut rng, &obstacles, wall, field_w, if meteor { METEOR_W } else { OBS_W }, settings.lanes)
+                    };
+                    if x.is_none() { clog!(Spawn, Debug, "f={} spawn skipped, no fair gap", frame); }
+                    if let Some(x) = x
+                        && let Some(obs) = obstacles.free_slot()
+                    {
+                        if side {
+                            *obs = Obstacle::side(rng.range(2) == 0);
+                            feedback.play(Sfx::Warning);
+                        } else if meteor {
+                            *obs = Obstacle::meteor(x);
+                            feedback.play(Sfx::Warning);
+                        } else if bounce {
+                            *obs = Obstacle::bouncer(x, rng.range(2) == 0);
+                        } else if armored {
+                            *obs = Obstacle::armored(x);
+                        } else if shooter {
+                            *obs = Obstacle::shooter(x);
+                        } else {
+                            *obs = Obstacle::falling(x);
                         }
+                        clog!(Spawn, Debug, "f={} {:?} at x={}", frame, obs.kind, obs.x);
+                    }
+                    // Cover drifts in now and then, one at a time
+                    if !barrier.active && wave_score >= BARRIER_MIN_SCORE && rng.range(100) < BARRIER_CHANCE {
+                        barrier = Barrier::spawn(wall + rng.range(field_w - BARRIER_W));
                     }
                 }
 
-                // --- Move obstacles ---
-                for obs in obstacles.iter_mut() {
+                // --- Move obstacles (sweepers stop while frozen, then burn out past their reach) ---
+                for (i, obs) in obstacles.iter_mut().enumerate() {
                     if !obs.active { continue; }
-                    obs.y += speed;
-                    if obs.y > SCREEN_H { obs.active = false; score += 1; }
+                    if obs.frozen > 0 { obs.frozen -= 1; continue; }
+                    match obs.kind {
+                        ObstacleKind::Falling | ObstacleKind::Armored => {
+                            obs.y += step(speed, frame);
+                            if obs.y > SCREEN_H { obs.active = false; pass_through(&mut score, &mut wave_leaks, 1, settings.hard); }
+                        }
+                        ObstacleKind::Shooter => {
+                            obs.y += step(speed, frame);
+                            // Staggered by slot so a pair doesn't fire in unison
+                            if speed > 0 && obs.y < SHOOTER_FIRE_Y
+                                && (frame + i as u32 * 7).is_multiple_of(ms_to_frames(SHOOTER_FIRE_MS))
+                            {
+                                let target = Point::new(player_x + ship.width / 2, player_y);
+                                let aimed = Volley::Aimed { count: 1, spread: 0 };
+                                fire_volley(&mut bullets, &mut rng, aimed, obs.cx(), obs.y + OBS_H, target, wall);
+                            }
+                            if obs.y > SCREEN_H { obs.active = false; pass_through(&mut score, &mut wave_leaks, 1, settings.hard); }
+                        }
+                        ObstacleKind::Meteor => {
+                            obs.y += step(if speed > 0 { METEOR_SPEED } else { 0 }, frame);
+                            if obs.y + obs.h() >= SCREEN_H {
+                                // Impact: the ground under it burns for a moment
+                                blast_x = obs.cx() - BLAST_W / 2;
+                                blast_timer = ms_to_frames(BLAST_MS);
+                                spawn_explosion(&mut explosions, obs.cx(), SCREEN_H - 4);
+                                emit_burst(&mut particles, &mut rng, ParticleKind::Debris, obs.cx(), SCREEN_H - 4, 8);
+                                feedback.play(Sfx::Bomb);
+                                obs.active = false;
+                                pass_through(&mut score, &mut wave_leaks, 1, settings.hard);
+                            }
+                        }
+                        ObstacleKind::Rock => {
+                            obs.y += step(speed * 2, frame);
+                            if obs.y > SCREEN_H { obs.active = false; pass_through(&mut score, &mut wave_leaks, 2, settings.hard); }
+                        }
+                        ObstacleKind::Bouncer => {
+                            obs.y += step(speed, frame);
+                            if speed > 0 { obs.x += step(obs.dx, frame); }
+                            // Flip on wall contact, pushed back inside so it can't stick
+                            if obs.x <= wall || obs.x >= SCREEN_W - wall - OBS_W {
+                                obs.x = obs.x.clamp(wall, SCREEN_W - wall - OBS_W);
+                                obs.dx = if obs.x == wall { obs.dx.abs() } else { -obs.dx.abs() };
+                            }
+                            if obs.y > SCREEN_H { obs.active = false; pass_through(&mut score, &mut wave_leaks, 1, settings.hard); }
+                        }
+                        ObstacleKind::Side => {
+                            if freeze_timer == 0 { obs.x += step(obs.dx, frame); }
+                            if obs.swept() {
+                                emit_burst(&mut particles, &mut rng, ParticleKind::Smoke, obs.cx(), obs.cy(), 3);
+                                obs.active = false;
+                                score += 1;
+                            }
+                        }
+                    }
+                    // Only a sweeper burns out; anything else that went inactive here got past the bottom
+                    if !obs.active && obs.kind != ObstacleKind::Side {
+                        heat.leak(obs.cx());
+                        run_leaks += 1;
+                    }
+                    // Falling kinds get pushed in by the squeeze walls too
+                    if obs.kind != ObstacleKind::Side { obs.x = obs.x.clamp(wall, SCREEN_W - wall - obs.w()); }
+                }
+                if leak_limit && run_leaks >= LEAK_LIMIT && game_state == GameState::Playing {
+                    game_state = GameState::GameOver;
+                    log::info!("Leak limit reached! Score: {}", score);
+                    events.push(Event::GameOver { score }).ok();
+                    feedback.play(Sfx::GameOver);
+                }
+
+                // --- Mini-boss every MINI_BOSS_POINTS, between full bosses ---
+                if wave_score >= next_mini_score {
+                    next_mini_score = (wave_score / MINI_BOSS_POINTS + 1) * MINI_BOSS_POINTS;
+                    if !boss.active && !mini.active && tutorial.is_none() && !practice {
+                        mini = Boss::spawn(&MINI_BOSS);
+                        feedback.play(Sfx::Warning);
+                        clog!(Spawn, Info, "Mini-boss at {}", wave_score);
+                    }
+                }
+
+                // --- Bosses: drop in, patrol, run the pattern script (freeze holds it all) ---
+                for b in [&mut boss, &mut mini] {
+                    b.flash = b.flash.saturating_sub(1);
+                    if !b.active || freeze_timer > 0 { continue; }
+                    if !b.arrived() {
+                        b.y = (b.y + step(BOSS_ENTER_SPEED, frame)).min(b.spec.y);
+                        continue;
+                    }
+                    let w = b.spec.w;
+                    b.x += step(b.dx, frame);
+                    if b.x <= wall || b.x >= SCREEN_W - wall - w {
+                        b.x = b.x.clamp(wall, SCREEN_W - wall - w);
+                        b.dx = if b.x == wall { b.dx.abs() } else { -b.dx.abs() };
+                    }
+                    if let Some(v) = b.next_volley() {
+                        let target = Point::new(player_x + ship.width / 2, player_y);
+                        fire_volley(&mut bullets, &mut rng, v, b.cx(), b.y + b.spec.h, target, wall);
+                    }
+                }
+
+                // --- Move enemy bullets ---
+                for b in bullets.iter_mut() {
+                    if !b.active || freeze_timer > 0 { continue; }
+                    b.x += step(b.dx, frame);
+                    b.y += step(b.dy, frame);
+                    if b.y > SCREEN_H || b.x + BULLET_SIZE < 0 || b.x > SCREEN_W { b.active = false; }
                 }
 
                 // --- Spawn gifts ---
                 gift_spawn_timer += 1;
-                if gift_spawn_timer >= 200 && rng.range(100) < 15 {
+                if tutorial.is_none() && !practice
+                    && gift_spawn_timer >= secs_to_frames(GIFT_SPAWN_SECS)
+                    && rng.range(100 * 50) < GIFT_CHANCE * (FRAME_US / 1000) as i32
+                {
                     gift_spawn_timer = 0;
-                    for g in gifts.iter_mut() {
-                        if !g.active {
-                            g.x = rng.range(SCREEN_W - GIFT_W);
-                            g.y = HUD_H;
-                            g.life = GIFT_MAX_LIFE;
-                            g.active = true;
-                            break;
-                        }
+                    if let Some(g) = gifts.free_slot() {
+                        let base_x = wall + GIFT_SWAY_MAX + rng.range(field_w - GIFT_W - 2 * GIFT_SWAY_MAX);
+                        *g = Gift::spawn(base_x, HUD_H, GiftKind::random(&mut rng));
                     }
                 }
 
                 // --- Move gifts ---
                 for g in gifts.iter_mut() {
                     if !g.active { continue; }
-                    g.y += GIFT_SPEED;
+                    g.y2 += step(GIFT_SPEED * GIFT_SUBPX, frame);
+                    g.y = g.y2 / GIFT_SUBPX;
+                    // Sway eases between table entries rather than jumping every 100 ms, then rounds to a pixel
+                    let age_ms = (GIFT_MAX_LIFE - g.life) as u32 * 1000 / FPS;
+                    let i = (age_ms / 100) as usize;
+                    let (from, to) = (GIFT_SWAY[i % GIFT_SWAY.len()] as i32, GIFT_SWAY[(i + 1) % GIFT_SWAY.len()] as i32);
+                    let sway2 = from + (to - from) * (age_ms % 100) as i32 / 100;
+                    g.x = g.base_x + (sway2 + GIFT_SUBPX / 2).div_euclid(GIFT_SUBPX);
                     g.life = g.life.saturating_sub(1);
                     if g.life == 0 { g.active = false; }
                 }
 
+                // --- Move coins ---
+                for c in coins.iter_mut() {
+                    if !c.active { continue; }
+                    c.y += step(COIN_SPEED, frame);
+                    if c.y > SCREEN_H { c.active = false; }
+                }
+
+                // --- Magnet: a pixel a frame toward the ship's column ---
+                let pcx = player_x + ship.width / 2;
+                let pulled = |cx: i32| magnet_timer > 0 && cx != pcx && (pcx - cx).abs() <= MAGNET_RANGE;
+                for g in gifts.iter_mut().filter(|g| g.active && pulled(g.base_x + GIFT_W / 2)) {
+                    g.base_x += (pcx - g.base_x - GIFT_W / 2).signum();
+                }
+                for c in coins.iter_mut().filter(|c| c.active && pulled(c.x + COIN_SIZE / 2)) {
+                    c.x += (pcx - c.x - COIN_SIZE / 2).signum();
+                }
+
+                // --- Move barrier: drift down to the rest height, crumble when its time is up ---
+                if barrier.active {
+                    if barrier.y < BARRIER_REST_Y {
+                        barrier.y = (barrier.y + step(BARRIER_SPEED, frame)).min(BARRIER_REST_Y);
+                    } else {
+                        barrier.life = barrier.life.saturating_sub(1);
+                    }
+                    if barrier.life == 0 {
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Smoke,
+                            barrier.x + BARRIER_W / 2, barrier.y, 4);
+                        barrier.active = false;
+                    }
+                }
+
                 // --- Move missiles (homing uses proportional navigation) ---
                 for m in missiles.iter_mut() {
                     if !m.active { continue; }
-                    m.y -= MISSILE_SPEED;
+                    m.y -= step(MISSILE_SPEED, frame);
+                    m.x += step(m.dx, frame);
                     if m.homing {
                         let mcx = m.x + MISSILE_W / 2;
                         let mut best = i32::MAX;
                         let mut tx = mcx;
                         let mut ty = m.y;
+                        // Sweepers run under the missiles, so they are never chased
                         for obs in obstacles.iter() {
-                            if !obs.active { continue; }
-                            let ocx = obs.x + OBS_W / 2;
-                            let ocy = obs.y + OBS_H / 2;
+                            if !obs.active || obs.kind == ObstacleKind::Side { continue; }
+                            let ocx = obs.cx();
+                            let ocy = obs.cy();
                             let d = (ocy - m.y).abs() + (ocx - mcx).abs();
                             if d < best { best = d; tx = ocx; ty = ocy; }
                         }
+                        for b in [&boss, &mini] {
+                            if !b.active { continue; }
+                            let d = (b.cy() - m.y).abs() + (b.cx() - mcx).abs();
+                            if d < best { best = d; tx = b.cx(); ty = b.cy(); }
+                        }
                         // Proportional steering: calculate frames to intercept
                         let dy = m.y - ty;
-                        let frames = (dy / (MISSILE_SPEED + speed)).max(1);
+                        let frames = (dy * FPS as i32 / (MISSILE_SPEED + speed)).max(1);
                         let dx = tx - mcx;
                         let mut turn = dx / frames;
                         if turn == 0 && dx != 0 { turn = if dx > 0 { 1 } else { -1 }; }
-                        m.x += turn.clamp(-6, 6);
+                        let max_turn = step(MISSILE_TURN, frame);
+                        m.x += turn.clamp(-max_turn, max_turn);
                     }
-                    if m.y < HUD_H { m.active = false; }
+                    if m.y < HUD_H || m.x + MISSILE_W < 0 || m.x > SCREEN_W { m.active = false; }
                 }
 
                 // --- Update particles ---
                 for p in particles.iter_mut() {
                     if p.life == 0 { continue; }
-                    p.x += p.dx;
-                    p.y += p.dy;
+                    let spec = p.kind.spec();
+                    p.dy += step(spec.gravity, frame);
+                    p.dx -= p.dx * spec.drag / (100 * FPS as i32);
+                    p.dy -= p.dy * spec.drag / (100 * FPS as i32);
+                    p.x += step(p.dx, frame);
+                    p.y += step(p.dy, frame);
                     p.life -= 1;
                 }
 
+                // --- Smoke from a ship on its last life ---
+                if lives == 1 && frame.is_multiple_of(ms_to_frames(SMOKE_INTERVAL_MS)) {
+                    emit_burst(&mut particles, &mut rng, ParticleKind::Smoke,
+                        player_x + ship.width / 2, player_y, 1);
+                }
+
+                // --- Heartbeat: beep, puff of smoke and a red pulse together ---
+                if lives == 1 {
+                    if heartbeat == 0 {
+                        let active = obstacles.iter().filter(|o| o.active).count() as u32;
+                        let period = HEARTBEAT_MS.saturating_sub(active * HEARTBEAT_STEP_MS).max(HEARTBEAT_MIN_MS);
+                        heartbeat = ms_to_frames(period);
+                        feedback.play(Sfx::Heartbeat);
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Smoke,
+                            player_x + ship.width / 2, player_y, 2);
+                        danger_tint = HEARTBEAT_TINT;
+                    }
+                    heartbeat -= 1;
+                } else {
+                    heartbeat = 0;
+                }
+
+                // --- Advance explosions ---
+                for e in explosions.iter_mut() {
+                    if !e.active { continue; }
+                    e.age += 1;
+                    if e.age >= EXPLOSION_STAGES * EXPLOSION_STAGE_FRAMES { e.active = false; }
+                }
+
+                // --- Obstacle-barrier collision: each obstacle costs it 1 HP, a meteor all of it ---
+                for obs in obstacles.iter_mut() {
+                    if !barrier.active { break; }
+                    if !obs.active { continue; }
+                    if aabb_overlap(
+                        barrier.x, barrier.y, BARRIER_W, BARRIER_H,
+                        obs.x, obs.y, obs.w(), obs.h(),
+                    ) {
+                        if obs.kind == ObstacleKind::Meteor {
+                            barrier.hp = 0;
+                        } else {
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Debris, obs.cx(), obs.cy(), 3);
+                            spawn_explosion(&mut explosions, obs.cx(), obs.cy());
+                            heat.kill(obs.cx(), obs.cy());
+                            obs.active = false;
+                            score += 1;
+                            barrier.hp -= 1;
+                        }
+                        feedback.play(Sfx::Destroy);
+                        if barrier.hp == 0 {
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Debris,
+                                barrier.x + BARRIER_W / 2, barrier.y, 6);
+                            barrier.active = false;
+                            clog!(Collide, Info, "Barrier destroyed");
+                        }
+                    }
+                }
+
+                // --- Wrecks: each soaks up one missile, then they rust away ---
+                for w in wrecks.iter_mut().filter(|w| w.active) {
+                    w.life = w.life.saturating_sub(1);
+                    if w.life == 0 { w.active = false; continue; }
+                    if let Some(m) = missiles.iter_mut()
+                        .find(|m| m.active && aabb_overlap(m.x, m.y, MISSILE_W, MISSILE_H, w.x, w.y, WRECK_W, WRECK_H))
+                    {
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Debris, w.x + WRECK_W / 2, w.y, 2);
+                        m.active = false;
+                        w.active = false;
+                    }
+                }
+
                 // --- Missile-obstacle collision ---
-                for mi in 0..MAX_MISSILES {
-                    if !missiles[mi].active { continue; }
-                    for oi in 0..MAX_OBS {
-                        if !obstacles[oi].active { continue; }
+                for m in missiles.iter_mut() {
+                    if !m.active { continue; }
+                    for (slot, obs) in obstacles.iter_mut().enumerate() {
+                        if !obs.active { continue; }
                         if aabb_overlap(
-                            missiles[mi].x, missiles[mi].y, MISSILE_W, MISSILE_H,
-                            obstacles[oi].x, obstacles[oi].y, OBS_W, OBS_H,
+                            m.x, m.y, MISSILE_W, MISSILE_H,
+                            obs.x, obs.y, obs.w(), obs.h(),
                         ) {
-                            spawn_particles(&mut particles, &mut rng,
-                                obstacles[oi].x + OBS_W / 2, obstacles[oi].y + OBS_H / 2, 6);
-                            missiles[mi].active = false;
-                            obstacles[oi].active = false;
-                            score += 2;
+                            if obs.kind == ObstacleKind::Meteor {
+                                emit_burst(&mut particles, &mut rng, ParticleKind::Spark, m.x, m.y, 2);
+                                m.active = false;
+                                break;
+                            }
+                            if m.bolt && obs.frozen == 0 {
+                                emit_burst(&mut particles, &mut rng, ParticleKind::Star, obs.cx(), obs.cy(), 3);
+                                m.active = false;
+                                obs.frozen = ms_to_frames(FROZEN_MS);
+                                break;
+                            }
+                            // Hit again while frozen, it shatters for double
+                            let shatter = if obs.frozen > 0 { 2 } else { 1 };
+                            if obs.hp > 1 && shatter == 1 {
+                                emit_burst(&mut particles, &mut rng, ParticleKind::Spark, m.x, m.y, 2);
+                                m.active = false;
+                                obs.hp -= 1;
+                                break;
+                            }
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Debris,
+                                obs.cx(), obs.cy(), 4);
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Spark,
+                                obs.cx(), obs.cy(), 2);
+                            spawn_explosion(&mut explosions, obs.cx(), obs.cy());
+                            drop_coin(&mut coins, &mut rng, obs.cx(), obs.cy());
+                            spawn_wreck(&mut wrecks, obs);
+                            heat.kill(obs.cx(), obs.cy());
+                            m.active = false;
+                            obs.active = false;
+                            score += obs.kind.kill_points(loop_count) * shatter;
+                            popup.show(Point::new(obs.cx(), obs.y), obs.kind.kill_points(loop_count) * shatter, KILL_POPUP_COLOR);
+                            run_kills += 1;
+                            clog!(Collide, Debug, "f={} missile kill slot {} at ({}, {})", frame, slot, obs.x, obs.y);
+                            events.push(Event::Kill { high: obs.y < HUD_H + SNIPER_ZONE, slot: slot as u8 }).ok();
+                            feedback.play(Sfx::Destroy);
                             break;
                         }
                     }
                 }
 
+                // --- Missile-boss collision ---
+                for m in missiles.iter_mut() {
+                    if !m.active { continue; }
+                    for b in [&mut boss, &mut mini] {
+                        if !b.active || !aabb_overlap(m.x, m.y, MISSILE_W, MISSILE_H, b.x, b.y, b.spec.w, b.spec.h) {
+                            continue;
+                        }
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Spark, m.x, m.y, 2);
+                        m.active = false;
+                        b.hit(1);
+                        score += 1;
+                        feedback.play(Sfx::Destroy);
+                        break;
+                    }
+                }
+
+                // --- Missile-bullet collision: shots cancel, for a point ---
+                for m in missiles.iter_mut() {
+                    if !m.active { continue; }
+                    for b in bullets.iter_mut() {
+                        if !b.active || !aabb_overlap(m.x, m.y, MISSILE_W, MISSILE_H, b.x, b.y, BULLET_SIZE, BULLET_SIZE) {
+                            continue;
+                        }
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Spark, b.x + BULLET_SIZE / 2, b.y + BULLET_SIZE / 2, 2);
+                        m.active = false;
+                        b.active = false;
+                        score += 1;
+                        feedback.play(Sfx::Destroy);
+                        break;
+                    }
+                }
+
+                // --- Boss defeated ---
+                if boss.active && boss.hp == 0 {
+                    boss.active = false;
+                    for b in bullets.iter_mut() { b.active = false; }
+                    for i in 0..3 {
+                        spawn_explosion(&mut explosions, boss.x + 8 + i * 12, boss.cy() + (i % 2) * 4);
+                    }
+                    emit_burst(&mut particles, &mut rng, ParticleKind::Debris, boss.cx(), boss.cy(), 12);
+                    score += boss.spec.points;
+                    run_kills += 1;
+                    events.push(Event::BossKill).ok();
+                    feedback.play(Sfx::Bomb);
+                    clog!(Collide, Info, "Boss defeated! Score: {}", score);
+                    if boss_wave >= FINAL_WAVE && !demo_mode {
+                        game_state = GameState::Victory;
+                        events.push(Event::GameOver { score }).ok();
+                    }
+                }
+
+                // --- Mini-boss defeated: always leaves a gift, bumping the oldest if none is free ---
+                if mini.active && mini.hp == 0 {
+                    mini.active = false;
+                    spawn_explosion(&mut explosions, mini.cx(), mini.cy());
+                    emit_burst(&mut particles, &mut rng, ParticleKind::Debris, mini.cx(), mini.cy(), 8);
+                    if let Some(g) = gifts.iter_mut().min_by_key(|g| if g.active { g.life as u32 + 1 } else { 0 }) {
+                        let base_x = (mini.cx() - GIFT_W / 2).clamp(wall + GIFT_SWAY_MAX, SCREEN_W - wall - GIFT_W - GIFT_SWAY_MAX);
+                        let kind = GiftKind::ALL[rng.range(GiftKind::ALL.len() as i32) as usize];
+                        *g = Gift::spawn(base_x, mini.y, kind);
+                    }
+                    score += mini.spec.points;
+                    run_kills += 1;
+                    events.push(Event::Kill { high: mini.y < HUD_H + SNIPER_ZONE, slot: achievements::BOSS_SLOT }).ok();
+                    feedback.play(Sfx::Destroy);
+                    clog!(Collide, Info, "Mini-boss defeated! Score: {}", score);
+                }
+
                 // --- Missile-gift collision ---
-                for mi in 0..MAX_MISSILES {
-                    if !missiles[mi].active { continue; }
-                    for gi in 0..MAX_GIFTS {
-                        if !gifts[gi].active { continue; }
+                for m in missiles.iter_mut() {
+                    if !m.active { continue; }
+                    for g in gifts.iter_mut() {
+                        if !g.active { continue; }
                         if aabb_overlap(
-                            missiles[mi].x, missiles[mi].y, MISSILE_W, MISSILE_H,
-                            gifts[gi].x, gifts[gi].y, GIFT_W, GIFT_H,
+                            m.x, m.y, MISSILE_W, MISSILE_H,
+                            g.x, g.y, GIFT_W, GIFT_H,
                         ) {
-                            missiles[mi].active = false;
-                            gifts[gi].active = false;
-                            spawn_particles(&mut particles, &mut rng,
-                                gifts[gi].x + GIFT_W / 2, gifts[gi].y + GIFT_H / 2, 4);
-                            // Random power-up (6 types)
-                            match rng.range(6) {
-                                0 => { bombs = (bombs + 1).min(MAX_BOMBS); log::info!("Gift: Bomb+1"); }
-                                1 => { lives = (lives + 1).min(MAX_LIVES); log::info!("Gift: Life+1"); }
-                                2 => {
+                            m.active = false;
+                            g.active = false;
+                            emit_burst(&mut particles, &mut rng, ParticleKind::Star,
+                                g.x + GIFT_W / 2, g.y + GIFT_H / 2, 4);
+                            feedback.play(Sfx::Pickup);
+                            events.push(Event::Gift).ok();
+                            let mut bank = false;
+                            match g.kind {
+                                GiftKind::Bomb if bombs >= max_bombs => bank = true,
+                                GiftKind::Life if lives >= MAX_LIVES => bank = true,
+                                GiftKind::Bomb => { bombs = (bombs + 1).min(max_bombs); clog!(Collide, Info, "Gift: Bomb+1"); }
+                                GiftKind::Life => { lives = (lives + 1).min(MAX_LIVES); clog!(Collide, Info, "Gift: Life+1"); }
+                                GiftKind::Freeze => {
                                     freeze_timer = FREEZE_DURATION;
                                     // Remove obstacles near the bottom
-                                    for oi in 0..MAX_OBS {
-                                        if obstacles[oi].active && obstacles[oi].y + OBS_H >= PLAYER_Y - 5 {
-                                            spawn_particles(&mut particles, &mut rng,
-                                                obstacles[oi].x + OBS_W / 2, obstacles[oi].y + OBS_H / 2, 3);
-                                            obstacles[oi].active = false;
+                                    for obs in obstacles.iter_mut() {
+                                        if obs.active && obs.y + obs.h() >= player_y - 5 {
+                                            emit_burst(&mut particles, &mut rng, ParticleKind::Smoke,
+                                                obs.cx(), obs.cy(), 3);
+                                            spawn_explosion(&mut explosions, obs.cx(), obs.cy());
+                                            heat.kill(obs.cx(), obs.cy());
+                                            obs.active = false;
                                         }
                                     }
-                                    log::info!("Gift: Freeze!");
+                                    clog!(Collide, Info, "Gift: Freeze!");
                                 }
-                                3 => { homing_timer = HOMING_DURATION; log::info!("Gift: Homing!"); }
-                                4 => { laser_timer = LASER_DURATION; log::info!("Gift: Laser!"); }
-                                _ => { shield_timer = SHIELD_DURATION; log::info!("Gift: Shield!"); }
+                                GiftKind::Homing => { homing_timer = HOMING_DURATION; clog!(Collide, Info, "Gift: Homing!"); }
+                                GiftKind::Laser => { laser_timer = LASER_DURATION; clog!(Collide, Info, "Gift: Laser!"); }
+                                GiftKind::Shield => { shield_timer = SHIELD_DURATION; clog!(Collide, Info, "Gift: Shield!"); }
+                                GiftKind::Twin => { twin_timer = TWIN_DURATION; clog!(Collide, Info, "Gift: Twin!"); }
+                                GiftKind::Magnet => { magnet_timer = MAGNET_DURATION; clog!(Collide, Info, "Gift: Magnet!"); }
+                                GiftKind::Reverse => { reverse_timer = REVERSE_DURATION; clog!(Collide, Info, "Gift: Reversed!"); }
+                            }
+                            // Already full: bank it as points with a popup instead of wasting it
+                            if bank {
+                                score += CAPPED_GIFT_POINTS;
+                                popup.show(Point::new(g.x + GIFT_W / 2, g.y), CAPPED_GIFT_POINTS, Rgb565::YELLOW);
+                                clog!(Collide, Info, "Gift: capped, +{}", CAPPED_GIFT_POINTS);
                             }
                             break;
                         }
                     }
                 }
 
+                // --- Player-coin collision ---
+                for c in coins.iter_mut() {
+                    if !c.active { continue; }
+                    if ship_overlap(player_x, player_y, ship.width, c.x, c.y, COIN_SIZE, COIN_SIZE) {
+                        c.active = false;
+                        score += 1;
+                        wallet += 1;
+                        events.push(Event::Coin { wallet }).ok();
+                        emit_burst(&mut particles, &mut rng, ParticleKind::Star,
+                            c.x + COIN_SIZE / 2, c.y + COIN_SIZE / 2, 3);
+                        feedback.play(Sfx::Coin);
+                    }
+                }
+
                 // --- Player-obstacle collision ---
-                let shielded = shield_timer > 0 || invincible > 0;
-                if invincible > 0 { invincible -= 1; }
-                if !shielded {
+                // God mode marks the run on any frame it's on, however it came back to play
+                run_cheated |= god;
+                // The tutorial and practice never cost a life
+                let shielded = shield_timer > 0 || invincible > 0 || tutorial.is_some() || practice || god;
+                invincible = invincible.saturating_sub(1);
+                // A run that just ended (final boss down) takes no more hits
+                if !shielded && game_state == GameState::Playing {
+                    let mut hits = 0;
                     for obs in obstacles.iter_mut() {
                         if !obs.active { continue; }
-                        if aabb_overlap(
-                            player_x, PLAYER_Y, PLAYER_W, PLAYER_H,
-                            obs.x, obs.y, OBS_W, OBS_H,
-                        ) {
+                        if ship_overlap(player_x, player_y, ship.width, obs.x, obs.y, obs.w(), obs.h()) {
                             obs.active = false;
-                            lives = lives.saturating_sub(1);
-                            invincible = 20;
-                            log::info!("Hit! Lives: {}", lives);
-                            if lives == 0 {
-                                game_state = GameState::GameOver;
-                                log::info!("Game Over! Score: {}", score);
-                                break;
-                            }
+                            spawn_explosion(&mut explosions, obs.cx(), obs.cy());
+                            hits += 1;
+                        }
+                    }
+                    for b in bullets.iter_mut() {
+                        if !b.active { continue; }
+                        if ship_overlap(player_x, player_y, ship.width, b.x, b.y, BULLET_SIZE, BULLET_SIZE) {
+                            b.active = false;
+                            hits += 1;
                         }
                     }
+                    // Standing in a meteor's blast zone counts as one more hit
+                    if blast_timer > 0
+                        && ship_overlap(player_x, player_y, ship.width, blast_x, BLAST_Y, BLAST_W, SCREEN_H - BLAST_Y)
+                    {
+                        hits += 1;
+                    }
+                    if hits > 0 { wave_hit = true; }
+                    for _ in 0..hits {
+                        lives = lives.saturating_sub(1);
+                        invincible = ms_to_frames(INVINCIBLE_MS);
+                        clog!(Collide, Info, "Hit! Lives: {}", lives);
+                        events.push(Event::Hit { lives, score }).ok();
+                        feedback.play(Sfx::PlayerHit);
+                        if lives == 0 {
+                            game_state = GameState::GameOver;
+                            log::info!("Game Over! Score: {}", score);
+                            events.push(Event::GameOver { score }).ok();
+                            feedback.play(Sfx::GameOver);
+                            break;
+                        }
+                    }
+                }
+                blast_timer = blast_timer.saturating_sub(1);
+
+                // --- Collision warning (rate-limited) ---
+                warn_cooldown = warn_cooldown.saturating_sub(1);
+                if warn_cooldown == 0 && !shielded && game_state == GameState::Playing {
+                    let reach = speed * COLLISION_WARN_FRAMES / FPS as i32;
+                    let incoming = obstacles.iter().any(|o| {
+                        let gap = player_y - (o.y + o.h());
+                        o.active && (0..=reach).contains(&gap)
+                            && ship_copies(player_x, ship.width, SCREEN_W).any(|sx| o.x < sx + ship.width && o.x + o.w() > sx)
+                    });
+                    if incoming {
+                        feedback.play(Sfx::Warning);
+                        warn_cooldown = ms_to_frames(COLLISION_WARN_COOLDOWN_MS);
+                    }
+                }
+
+                // --- Wave end: clear the field and open the shop ---
+                let wave_score = (score + score_offset).saturating_sub(perfect_points);
+                // A boss holds the wave open until it's beaten
+                if wave_score >= next_shop_score && game_state == GameState::Playing && tutorial.is_none() && !practice
+                    && !boss.active
+                {
+                    next_shop_score = (wave_score / WAVE_POINTS + 1) * WAVE_POINTS;
+                    let wave = wave_score / WAVE_POINTS;
+                    let boss_next = (wave + 1).is_multiple_of(BOSS_EVERY_WAVES);
+                    if boss_next {
+                        boss = Boss::spawn(&BOSS);
+                        boss_wave = wave + 1;
+                        clog!(Spawn, Info, "Boss incoming in wave {}", wave + 1);
+                    } else if wave.is_multiple_of(EVENT_EVERY_WAVES) {
+                        let squeeze = wave >= SQUEEZE_MIN_WAVE && (wave / EVENT_EVERY_WAVES).is_multiple_of(2);
+                        scheduler.schedule(if squeeze { FieldEvent::Squeeze } else { FieldEvent::Asteroids }, EVENT_DELAY_MS);
+                    }
+                    if !demo_mode {
+                        events.push(Event::WaveClear { wave: wave_score / WAVE_POINTS }).ok();
+                        if !wave_hit && wave_leaks == 0 {
+                            score += PERFECT_BONUS;
+                            perfect_points += PERFECT_BONUS;
+                            perfect_banner = ms_to_frames(PERFECT_BANNER_MS);
+                            feedback.play(Sfx::Fanfare);
+                            log::info!("Perfect wave! +{}", PERFECT_BONUS);
+                        }
+                        wave_hit = false;
+                        wave_leaks = 0;
+                        for o in obstacles.iter_mut() { o.active = false; }
+                        for m in missiles.iter_mut() { m.active = false; }
+                        for b in bullets.iter_mut() { b.active = false; }
+                        barrier.active = false;
+                        mini.active = false;
+                        game_state = GameState::Shop;
+                        log::info!("Wave cleared at {}, coins: {}", score, wallet);
+                    }
+                }
+
+                // --- Tutorial: scripted spawns, advance when the step's goal shows up ---
+                if let Some(t) = tutorial {
+                    if mv_l { tutorial_moved.0 = true; }
+                    if mv_r { tutorial_moved.1 = true; }
+                    let goal = match t {
+                        tutorial::Step::Move => tutorial_moved == (true, true),
+                        tutorial::Step::Fire => events.iter().any(|e| matches!(e, Event::Kill { .. })),
+                        tutorial::Step::Gift => events.iter().any(|e| matches!(e, Event::Gift)),
+                        tutorial::Step::Bomb => events.iter().any(|e| matches!(e, Event::Bomb(_))),
+                        tutorial::Step::Done => { tutorial_done_timer += 1; tutorial_done_timer >= secs_to_frames(2) }
+                    };
+                    if goal && t == tutorial::Step::Done {
+                        game_state = GameState::Title;
+                    } else if goal {
+                        tutorial = Some(t.next());
+                        feedback.play(Sfx::Pickup);
+                    } else if t == tutorial::Step::Fire && !obstacles.iter().any(|o| o.active) {
+                        obstacles[0] = Obstacle::falling((SCREEN_W - OBS_W) / 2);
+                    } else if t == tutorial::Step::Gift && !gifts.iter().any(|g| g.active) {
+                        let base_x = (SCREEN_W - GIFT_W) / 2;
+                        gifts[0] = Gift::spawn(base_x, HUD_H, GiftKind::Shield);
+                    } else if t == tutorial::Step::Bomb && !obstacles.iter().any(|o| o.active) {
+                        bombs = bombs.max(1);
+                        for (i, obs) in obstacles.iter_mut().take(4).enumerate() {
+                            *obs = Obstacle::falling(30 + i as i32 * 50);
+                        }
+                    }
+                }
+
+                // --- Special gauge: only shot-down obstacles fill it ---
+                let kills = events.iter().filter(|e| matches!(e, Event::Kill { .. })).count() as u32;
+                charge = (charge + kills).min(CHARGE_KILLS);
+                run_shots += events.iter().filter(|e| matches!(e, Event::Fire)).count() as u32;
+                run_hits += kills;
+                run_frames += 1;
+
+                // --- Achievement toast: show queued ones one at a time ---
+                events.push(Event::Score(score)).ok();
+                for m in missions.iter_mut() { m.tick(FRAME_US as u32 / 1000); }
+                toast_timer = toast_timer.saturating_sub(1);
+                if toast_timer == 0 {
+                    toast = toasts.pop_front();
+                    if toast.is_some() { toast_timer = ms_to_frames(TOAST_MS); }
                 }
 
                 // --- Tick power-up timers ---
@@ -623,185 +3848,1217 @@ async fn main(spawner: Spawner) {
                 homing_timer = homing_timer.saturating_sub(1);
                 laser_timer = laser_timer.saturating_sub(1);
                 shield_timer = shield_timer.saturating_sub(1);
+                twin_timer = twin_timer.saturating_sub(1);
+                magnet_timer = magnet_timer.saturating_sub(1);
+                reverse_timer = reverse_timer.saturating_sub(1);
+                popup.timer = popup.timer.saturating_sub(1);
+
+                // --- Bomb regen: one back per BOMB_REGEN_SECS survived while short ---
+                if bombs < max_bombs {
+                    bomb_regen += 1;
+                    if bomb_regen >= secs_to_frames(BOMB_REGEN_SECS) {
+                        bomb_regen = 0;
+                        bombs += 1;
+                        feedback.play(Sfx::Pickup);
+                    }
+                } else {
+                    bomb_regen = 0;
+                }
 
                 // ==================== RENDER ====================
-                Rectangle::new(
-                    Point::new(0, HUD_H),
-                    Size::new(SCREEN_W as u32, (SCREEN_H - HUD_H) as u32),
-                )
-                .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
-                .draw(&mut display).unwrap();
+                // Playfield rects are batched into one window write over a black background
 
-                // Laser beam (line to target)
-                if laser_on && laser_hit {
-                    let pcx = player_x + PLAYER_W / 2;
-                    Line::new(Point::new(pcx, PLAYER_Y), Point::new(laser_tx, laser_ty))
-                        .into_styled(PrimitiveStyle::with_stroke(laser_color, 1))
-                        .draw(&mut display).unwrap();
+                // Border first so everything else draws over it
+                push_border(&mut batch, speed_level, frame, speed_banner > 0 && blink(frame, 200), zone.accent);
+                push_stars(&mut batch, zone.stars, frame);
+
+                // Squeeze walls, each with a bright inner edge
+                if wall > 0 {
+                    batch.push(0, HUD_H, wall, SCREEN_H - HUD_H, SQUEEZE_COLOR);
+                    batch.push(SCREEN_W - wall, HUD_H, wall, SCREEN_H - HUD_H, SQUEEZE_COLOR);
+                    batch.push(wall - 1, HUD_H, 1, SCREEN_H - HUD_H, Rgb565::new(31, 40, 0));
+                    batch.push(SCREEN_W - wall, HUD_H, 1, SCREEN_H - HUD_H, Rgb565::new(31, 40, 0));
                 }
 
-                // Obstacles (blue when frozen)
-                for obs in &obstacles {
+                // Obstacles (blue when frozen, flickering flames when the chip runs hot)
+                let flame = [Rgb565::RED, Rgb565::new(31, 32, 0), Rgb565::YELLOW];
+                for (i, obs) in obstacles.iter().enumerate() {
                     if !obs.active { continue; }
-                    let c = if freeze_timer > 0 { Rgb565::BLUE } else { obs_color };
-                    Rectangle::new(Point::new(obs.x, obs.y), Size::new(OBS_W as u32, OBS_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(c))
-                        .draw(&mut display).unwrap();
+                    let c = if obs.frozen > 0 { FROZEN_COLOR }
+                        else if freeze_timer > 0 { Rgb565::BLUE }
+                        else if overheat { flame[((frame / ms_to_frames(100)) as usize + i) % flame.len()] }
+                        else if obs.kind == ObstacleKind::Bouncer { BOUNCER_COLOR }
+                        else if obs.kind == ObstacleKind::Rock { ROCK_COLOR }
+                        else if obs.kind == ObstacleKind::Meteor { METEOR_COLOR }
+                        else if obs.kind == ObstacleKind::Shooter { SHOOTER_COLOR }
+                        else if obs.kind == ObstacleKind::Armored { if obs.hp > 1 { ARMOR_COLOR } else { ARMOR_CRACKED } }
+                        else { theme.obstacle };
+                    if obs.kind == ObstacleKind::Meteor && obs.y + obs.h() <= HUD_H {
+                        // Still above the play area: mark the ground it will hit
+                        if blink(frame, 150) { batch.push(obs.x, SCREEN_H - 2, obs.w(), 2, Rgb565::RED); }
+                    } else if obs.on_screen() {
+                        batch.push(obs.x, obs.y, obs.w(), obs.h(), c);
+                    } else if blink(frame, 150) {
+                        // Incoming sweeper: flash a marker on the edge it will enter from
+                        batch.push(if obs.dx > 0 { 0 } else { SCREEN_W - 3 }, obs.y, 3, OBS_H, c);
+                    }
                 }
 
-                // Gifts (blink when fading)
-                for g in &gifts {
-                    if !g.active { continue; }
-                    if g.life <= GIFT_FADE_START && frame % 4 < 2 { continue; }
-                    let c = if g.life > GIFT_FADE_START { Rgb565::GREEN } else { Rgb565::new(0, 20, 0) };
-                    Rectangle::new(Point::new(g.x, g.y), Size::new(GIFT_W as u32, GIFT_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(c))
-                        .draw(&mut display).unwrap();
+                // Meteor blast zone, flickering
+                if blast_timer > 0 {
+                    let c = if blink(frame, 100) { Rgb565::new(31, 32, 0) } else { Rgb565::RED };
+                    batch.push(blast_x, BLAST_Y, BLAST_W, SCREEN_H - BLAST_Y, c);
+                }
+
+                // Barrier, rusting as it takes hits and blinking before it crumbles
+                if barrier.active && !(barrier.life < ms_to_frames(1000) && blink(frame, 150)) {
+                    batch.push(barrier.x, barrier.y, BARRIER_W, BARRIER_H, barrier.color());
+                }
+
+                // Bosses: hull with a red core, white while flashing from a hit
+                for b in [&boss, &mini].into_iter().filter(|b| b.active) {
+                    let (w, h) = (b.spec.w, b.spec.h);
+                    batch.push(b.x, b.y, w, h, if b.flash > 0 { Rgb565::WHITE } else { b.spec.color });
+                    batch.push(b.cx() - w / 10, b.y + h - 5, w / 5, 3, Rgb565::RED);
+                }
+
+                // Magnet: faint lines from everything it's pulling to the ship's column
+                if magnet_timer > 0 {
+                    let pcx = player_x + ship.width / 2;
+                    let mut line = |cx: i32, cy: i32| {
+                        if cx != pcx && (pcx - cx).abs() <= MAGNET_RANGE {
+                            batch.push(cx.min(pcx), cy, (pcx - cx).abs(), 1, MAGNET_LINE);
+                        }
+                    };
+                    for g in gifts.iter().filter(|g| g.active) { line(g.base_x + GIFT_W / 2, g.y + GIFT_H / 2); }
+                    for c in coins.iter().filter(|c| c.active) { line(c.x + COIN_SIZE / 2, c.y + COIN_SIZE / 2); }
+                }
+
+                // Gifts (box in the power-up's color; glyphs and fading gifts go on after the flush)
+                let fade_level = |g: &Gift| (g.life.min(GIFT_FADE_START) as u32 * 16 / GIFT_FADE_START as u32) as u8;
+                let chute = Rgb565::new(20, 40, 20);
+                for g in gifts.iter().filter(|g| g.active && g.life > GIFT_FADE_START) {
+                    batch.push(g.x - 1, g.y - CHUTE_H, GIFT_W + 2, 2, chute);
+                    batch.push(g.x, g.y - CHUTE_H + 2, 1, CHUTE_H - 2, chute);
+                    batch.push(g.x + GIFT_W - 1, g.y - CHUTE_H + 2, 1, CHUTE_H - 2, chute);
+                    batch.push(g.x, g.y, GIFT_W, GIFT_H, g.kind.color());
+                }
+
+                // Wrecks
+                for w in wrecks.iter().filter(|w| w.active) {
+                    batch.push(w.x, w.y, WRECK_W, WRECK_H, WRECK_COLOR);
+                }
+
+                // Coins (gold with a glint that hops corners)
+                for c in &coins {
+                    if !c.active { continue; }
+                    batch.push(c.x, c.y, COIN_SIZE, COIN_SIZE, Rgb565::new(31, 52, 0));
+                    let gx = if blink(frame, 250) { c.x + 1 } else { c.x + COIN_SIZE - 2 };
+                    batch.push(gx, c.y + 1, 1, 1, Rgb565::WHITE);
                 }
 
                 // Missiles (orange when homing)
                 for m in &missiles {
                     if !m.active { continue; }
-                    let c = if m.homing { homing_color } else { missile_color };
-                    Rectangle::new(Point::new(m.x, m.y), Size::new(MISSILE_W as u32, MISSILE_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(c))
-                        .draw(&mut display).unwrap();
+                    let c = if m.bolt { BOLT_COLOR } else if m.homing { theme.homing } else { theme.missile };
+                    batch.push(m.x, m.y, MISSILE_W, MISSILE_H, c);
+                }
+
+                // Enemy bullets
+                for b in bullets.iter().filter(|b| b.active) {
+                    batch.push(b.x, b.y, BULLET_SIZE, BULLET_SIZE, BULLET_COLOR);
+                }
+
+                // Explosions (under the debris): white core, yellow ball, then widening rings
+                for e in &explosions {
+                    if !e.active { continue; }
+                    match e.age / EXPLOSION_STAGE_FRAMES {
+                        0 => batch.push(e.x - 2, e.y - 2, 4, 4, Rgb565::WHITE),
+                        1 => batch.push(e.x - 4, e.y - 4, 8, 8, Rgb565::YELLOW),
+                        2 => batch.push_ring(e.x - 6, e.y - 6, 12, 12, 2, Rgb565::new(31, 32, 0)),
+                        _ => batch.push_ring(e.x - 8, e.y - 8, 16, 16, 1, Rgb565::RED),
+                    }
                 }
 
                 // Particles
                 for p in &particles {
                     if p.life == 0 { continue; }
-                    let c = if p.life > 5 { Rgb565::WHITE }
-                        else if p.life > 2 { Rgb565::YELLOW }
-                        else { Rgb565::RED };
-                    Rectangle::new(Point::new(p.x, p.y), Size::new(2, 2))
-                        .into_styled(PrimitiveStyle::with_fill(c))
-                        .draw(&mut display).unwrap();
+                    batch.push(p.x, p.y, p.size, p.size, p.color());
                 }
 
                 // Player (blinks: shield=white fast, invincible=cyan slow)
-                let show = if shield_timer > 0 { frame % 3 != 0 }
-                    else if invincible > 0 { frame % 4 < 2 }
+                let show = if shield_timer > 0 { blink(frame, 150) }
+                    else if invincible > 0 { blink(frame, 200) }
                     else { true };
                 if show {
-                    let c = if shield_timer > 0 { Rgb565::WHITE } else { player_color };
-                    Rectangle::new(
-                        Point::new(player_x, PLAYER_Y),
-                        Size::new(PLAYER_W as u32, PLAYER_H as u32),
-                    )
-                    .into_styled(PrimitiveStyle::with_fill(c))
-                    .draw(&mut display).unwrap();
+                    let c = if shield_timer > 0 { Rgb565::WHITE } else { theme.player };
+                    // Afterimages under the ship, the oldest faintest
+                    for (i, &(x, y)) in trail.iter().enumerate() {
+                        batch.push(x, y, ship.width, PLAYER_H, dim(c, 15 + 45 * i as u32 / TRAIL_LEN as u32));
+                    }
+                    for x in ship_copies(player_x, ship.width, SCREEN_W) {
+                        if settings.ship == 1 {
+                            // Arrow: a nose over a slimmer hull, same hitbox
+                            batch.push(x + ship.width / 2 - 3, player_y, 6, 3, c);
+                            batch.push(x, player_y + 3, ship.width, PLAYER_H - 3, c);
+                        } else {
+                            batch.push(x, player_y, ship.width, PLAYER_H, c);
+                        }
+                    }
                 }
 
-                // --- HUD: score (big) ---
-                if score != prev_score {
-                    Rectangle::new(Point::new(0, 0), Size::new(100, HUD_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
-                        .draw(&mut display).unwrap();
+                // Special gauge under the ship, flashing once full
+                let gy = player_y + PLAYER_H + 1;
+                let fill = ship.width * charge as i32 / CHARGE_KILLS as i32;
+                for x in ship_copies(player_x, ship.width, SCREEN_W) {
+                    batch.push(x, gy, ship.width, 1, Rgb565::new(6, 12, 6));
+                    if fill > 0 {
+                        batch.push(x, gy, fill, 1, if charged && blink(frame, 200) { Rgb565::WHITE } else { CHARGE_COLOR });
+                    }
+                }
+
+                // Toast box, sliding in and out under the HUD
+                let toast_x = (SCREEN_W - TOAST_W) / 2;
+                let slide = ms_to_frames(TOAST_SLIDE_MS);
+                let shown = (ms_to_frames(TOAST_MS) - toast_timer).min(toast_timer).min(slide) as i32;
+                let toast_y = HUD_H - TOAST_H + TOAST_H * shown / slide as i32;
+                if toast.is_some() {
+                    batch.push(toast_x, toast_y, TOAST_W, TOAST_H, Rgb565::new(4, 8, 12));
+                    batch.push(toast_x, toast_y + TOAST_H - 1, TOAST_W, 1, Rgb565::YELLOW);
+                }
+
+                let playfield = Rectangle::new(
+                    Point::new(0, HUD_H),
+                    Size::new(SCREEN_W as u32, (SCREEN_H - HUD_H) as u32),
+                );
+                // Background eases toward dark red while crowded or on the last life
+                let crowded = obstacles.iter().filter(|o| o.active).count() >= DANGER_OBSTACLES;
+                let target = if crowded || lives == 1 { DANGER_TINT_MAX } else { 0 };
+                if danger_tint < target { danger_tint += 1; } else if danger_tint > target { danger_tint -= 1; }
+                let field_tint = if asteroids { ASTEROID_TINT } else { 0 };
+                let field_bg = Rgb565::new(danger_tint / 4, 0, field_tint);
+                batch.flush(&mut display, playfield, field_bg).unwrap();
+
+                // Fading gifts thin out through an ordered dither instead of blinking; the unlit pixels
+                // keep whatever the flush put there
+                for g in gifts.iter().filter(|g| g.active && g.life <= GIFT_FADE_START) {
+                    let level = fade_level(g);
+                    let mut field = display.clipped(&playfield);
+                    fill_dithered(&mut field, g.x - 1, g.y - CHUTE_H, GIFT_W + 2, 2, chute, level).unwrap();
+                    fill_dithered(&mut field, g.x, g.y - CHUTE_H + 2, 1, CHUTE_H - 2, chute, level).unwrap();
+                    fill_dithered(&mut field, g.x + GIFT_W - 1, g.y - CHUTE_H + 2, 1, CHUTE_H - 2, chute, level).unwrap();
+                    fill_dithered(&mut field, g.x, g.y, GIFT_W, GIFT_H, g.kind.color(), level).unwrap();
+                }
+
+                // Gift glyphs, dropped once a fade is half done
+                let label_style = MonoTextStyle::new(&FONT_6X10, Rgb565::BLACK);
+                for g in gifts.iter().filter(|g| g.active && fade_level(g) > 8) {
+                    if g.kind == GiftKind::Life {
+                        draw_icon(&mut display, &ICON_HEART, g.x + 1, g.y + 1, Rgb565::RED, g.kind.color()).unwrap();
+                    } else {
+                        Text::with_baseline(g.kind.label(), Point::new(g.x + 2, g.y), label_style, Baseline::Top)
+                            .draw(&mut display).unwrap();
+                    }
+                }
+
+                // Compass at ship height on the side of a gift that could otherwise fall by unseen
+                let pcx = player_x + ship.width / 2;
+                if let Some(g) = gifts.iter().filter(|g| g.active).min_by_key(|g| (g.x + GIFT_W / 2 - pcx).abs())
+                    && (g.x + GIFT_W / 2 - pcx).abs() > COMPASS_MIN_DX
+                {
+                    let c = COMPASS_COLORS[(g.life as usize * 3 / GIFT_MAX_LIFE as usize).min(2)];
+                    let y = player_y + PLAYER_H / 2;
+                    let (tip, base) = if g.x > pcx { (SCREEN_W - 2, SCREEN_W - 2 - COMPASS_SIZE) } else { (1, 1 + COMPASS_SIZE) };
+                    Triangle::new(Point::new(tip, y), Point::new(base, y - COMPASS_SIZE), Point::new(base, y + COMPASS_SIZE))
+                        .into_styled(PrimitiveStyle::with_fill(c)).draw(&mut display.clipped(&playfield)).unwrap();
+                }
+
+                if practice {
+                    text::draw(&mut display, Msg::PracticeHint.text(lang), Point::new(12, HUD_H + 4),
+                        MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16))).unwrap();
+                }
+
+                // Tutorial prompt and skip hint
+                if let Some(t) = tutorial {
+                    let prompt = t.prompt().text(lang);
+                    let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+                    text::draw_aligned(&mut display, prompt, Point::new(SCREEN_W / 2, HUD_H + 20), style, Alignment::Center)
+                        .unwrap();
+                    let skip = Msg::Skip.text(lang);
+                    let style = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, skip, Point::new(SCREEN_W / 2, HUD_H + 34), style, Alignment::Center)
+                        .unwrap();
+                }
+
+                // Toast text once the box has fully slid in
+                if let Some(a) = toast
+                    && toast_y == HUD_H
+                {
                     buf.clear();
-                    core::write!(buf, "{}", score).ok();
-                    let score_style = MonoTextStyle::new(&FONT_10X20, Rgb565::WHITE);
-                    Text::with_baseline(&buf, Point::new(4, 2), score_style, Baseline::Top)
+                    buf.push_str(Msg::Achievement.text(lang)).ok();
+                    buf.push_str(a.name().text(lang)).ok();
+                    let x = text::place(text::tiny_width(&buf), SCREEN_W / 2, Alignment::Center);
+                    text::draw_tiny(&mut display, &buf, x, toast_y + 4, Rgb565::YELLOW, Rgb565::new(4, 8, 12))
+                        .unwrap();
+                }
+
+                // Field event warning, flashing until it starts
+                if let Some(ev) = scheduler.warning()
+                    && blink(frame, 300)
+                {
+                    let banner = ev.banner().text(lang);
+                    let x = text::left_edge(banner, &big_red, SCREEN_W / 2, Alignment::Center);
+                    draw_text_fx(&mut display, banner, Point::new(x, 40), big_red, TextFx::Outline,
+                        Rgb565::BLACK).unwrap();
+                }
+
+                // Reversed controls: a flashing label riding above the ship
+                if reverse_timer > 0 && blink(frame, 250) {
+                    let small_red = MonoTextStyle::new(&FONT_6X10, GiftKind::Reverse.color());
+                    let label = Msg::Reversed.text(lang);
+                    let w = text::width(label, &small_red);
+                    let x = (player_x + ship.width / 2 - w / 2).clamp(0, SCREEN_W - w);
+                    draw_text_fx(&mut display, label, Point::new(x, player_y - 14), small_red, TextFx::Outline,
+                        Rgb565::BLACK).unwrap();
+                }
+
+                // Points popup (kills, banked gifts), drifting up from where they were scored
+                if popup.timer > 0 {
+                    let rise = POPUP_RISE * (ms_to_frames(POPUP_MS) - popup.timer) as i32 / FPS as i32;
+                    buf.clear();
+                    buf.push('+').ok();
+                    push_u32(&mut buf, popup.pts);
+                    let x = (popup.at.x - buf.len() as i32 * 3).clamp(0, SCREEN_W - buf.len() as i32 * 6);
+                    let y = (popup.at.y - rise).max(HUD_H);
+                    draw_text_fx(&mut display, &buf, Point::new(x, y), MonoTextStyle::new(&FONT_6X10, popup.color),
+                        TextFx::Outline, Rgb565::BLACK).unwrap();
+                }
+
+                // Perfect-wave banner, up as the next wave starts
+                if perfect_banner > 0 {
+                    buf.clear();
+                    buf.push_str(Msg::Perfect.text(lang)).ok();
+                    push_u32(&mut buf, PERFECT_BONUS);
+                    let x = text::left_edge(&buf, &big_yellow, SCREEN_W / 2, Alignment::Center);
+                    draw_text_fx(&mut display, &buf, Point::new(x, 40), big_yellow, TextFx::Outline, Rgb565::BLACK).unwrap();
+                }
+
+                // New loop banner, in the speed-up banner's spot
+                if loop_banner > 0 {
+                    buf.clear();
+                    buf.push_str(Msg::Loop.text(lang)).ok();
+                    push_u32(&mut buf, loop_count);
+                    let x = text::left_edge(&buf, &big_yellow, SCREEN_W / 2, Alignment::Center);
+                    draw_text_fx(&mut display, &buf, Point::new(x, 62), big_yellow, TextFx::Outline, Rgb565::BLACK).unwrap();
+                }
+
+                // Speed-up banner, outlined so it reads over anything underneath
+                if speed_banner > 0 {
+                    let banner = Msg::SpeedUp.text(lang);
+                    let x = text::left_edge(banner, &big_yellow, SCREEN_W / 2, Alignment::Center);
+                    draw_text_fx(&mut display, banner, Point::new(x, 62), big_yellow, TextFx::Outline, Rgb565::BLACK).unwrap();
+                }
+
+                // Laser beam (line to target, drawn over the batch)
+                if laser_on && laser_hit {
+                    let pcx = player_x + ship.width / 2;
+                    Line::new(Point::new(pcx, player_y), Point::new(laser_tx, laser_ty))
+                        .into_styled(PrimitiveStyle::with_stroke(theme.laser, 1))
                         .draw(&mut display).unwrap();
+                }
+
+                // Collision rectangles as 1 px outlines over the sprites, the exact boxes aabb_overlap sees
+                if hitboxes {
+                    // Clipped so boxes entering from the top don't smear the HUD
+                    let mut field = display.clipped(&playfield);
+                    let outline = |x: i32, y: i32, w: i32, h: i32, color: Rgb565| {
+                        Rectangle::new(Point::new(x, y), Size::new(w as u32, h as u32))
+                            .into_styled(PrimitiveStyle::with_stroke(color, 1))
+                    };
+                    for sx in ship_copies(player_x, ship.width, SCREEN_W) {
+                        outline(sx, player_y, ship.width, PLAYER_H, Rgb565::MAGENTA).draw(&mut field).unwrap();
+                    }
+                    for o in obstacles.iter().filter(|o| o.active) {
+                        outline(o.x, o.y, o.w(), o.h(), Rgb565::WHITE).draw(&mut field).unwrap();
+                    }
+                    for m in missiles.iter().filter(|m| m.active) {
+                        outline(m.x, m.y, MISSILE_W, MISSILE_H, Rgb565::YELLOW).draw(&mut field).unwrap();
+                    }
+                    for g in gifts.iter().filter(|g| g.active) {
+                        outline(g.x, g.y, GIFT_W, GIFT_H, Rgb565::CYAN).draw(&mut field).unwrap();
+                    }
+                }
+
+                if run_cheated && !tas_run {
+                    tiny_font::draw(&mut display.translated(hud_shift), "CHEATED", 2, SCREEN_H - 8, 1, Rgb565::RED, Rgb565::BLACK)
+                        .unwrap();
+                }
+
+                // Debug overlay in the top-right of the playfield; the next flush wipes it when off
+                if overlay {
+                    let busy_tenths = busy_us / 100;
+                    let mut lines = [heapless::String::<16>::new(), heapless::String::new(), heapless::String::new(),
+                        heapless::String::new()];
+                    push_u32(&mut lines[0], (1_000_000 / period_us.max(1)) as u32);
+                    lines[0].push_str("FPS ").ok();
+                    push_u32(&mut lines[0], (busy_tenths / 10) as u32);
+                    lines[0].push('.').ok();
+                    push_u32(&mut lines[0], (busy_tenths % 10) as u32);
+                    lines[0].push_str("MS").ok();
+                    let pools = [
+                        ("OBS ", obstacles.iter().filter(|o| o.active).count(), MAX_OBS),
+                        ("MIS ", missiles.iter().filter(|m| m.active).count(), MAX_MISSILES),
+                        ("PRT ", particles.iter().filter(|p| p.life > 0).count(), MAX_PARTICLES),
+                    ];
+                    for (line, (label, used, max)) in lines[1..].iter_mut().zip(pools) {
+                        line.push_str(label).ok();
+                        push_u32(line, used as u32);
+                        line.push_str(" FREE ").ok();
+                        push_u32(line, (max - used) as u32);
+                    }
+                    for (i, line) in lines.iter().enumerate() {
+                        let x = SCREEN_W - 2 - tiny_font::width(line.len(), 1) as i32;
+                        tiny_font::draw(&mut display, line, x, HUD_H + 2 + i as i32 * 7, 1, Rgb565::GREEN, Rgb565::BLACK)
+                            .unwrap();
+                    }
+                }
+
+                // --- HUD: score (double-size tiny font) ---
+                if score != prev_score {
+                    draw_icon(&mut display.translated(hud_shift), &ICON_CROSSHAIR, 2, 3, Rgb565::YELLOW, Rgb565::BLACK)
+                        .unwrap();
+                    display.erase(Rectangle::new(Point::new(12, 0) + hud_shift, Size::new(66, HUD_H as u32)));
+                    buf.clear();
+                    push_u32(&mut buf, score);
+                    tiny_font::draw(&mut display.translated(hud_shift), &buf, 12, 1, 2, theme.hud, Rgb565::BLACK)
+                        .unwrap();
                     prev_score = score;
                 }
 
-                // --- HUD: bombs ---
-                if bombs != prev_bombs {
-                    Rectangle::new(Point::new(100, 0), Size::new(35, HUD_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
-                        .draw(&mut display).unwrap();
-                    for i in 0..MAX_BOMBS {
-                        let c = if i < bombs { bomb_on } else { bomb_off };
-                        Rectangle::new(Point::new(102 + (i as i32) * 10, 7), Size::new(7, 8))
-                            .into_styled(PrimitiveStyle::with_fill(c))
-                            .draw(&mut display).unwrap();
+                // --- HUD: bombs, the next one filling in as it regenerates ---
+                let regen_rows = (bomb_regen * 8 / secs_to_frames(BOMB_REGEN_SECS)) as usize;
+                if bombs != prev_bombs || regen_rows != prev_regen_rows {
+                    if max_bombs.max(bombs) > HUD_BOMB_SLOTS {
+                        let rows = if bombs > 0 { 8 } else { regen_rows };
+                        draw_icon_fill(&mut display.translated(hud_shift), &ICON_BOMB, 80, 3, theme.bomb_on, theme.bomb_off,
+                            Rgb565::BLACK, rows).unwrap();
+                        draw_hud_count(&mut display.translated(hud_shift), bombs, 90, theme.bomb_on).unwrap();
+                    } else {
+                        for i in 0..max_bombs {
+                            let rows = if i < bombs { 8 } else if i == bombs { regen_rows } else { 0 };
+                            draw_icon_fill(&mut display.translated(hud_shift), &ICON_BOMB, 80 + (i as i32) * 10, 3,
+                                theme.bomb_on, theme.bomb_off, Rgb565::BLACK, rows).unwrap();
+                        }
                     }
                     prev_bombs = bombs;
+                    prev_regen_rows = regen_rows;
                 }
 
-                // --- HUD: active power-ups ---
-                let pwr = (if freeze_timer > 0 { 1u8 } else { 0 })
-                    | (if homing_timer > 0 { 2 } else { 0 })
-                    | (if laser_timer > 0 { 4 } else { 0 })
-                    | (if shield_timer > 0 { 8 } else { 0 });
-                if pwr != prev_power {
-                    Rectangle::new(Point::new(135, 0), Size::new(60, HUD_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
-                        .draw(&mut display).unwrap();
-                    let mut ix = 137i32;
-                    if freeze_timer > 0 {
-                        let s = MonoTextStyle::new(&FONT_6X10, Rgb565::BLUE);
-                        Text::with_baseline("F", Point::new(ix, 7), s, Baseline::Top)
-                            .draw(&mut display).unwrap();
-                        ix += 10;
-                    }
-                    if homing_timer > 0 {
-                        let s = MonoTextStyle::new(&FONT_6X10, homing_color);
-                        Text::with_baseline("H", Point::new(ix, 7), s, Baseline::Top)
-                            .draw(&mut display).unwrap();
-                        ix += 10;
+                // --- HUD: demo tag, leak count, active power-ups with seconds left and freeze bolts in stock ---
+                // The trap has no letter; it shows as a swapped-arrows icon up front
+                let buffs = [
+                    (reverse_timer.div_ceil(FPS), "", GiftKind::Reverse.color()),
+                    (freeze_timer.div_ceil(FPS), "F", Rgb565::BLUE),
+                    (homing_timer.div_ceil(FPS), "H", theme.homing),
+                    (laser_timer.div_ceil(FPS), "L", theme.laser),
+                    (shield_timer.div_ceil(FPS), "S", Rgb565::WHITE),
+                    (twin_timer.div_ceil(FPS), "W", GiftKind::Twin.color()),
+                    (magnet_timer.div_ceil(FPS), "M", GiftKind::Magnet.color()),
+                    (bolts as u32, "Z", BOLT_COLOR),
+                ];
+                let pwr = buffs.map(|(n, _, _)| n);
+                if pwr != prev_power || run_leaks != prev_leaks {
+                    display.erase(Rectangle::new(Point::new(120, 0) + hud_shift, Size::new(80, HUD_H as u32 - 2)));
+                    let mut ix = 122i32;
+                    let tag = if demo_mode { "DEMO" } else if two_player { ["P1", "P2"][player] } else { "" };
+                    if !tag.is_empty() {
+                        tiny_font::draw(&mut display.translated(hud_shift), tag, ix, 4, 1, Rgb565::new(8, 16, 8), Rgb565::BLACK)
+                            .unwrap();
+                        ix += tiny_font::width(tag.len(), 1) as i32 + 4;
                     }
-                    if laser_timer > 0 {
-                        let s = MonoTextStyle::new(&FONT_6X10, laser_color);
-                        Text::with_baseline("L", Point::new(ix, 7), s, Baseline::Top)
-                            .draw(&mut display).unwrap();
-                        ix += 10;
+                    if leak_limit {
+                        buf.clear();
+                        push_u32(&mut buf, run_leaks);
+                        buf.push('/').ok();
+                        push_u32(&mut buf, LEAK_LIMIT);
+                        let c = if run_leaks >= LEAK_WARN { Rgb565::RED } else { Rgb565::new(31, 40, 0) };
+                        tiny_font::draw(&mut display.translated(hud_shift), &buf, ix, 4, 1, c, Rgb565::BLACK).unwrap();
+                        ix += tiny_font::width(buf.len(), 1) as i32 + 4;
                     }
-                    if shield_timer > 0 {
-                        let s = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
-                        Text::with_baseline("S", Point::new(ix, 7), s, Baseline::Top)
-                            .draw(&mut display).unwrap();
+                    for (secs, (_, letter, c)) in pwr.iter().zip(buffs) {
+                        if *secs == 0 { continue; }
+                        if letter.is_empty() {
+                            draw_icon(&mut display.translated(hud_shift), &ICON_REVERSE, ix, 3, c, Rgb565::BLACK)
+                                .unwrap();
+                            ix += 9;
+                        }
+                        buf.clear();
+                        buf.push_str(letter).ok();
+                        push_u32(&mut buf, *secs);
+                        let w = tiny_font::width(buf.len(), 1) as i32;
+                        if ix + w > 200 { break; }
+                        tiny_font::draw(&mut display.translated(hud_shift), &buf, ix, 4, 1, c, Rgb565::BLACK).unwrap();
+                        ix += w + 3;
                     }
                     prev_power = pwr;
+                    prev_leaks = run_leaks;
                 }
+                shield_bar.draw(&mut display.translated(hud_shift), shield_timer, SHIELD_DURATION).unwrap();
+                twin_bar.draw(&mut display.translated(hud_shift), twin_timer, TWIN_DURATION).unwrap();
+                let (boss_hp, mini_hp) = (if boss.active { boss.hp as u32 } else { 0 }, if mini.active { mini.hp as u32 } else { 0 });
+                boss_bar.draw(&mut display.translated(hud_shift), boss_hp, BOSS.hp as u32).unwrap();
+                mini_bar.draw(&mut display.translated(hud_shift), mini_hp, MINI_BOSS.hp as u32).unwrap();
+                dash_bar.draw(&mut display.translated(hud_shift), dash_cooldown, ms_to_frames(DASH_COOLDOWN_MS)).unwrap();
 
                 // --- HUD: lives ---
                 if lives != prev_lives {
-                    Rectangle::new(Point::new(200, 0), Size::new(40, HUD_H as u32))
-                        .into_styled(PrimitiveStyle::with_fill(Rgb565::BLACK))
-                        .draw(&mut display).unwrap();
-                    for i in 0..MAX_LIVES {
-                        let c = if i < lives { life_on } else { life_off };
-                        Rectangle::new(Point::new(204 + (i as i32) * 12, 7), Size::new(8, 8))
-                            .into_styled(PrimitiveStyle::with_fill(c))
-                            .draw(&mut display).unwrap();
+                    if MAX_LIVES.max(lives) > HUD_LIFE_SLOTS {
+                        let c = if lives > 0 { theme.life_on } else { theme.life_off };
+                        draw_icon(&mut display.translated(hud_shift), &ICON_HEART, 204, 3, c, Rgb565::BLACK).unwrap();
+                        draw_hud_count(&mut display.translated(hud_shift), lives, 214, theme.life_on).unwrap();
+                    } else {
+                        for i in 0..MAX_LIVES {
+                            let c = if i < lives { theme.life_on } else { theme.life_off };
+                            draw_icon(&mut display.translated(hud_shift), &ICON_HEART, 204 + (i as i32) * 12, 3, c, Rgb565::BLACK)
+                                .unwrap();
+                        }
                     }
                     prev_lives = lives;
                 }
             }
 
-            // ==================== GAME OVER ====================
-            GameState::GameOver => {
-                if prev_state != GameState::GameOver {
-                    if score > high_score { high_score = score; }
-                    display.clear(Rgb565::BLACK).unwrap();
-                    Text::with_baseline("GAME OVER", Point::new(50, 10), big_red, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    buf.clear();
-                    core::write!(buf, "{}", score).ok();
-                    Text::with_baseline(&buf, Point::new(100, 40), big_yellow, Baseline::Top)
-                        .draw(&mut display).unwrap();
+            // ==================== SHOP ====================
+            GameState::Shop => {
+                if prev_state != GameState::Shop {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::Shop.text(lang), Point::new(SCREEN_W / 2, 4), big_yellow,
+                        Alignment::Center).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::ShopHint.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    shop_sel = 0;
+                    shop_dirty = true;
+                    feedback.set_led(false);
+                    prev_state = GameState::Shop;
+                }
+
+                let n = SHOP_ITEMS.len();
+                if b_just { shop_sel = (shop_sel + n - 1) % n; shop_dirty = true; feedback.play(Sfx::MenuMove); }
+                if y_just { shop_sel = (shop_sel + 1) % n; shop_dirty = true; feedback.play(Sfx::MenuMove); }
+                if x_just {
+                    let item = SHOP_ITEMS[shop_sel];
+                    let capped = match item {
+                        ShopItem::Bomb => bombs >= max_bombs,
+                        ShopItem::Life => lives >= MAX_LIVES,
+                        ShopItem::Bolts => bolts >= MAX_BOLTS,
+                        _ => false,
+                    };
+                    if item == ShopItem::Done {
+                        game_state = GameState::Playing;
+                    } else if capped || wallet < item.cost() {
+                        feedback.play(Sfx::Warning);
+                    } else {
+                        wallet -= item.cost();
+                        match item {
+                            ShopItem::Bomb => bombs += 1,
+                            ShopItem::Life => lives += 1,
+                            ShopItem::Twin => twin_timer = TWIN_DURATION,
+                            ShopItem::Bolts => bolts = (bolts + BOLTS_PER_BUY).min(MAX_BOLTS),
+                            ShopItem::Done => {}
+                        }
+                        log::info!("Bought {}, coins left: {}", item.label().text(Lang::En), wallet);
+                        events.push(Event::Buy).ok();
+                        feedback.play(Sfx::Pickup);
+                        shop_dirty = true;
+                    }
+                }
+
+                if shop_dirty && game_state == GameState::Shop {
+                    display.erase(Rectangle::new(Point::new(0, 28), Size::new(SCREEN_W as u32, 94)));
                     buf.clear();
-                    core::write!(buf, "Best: {}", high_score).ok();
-                    Text::with_baseline(&buf, Point::new(60, 70), big_white, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    Text::with_baseline("Press any button", Point::new(20, 105), big_white, Baseline::Top)
-                        .draw(&mut display).unwrap();
-                    led.set_low();
-                    prev_state = GameState::GameOver;
-                    log::info!("Game Over screen");
+                    buf.push_str(Msg::Coins.text(lang)).ok();
+                    push_u32(&mut buf, wallet);
+                    text::draw(&mut display, &buf, Point::new(20, 30), MonoTextStyle::new(&FONT_8X13, Rgb565::YELLOW)).unwrap();
+                    for (i, it) in SHOP_ITEMS.iter().enumerate() {
+                        buf.clear();
+                        buf.push_str(if i == shop_sel { "> " } else { "  " }).ok();
+                        buf.push_str(it.label().text(lang)).ok();
+                        if it.cost() > 0 {
+                            buf.push_str(" (").ok();
+                            push_u32(&mut buf, it.cost());
+                            buf.push(')').ok();
+                        }
+                        let c = if i == shop_sel { theme.player }
+                            else if it.cost() > wallet { Rgb565::new(12, 24, 12) }
+                            else { theme.hud };
+                        text::draw(&mut display, &buf, Point::new(20, 48 + i as i32 * 15), MonoTextStyle::new(&FONT_8X13, c))
+                            .unwrap();
+                    }
+                    shop_dirty = false;
+                }
+            }
+
+            // ==================== GAME OVER / VICTORY ====================
+            // Both end the run the same way and differ only in what they show
+            GameState::GameOver | GameState::Victory => {
+                let victory = game_state == GameState::Victory;
+                if prev_state != game_state {
+                    // Rubber band: judge the run before mission bonuses; a script would replay differently after it
+                    if !demo_mode && !tas_run {
+                        if score < ASSIST_SHORT_RUN {
+                            short_runs += 1;
+                            if short_runs >= ASSIST_SHORT_RUNS {
+                                short_runs = 0;
+                                assist_level = (assist_level + 1).min(ASSIST_MAX_LEVEL);
+                            }
+                        } else {
+                            short_runs = 0;
+                            assist_level = assist_level.saturating_sub(1);
+                        }
+                        if settings.assist { log::info!("Assist level {}", assist_level); }
+                    }
+                    let mut mission_bonus = 0;
+                    if !demo_mode {
+                        mission_bonus = missions.iter().filter(|m| m.done()).count() as u32 * missions::BONUS;
+                        score += mission_bonus;
+                    }
+                    if run_cheated { log::info!("Cheats used; score not recorded"); }
+                    if score > high_score && !run_cheated { high_score = score; }
+                    if two_player { player_scores[player] = score; }
+                    let duration_ms = (run_frames as u64 * FRAME_US / 1000) as u32;
+                    log::info!("Run: {} points, {}/{} shots hit, {} ms, seed {:08x}", score, run_hits, run_shots, duration_ms, run_seed);
+                    if !demo_mode { heat.dump(); }
+                    #[cfg(feature = "sd-log")]
+                    if !demo_mode && !run_cheated {
+                        sd_log.append(&sdlog::Run {
+                            frame, score, shots: run_shots, hits: run_hits, duration_ms, seed: run_seed,
+                        });
+                    }
+                    // Bank the run's kills; only real games count toward unlocks (and a script must replay the same)
+                    let mut unlocked: Option<Unlock> = None;
+                    if !demo_mode && !tas_run {
+                        let before = progress;
+                        progress.cleared |= victory;
+                        if !run_cheated { progress.best_score = progress.best_score.max(score); }
+                        progress.kills = progress.kills.saturating_add(run_kills);
+                        run_kills = 0;
+                        tracker.handle(Event::LifetimeKills(progress.kills));
+                        progress.achievements = tracker.earned;
+                        unlocked = UNLOCKS.iter().copied().find(|u| u.earned(&progress) && !u.earned(&before));
+                        if progress != saved_progress {
+                            match progress.save(&mut store) {
+                                Ok(()) => saved_progress = progress,
+                                Err(_) => log::warn!("Progress save failed"),
+                            }
+                        }
+                    }
+                    display.erase_all();
+                    if victory {
+                        let title = Msg::Victory.text(lang);
+                        let x = text::left_edge(title, &big_yellow, SCREEN_W / 2, Alignment::Center);
+                        draw_text_fx(&mut display, title, Point::new(x, 4),
+                            big_yellow, TextFx::Outline, Rgb565::RED).unwrap();
+                        buf.clear();
+                        buf.push_str(Msg::ScoreLabel.text(lang)).ok();
+                        push_u32(&mut buf, score - mission_bonus);
+                        buf.push_str(Msg::MissionsPlus.text(lang)).ok();
+                        push_u32(&mut buf, mission_bonus);
+                        let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                        text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 28), hint,
+                            Alignment::Center).unwrap();
+                        if let Some(u) = unlocked { log::info!("Unlocked {}", u.name().text(Lang::En)); }
+                        tally = 0;
+                        credits_scroll = 0;
+                        log::info!("Victory! Final score: {}", score);
+                    } else {
+                        let title = Msg::GameOver.text(lang);
+                        let x = text::left_edge(title, &big_red, SCREEN_W / 2, Alignment::Center);
+                        draw_text_fx(&mut display, title, Point::new(x, 10),
+                            big_red, TextFx::Shadow, Rgb565::new(10, 0, 0)).unwrap();
+                        buf.clear();
+                        push_u32(&mut buf, score);
+                        Text::with_baseline(&buf, Point::new(100, 40), big_yellow, Baseline::Top)
+                            .draw(&mut display).unwrap();
+                        if !demo_mode {
+                            buf.clear();
+                            buf.push_str(Msg::MissionsLabel.text(lang)).ok();
+                            push_u32(&mut buf, missions.iter().filter(|m| m.done()).count() as u32);
+                            buf.push('/').ok();
+                            push_u32(&mut buf, missions.len() as u32);
+                            buf.push_str(" +").ok();
+                            push_u32(&mut buf, mission_bonus);
+                            let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                            text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 60), hint, Alignment::Center)
+                                .unwrap();
+                        }
+                        buf.clear();
+                        buf.push_str(Msg::Best.text(lang)).ok();
+                        push_u32(&mut buf, high_score);
+                        text::draw(&mut display, &buf, Point::new(60, 70), big_white).unwrap();
+                        if let Some(u) = unlocked {
+                            buf.clear();
+                            buf.push_str(Msg::Unlocked.text(lang)).ok();
+                            buf.push_str(u.name().text(lang)).ok();
+                            let style = MonoTextStyle::new(&FONT_6X10, Rgb565::GREEN);
+                            text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 93), style, Alignment::Center)
+                                .unwrap();
+                            log::info!("Unlocked {}", u.name().text(Lang::En));
+                        }
+                        text::draw(&mut display, Msg::PressAny.text(lang), Point::new(20, 107), big_white).unwrap();
+                        log::info!("Game Over screen");
+                    }
+                    feedback.set_led(false);
+                    end_idle = 0;
+                    prev_state = game_state;
+                }
+
+                // Nobody around: back to the title rather than sitting on this screen
+                end_idle = if a_down || b_down || x_down || y_down { 0 } else { end_idle + 1 };
+                if end_idle >= END_SCREEN_IDLE_FRAMES && !demo_mode {
+                    log::info!("End screen idle, back to the title");
+                    two_player = false;
+                    game_state = GameState::Title;
                 }
 
-                if demo_mode {
-                    if frame % 40 == 0 { game_state = GameState::Title; }
+                // In 2P the first run hands over to the second player, the second to the results
+                let next = if !two_player { GameState::Title } else if player == 0 { GameState::GetReady } else { GameState::Versus };
+                if victory {
+                    // Total counts up, then the credits roll until a button is pressed
+                    if tally < score {
+                        tally = (tally + score.div_ceil(ms_to_frames(TALLY_MS))).min(score);
+                        buf.clear();
+                        buf.push_str(Msg::Total.text(lang)).ok();
+                        push_u32(&mut buf, tally);
+                        // The centered line shifts as digits are added, so blank all of it; row 39 is the hint's Hangul
+                        display.erase(Rectangle::new(Point::new(0, 40), Size::new(SCREEN_W as u32, 22)));
+                        text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 40), big_white, Alignment::Center)
+                            .unwrap();
+                        if tally == score {
+                            let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                            let prompt = Msg::PressAny.text(lang);
+                            text::draw_aligned(&mut display, prompt, Point::new(SCREEN_W / 2, 124), hint, Alignment::Center)
+                                .unwrap();
+                        }
+                    } else {
+                        let roll = CREDITS_H + CREDITS.len() as i32 * CREDITS_LINE_H;
+                        credits_scroll = (credits_scroll + step(CREDITS_SPEED, frame)) % roll;
+                        display.erase(Rectangle::new(Point::new(0, CREDITS_TOP), Size::new(SCREEN_W as u32, CREDITS_H as u32)));
+                        let style = MonoTextStyle::new(&FONT_6X10, theme.hud);
+                        for (i, line) in CREDITS.iter().enumerate() {
+                            let y = CREDITS_TOP + CREDITS_H - credits_scroll + i as i32 * CREDITS_LINE_H;
+                            // Whole lines only, so nothing spills outside the credits band; Hangul
+                            // reaches a pixel above and below the text
+                            if y - 1 < CREDITS_TOP || y + 11 > CREDITS_TOP + CREDITS_H { continue; }
+                            let line = line.text(lang);
+                            text::draw_aligned(&mut display, line, Point::new(SCREEN_W / 2, y), style, Alignment::Center)
+                                .unwrap();
+                        }
+                        if a_just || b_just || x_just || y_just { game_state = next; }
+                    }
+                } else if demo_mode {
+                    if frame.is_multiple_of(secs_to_frames(2)) { game_state = GameState::Title; }
                 } else if a_just || b_just || x_just || y_just {
+                    game_state = next;
+                }
+            }
+
+            // ==================== 2P: GET READY ====================
+            GameState::GetReady => {
+                if prev_state != GameState::GetReady {
+                    display.erase_all();
+                    for (msg, style, y) in [(Msg::Player2, big_yellow, 20), (Msg::GetReady, big_white, 45)] {
+                        let text = msg.text(lang);
+                        text::draw_aligned(&mut display, text, Point::new(SCREEN_W / 2, y), style,
+                            Alignment::Center).unwrap();
+                    }
+                    buf.clear();
+                    buf.push_str(Msg::P1Scored.text(lang)).ok();
+                    push_u32(&mut buf, player_scores[0]);
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 78), hint,
+                        Alignment::Center).unwrap();
+                    text::draw(&mut display, Msg::PressAny.text(lang), Point::new(20, 107), big_white).unwrap();
+                    prev_state = GameState::GetReady;
+                    log::info!("Player 2 get ready");
+                }
+                if a_just || b_just || x_just || y_just {
+                    player = 1;
+                    start_run = true;
+                }
+            }
+
+            // ==================== 2P: RESULTS ====================
+            GameState::Versus => {
+                if prev_state != GameState::Versus {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::Results.text(lang), Point::new(SCREEN_W / 2, 4), big_yellow,
+                        Alignment::Center).unwrap();
+                    let [p1, p2] = player_scores;
+                    for (i, &sc) in player_scores.iter().enumerate() {
+                        buf.clear();
+                        buf.push_str(["P1  ", "P2  "][i]).ok();
+                        push_u32(&mut buf, sc);
+                        // The winner's line in yellow
+                        let won = sc > player_scores[1 - i];
+                        let style = if won { big_yellow } else { big_white };
+                        Text::with_baseline(&buf, Point::new(70, 32 + i as i32 * 24), style, Baseline::Top)
+                            .draw(&mut display).unwrap();
+                    }
+                    let verdict = if p1 > p2 { Msg::P1Wins } else if p2 > p1 { Msg::P2Wins } else { Msg::Draw }.text(lang);
+                    let x = text::left_edge(verdict, &big_yellow, SCREEN_W / 2, Alignment::Center);
+                    draw_text_fx(&mut display, verdict, Point::new(x, 84), big_yellow, TextFx::Outline,
+                        Rgb565::RED).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    let prompt = Msg::PressAny.text(lang);
+                    text::draw_aligned(&mut display, prompt, Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    prev_state = GameState::Versus;
+                    log::info!("2P results: {} vs {}", p1, p2);
+                }
+                if a_just || b_just || x_just || y_just {
+                    two_player = false;
                     game_state = GameState::Title;
                 }
             }
+
+            // ==================== PAUSED ====================
+            GameState::Paused => {
+                if prev_state != GameState::Paused {
+                    let mut snap = Snapshot {
+                        rng: rng.state, score, score_offset, perfect_points, next_shop_score, next_mini_score,
+                        speed_base_score, speed_level: speed_level as u8, loop_count: loop_count as u8,
+                        wallet, run_kills, lives, bombs, max_bombs,
+                        bolts, profile: profile as u8, charge: charge as u16, player_x: player_x as i16, player_y: player_y as i16,
+                        timers: Timers {
+                            freeze: freeze_timer as u16, homing: homing_timer as u16, laser: laser_timer as u16,
+                            shield: shield_timer as u16, twin: twin_timer as u16, magnet: magnet_timer as u16,
+                            reverse: reverse_timer as u16, dash_cooldown: dash_cooldown as u16,
+                            bomb_regen: bomb_regen as u16,
+                        },
+                        wave_leaks: wave_leaks as u16, run_leaks: run_leaks as u16, wave_hit, cheated: run_cheated,
+                        boss_hp: if boss.active { boss.hp } else { 0 }, boss_wave: boss_wave as u8,
+                        mini_hp: if mini.active { mini.hp } else { 0 },
+                        missions: [(0, 0, 0); missions::PER_RUN],
+                        obstacles: heapless::Vec::new(),
+                    };
+                    for (s, m) in snap.missions.iter_mut().zip(&missions) {
+                        let kind = MissionKind::ALL.iter().position(|&k| k == m.kind).unwrap_or(0) as u8;
+                        let (count, ms) = m.progress();
+                        *s = (kind, count, ms);
+                    }
+                    for o in obstacles.iter().filter(|o| o.active) {
+                        let kind = ObstacleKind::ALL.iter().position(|&k| k == o.kind).unwrap_or(0) as u8;
+                        snap.obstacles.push(SavedObstacle {
+                            x: o.x as i16, y: o.y as i16, dx: o.dx as i16, kind, frozen: o.frozen as u16, hp: o.hp,
+                        }).ok();
+                    }
+                    let saved = snap.save(&mut store);
+                    if saved.is_err() { log::warn!("Run save failed"); }
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::Paused.text(lang), Point::new(SCREEN_W / 2, 30), big_yellow,
+                        Alignment::Center).unwrap();
+                    let (note, color) = if saved.is_ok() { (Msg::RunSaved, theme.hud) } else { (Msg::SaveFailed, Rgb565::RED) };
+                    text::draw_aligned(&mut display, note.text(lang), Point::new(SCREEN_W / 2, 62),
+                        MonoTextStyle::new(&FONT_8X13, color), Alignment::Center).unwrap();
+                    text::draw_aligned(&mut display, Msg::ResumeHint.text(lang), Point::new(SCREEN_W / 2, 107), big_white,
+                        Alignment::Center).unwrap();
+                    feedback.set_led(false);
+                    pause_idle = 0;
+                    prev_state = GameState::Paused;
+                    log::info!("Paused at {} points", score);
+                }
+                pause_idle += 1;
+                if pause_idle >= PAUSE_TIMEOUT_FRAMES {
+                    log::info!("Pause timed out; the run stays saved for the next boot");
+                    game_state = GameState::Title;
+                }
+                // Played on from here, so the save is spent
+                if a_just || x_just {
+                    if savestate::erase(&mut store).is_err() { log::warn!("Run save erase failed"); }
+                    game_state = GameState::Playing;
+                }
+            }
+
+            // ==================== RESUME ====================
+            GameState::Resume => {
+                if prev_state != GameState::Resume {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::SavedRun.text(lang), Point::new(SCREEN_W / 2, 20), big_yellow,
+                        Alignment::Center).unwrap();
+                    if let Some(s) = &saved_run {
+                        let wave = (s.score + s.score_offset).saturating_sub(s.perfect_points) / WAVE_POINTS + 1;
+                        buf.clear();
+                        buf.push_str(Msg::WaveLabel.text(lang)).ok();
+                        push_u32(&mut buf, wave);
+                        buf.push_str("  ").ok();
+                        buf.push_str(Msg::ScoreLabel.text(lang)).ok();
+                        push_u32(&mut buf, s.score);
+                        text::draw_aligned(&mut display, &buf, Point::new(SCREEN_W / 2, 55),
+                            MonoTextStyle::new(&FONT_8X13, theme.hud), Alignment::Center).unwrap();
+                    }
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::ResumeOrDiscard.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    prev_state = GameState::Resume;
+                }
+                if a_just || x_just {
+                    // The new-run reset below applies the save on top
+                    start_run = true;
+                } else if b_just || y_just {
+                    saved_run = None;
+                    if savestate::erase(&mut store).is_err() { log::warn!("Run save erase failed"); }
+                    game_state = GameState::Title;
+                }
+            }
+
+            // ==================== FACTORY RESET ====================
+            GameState::FactoryReset => {
+                if prev_state != GameState::FactoryReset {
+                    display.erase_all();
+                    text::draw_aligned(&mut display, Msg::EraseAll.text(lang), Point::new(SCREEN_W / 2, 20), big_red,
+                        Alignment::Center).unwrap();
+                    text::draw_aligned(&mut display, Msg::EraseWhat.text(lang), Point::new(SCREEN_W / 2, 58),
+                        MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE), Alignment::Center).unwrap();
+                    let hint = MonoTextStyle::new(&FONT_6X10, Rgb565::new(16, 32, 16));
+                    text::draw_aligned(&mut display, Msg::EraseOrCancel.text(lang), Point::new(SCREEN_W / 2, 124), hint,
+                        Alignment::Center).unwrap();
+                    reset_hold = 0;
+                    erase_armed = false;
+                    prev_state = GameState::FactoryReset;
+                    log::info!("Factory reset?");
+                }
+                // The hold that got here must be let go first
+                if !(a_down || b_down || x_down || y_down) { erase_armed = true; }
+                if erase_armed && (a_just || x_just) {
+                    watchdog.feed();
+                    if store.wipe().is_ok() {
+                        log::info!("Store erased, rebooting");
+                        display.erase_all();
+                        text::draw_aligned(&mut display, Msg::Erased.text(lang), Point::new(SCREEN_W / 2, 55), big_yellow,
+                            Alignment::Center).unwrap();
+                        Timer::after(Duration::from_millis(500)).await;
+                        reboot(&mut watchdog);
+                    }
+                    log::warn!("Store erase failed");
+                    text::draw_aligned(&mut display, Msg::EraseFailed.text(lang), Point::new(SCREEN_W / 2, 85),
+                        MonoTextStyle::new(&FONT_8X13, Rgb565::RED), Alignment::Center).unwrap();
+                } else if erase_armed && (b_just || y_just) {
+                    game_state = GameState::Title;
+                }
+            }
+
+            // ==================== SCREENSAVER ====================
+            GameState::Screensaver => {
+                let cell = FONT_10X20.character_size;
+                let size = Size::new(cell.width * SAVER_LOGO.len() as u32, cell.height);
+                if prev_state != GameState::Screensaver {
+                    display.erase_all();
+                    saver_pos = Point::new((SCREEN_W - size.width as i32) / 2, (SCREEN_H - size.height as i32) / 2);
+                    prev_state = GameState::Screensaver;
+                    log::info!("Screensaver");
+                }
+
+                // Bounce off the edges, changing color at each
+                let (max_x, max_y) = (SCREEN_W - size.width as i32, SCREEN_H - size.height as i32);
+                let d = step(SAVER_SPEED, frame);
+                let mut next = saver_pos + saver_dir * d;
+                if !(0..=max_x).contains(&next.x) {
+                    saver_dir.x = -saver_dir.x;
+                    next.x = next.x.clamp(0, max_x);
+                    saver_color = (saver_color + 1) % SAVER_COLORS.len();
+                }
+                if !(0..=max_y).contains(&next.y) {
+                    saver_dir.y = -saver_dir.y;
+                    next.y = next.y.clamp(0, max_y);
+                    saver_color = (saver_color + 1) % SAVER_COLORS.len();
+                }
+                // The logo paints its own cell background, so only the strips it leaves behind need blanking
+                let (dx, dy) = (next.x - saver_pos.x, next.y - saver_pos.y);
+                if dx != 0 {
+                    let x = if dx > 0 { saver_pos.x } else { next.x + size.width as i32 };
+                    display.erase(Rectangle::new(Point::new(x, saver_pos.y), Size::new(dx.unsigned_abs(), size.height)));
+                }
+                if dy != 0 {
+                    let y = if dy > 0 { saver_pos.y } else { next.y + size.height as i32 };
+                    display.erase(Rectangle::new(Point::new(saver_pos.x, y), Size::new(size.width, dy.unsigned_abs())));
+                }
+                saver_pos = next;
+                let mut style = MonoTextStyle::new(&FONT_10X20, SAVER_COLORS[saver_color]);
+                style.background_color = Some(Rgb565::BLACK);
+                Text::with_baseline(SAVER_LOGO, saver_pos, style, Baseline::Top).draw(&mut display).unwrap();
+
+                // Any press wakes the screen it covered; that press does nothing more
+                if a_just || b_just || x_just || y_just {
+                    log::info!("Screensaver off");
+                    game_state = saver_from;
+                }
+            }
+        }
+
+        // --- New run: started from the title, or the second player's turn in 2P ---
+        if start_run {
+            start_run = false;
+            // A script replays from its own seed and frame zero, on a fresh input history
+            tas_run = tas_play;
+            if tas_run {
+                rng = Rng::new(script.seed);
+                rng_seeded = true;
+                frame = 0;
+                script.rewind();
+                tap_b = DoubleTap::new();
+                tap_y = DoubleTap::new();
+                (prev_a, prev_b, prev_x, prev_y) = (false, false, false, false);
+            }
+            practice_y_hold = 0;
+            tutorial_moved = (false, false);
+            tutorial_done_timer = 0;
+            // The demo and the tutorial fly the standard ship; everything else the last one picked, if lane mode allows it
+            let picked = settings.profile as usize % ships::PROFILES.len();
+            let lanes_ok = !settings.lanes || ships::PROFILES[picked].fits_lanes();
+            profile = if demo_mode || tutorial.is_some() || !lanes_ok { 0 } else { picked };
+            let ship = &ships::PROFILES[profile];
+            player_x = (SCREEN_W - ship.width) / 2;
+            player_y = PLAYER_Y;
+            for o in obstacles.iter_mut() { o.active = false; }
+            for m in missiles.iter_mut() { m.active = false; }
+            for p in particles.iter_mut() { p.life = 0; }
+            for e in explosions.iter_mut() { e.active = false; }
+            for g in gifts.iter_mut() { g.active = false; }
+            for c in coins.iter_mut() { c.active = false; }
+            barrier.active = false;
+            boss.active = false;
+            mini.active = false;
+            next_mini_score = MINI_BOSS_POINTS;
+            for b in bullets.iter_mut() { b.active = false; }
+            for w in wrecks.iter_mut() { w.active = false; }
+            score = 0;
+            lives = MAX_LIVES;
+            max_bombs = MAX_BOMBS + Unlock::BombBay.earned(&progress) as u8 + ship.extra_bombs;
+            bombs = max_bombs;
+            run_kills = 0;
+            run_shots = 0;
+            run_hits = 0;
+            run_frames = 0;
+            heat.clear();
+            run_cheated = god || tas_run;
+            tracker.start_run();
+            events.clear();
+            toasts.clear();
+            toast = None;
+            toast_timer = 0;
+            popup.timer = 0;
+            freeze_timer = 0;
+            homing_timer = 0;
+            laser_timer = 0;
+            shield_timer = 0;
+            twin_timer = 0;
+            magnet_timer = 0;
+            reverse_timer = 0;
+            spawn_timer = 0;
+            spawn_held = 0;
+            gift_spawn_timer = 0;
+            invincible = 0;
+            dash_cooldown = 0;
+            charge = 0;
+            bomb_regen = 0;
+            speed_base_score = 0;
+            danger_tint = 0;
+            heartbeat = 0;
+            warn_cooldown = 0;
+            speed_level = 0;
+            speed_banner = 0;
+            loop_count = 1;
+            loop_banner = 0;
+            zone_idx = 0;
+            beat_timer = 0;
+            perfect_banner = 0;
+            wave_leaks = 0;
+            run_leaks = 0;
+            leak_limit = settings.leak_limit && !demo_mode && !practice && tutorial.is_none();
+            wave_hit = false;
+            perfect_points = 0;
+            wallet = 0;
+            bolts = 0;
+            next_shop_score = WAVE_POINTS;
+            score_offset = 0;
+            scheduler.clear();
+            rock_timer = 0;
+            blast_timer = 0;
+            // Roll distinct missions; the demo and scripts skip the briefing
+            for i in 0..missions.len() {
+                loop {
+                    let kind = MissionKind::ALL[rng.range(MissionKind::ALL.len() as i32) as usize];
+                    if missions[..i].iter().all(|m| m.kind != kind) { missions[i] = Mission::new(kind); break; }
+                }
+            }
+            let mode = if demo_mode { "Demo" } else if tas_run { "TAS" } else if tutorial.is_some() { "Tutorial" }
+                else if practice { "Practice" } else { "Game" };
+            game_state = if mode == "Game" { GameState::Briefing } else { GameState::Playing };
+            log::info!("{} start!", mode);
+            // Resuming a saved run: the reset above cleared what the save doesn't cover
+            if let Some(s) = saved_run.take() {
+                rng = Rng { state: s.rng };
+                rng_seeded = true;
+                profile = s.profile as usize % ships::PROFILES.len();
+                if settings.lanes && !ships::PROFILES[profile].fits_lanes() { profile = 0; }
+                score = s.score;
+                score_offset = s.score_offset;
+                perfect_points = s.perfect_points;
+                next_shop_score = s.next_shop_score;
+                next_mini_score = s.next_mini_score;
+                speed_base_score = s.speed_base_score;
+                speed_level = s.speed_level as u32;
+                loop_count = s.loop_count.max(1) as u32;
+                wallet = s.wallet;
+                run_kills = s.run_kills;
+                lives = s.lives;
+                bombs = s.bombs;
+                max_bombs = s.max_bombs;
+                bolts = s.bolts;
+                charge = s.charge as u32;
+                player_x = s.player_x as i32;
+                player_y = s.player_y as i32;
+                freeze_timer = s.timers.freeze as u32;
+                homing_timer = s.timers.homing as u32;
+                laser_timer = s.timers.laser as u32;
+                shield_timer = s.timers.shield as u32;
+                twin_timer = s.timers.twin as u32;
+                magnet_timer = s.timers.magnet as u32;
+                reverse_timer = s.timers.reverse as u32;
+                dash_cooldown = s.timers.dash_cooldown as u32;
+                bomb_regen = s.timers.bomb_regen as u32;
+                wave_leaks = s.wave_leaks as u32;
+                run_leaks = s.run_leaks as u32;
+                wave_hit = s.wave_hit;
+                run_cheated |= s.cheated;
+                boss_wave = s.boss_wave as u32;
+                if s.boss_hp > 0 {
+                    boss = Boss::spawn(&BOSS);
+                    boss.hp = s.boss_hp;
+                }
+                if s.mini_hp > 0 {
+                    mini = Boss::spawn(&MINI_BOSS);
+                    mini.hp = s.mini_hp;
+                }
+                for (m, &(kind, count, ms)) in missions.iter_mut().zip(&s.missions) {
+                    *m = Mission::restore(MissionKind::ALL[kind as usize % MissionKind::ALL.len()], count, ms);
+                }
+                for (o, so) in obstacles.iter_mut().zip(&s.obstacles) {
+                    *o = Obstacle {
+                        x: so.x as i32, y: so.y as i32, dx: so.dx as i32,
+                        kind: ObstacleKind::ALL[so.kind as usize % ObstacleKind::ALL.len()],
+                        frozen: so.frozen as u32, hp: so.hp, active: true,
+                    };
+                }
+                // A moment to get bearings before anything can hit
+                invincible = ms_to_frames(INVINCIBLE_MS);
+                if savestate::erase(&mut store).is_err() { log::warn!("Run save erase failed"); }
+                game_state = GameState::Playing;
+                log::info!("Resumed a saved run at {} points", score);
+            }
+            run_seed = rng.state;
+            if !demo_mode && session_start.is_none() { session_start = Some(Instant::now()); }
+            lane = (player_x + ships::PROFILES[profile].width / 2) / LANE_W;
+            lane_repeat = 0;
+            trail.clear();
+            fast_streak = 0;
+            hold_vel = [0; 2];
+            defmt::info!("run start f={=u32} seed={=u32:08x} demo={=bool} practice={=bool}", frame, run_seed, demo_mode, practice);
         }
 
+        // Trace every event for reconstructing runs from a probe-rs log; the timestamp is uptime
+        for ev in &events { defmt::debug!("ev f={=u32} {}", frame, ev); }
+
+        // Achievements: demo runs don't count
+        tracker.tick(FRAME_US as u32 / 1000);
+        for ev in events.iter().copied().filter(|_| !demo_mode && !tas_run && tutorial.is_none() && !practice) {
+            for m in missions.iter_mut().filter(|m| !m.done()) {
+                m.handle(ev);
+                if m.done() { log::info!("Mission complete"); }
+            }
+            if let Event::WaveClear { wave } = ev { progress.best_wave = progress.best_wave.max((wave + 1).min(u8::MAX as u32) as u8); }
+            let new = tracker.handle(ev);
+            for a in Achievement::ALL.iter().copied().filter(|a| new & a.bit() != 0) {
+                log::info!("Achievement: {}", a.name().text(Lang::En));
+                toasts.push_back(a).ok();
+                feedback.play(Sfx::Achievement);
+            }
+        }
+        events.clear();
+        progress.achievements = tracker.earned;
+
+        // Play runs at the chosen pace; menus always at FPS
+        let frame_us = if game_state == GameState::Playing {
+            1_000_000 / PACE_FPS[settings.pace as usize % PACE_FPS.len()] as u64
+        } else {
+            FRAME_US
+        };
+
+        // The demo plays silent and still
+        let outputs = if settings.sound { feedback::BUZZER } else { 0 } | settings.outputs;
+        feedback.enabled = if demo_mode && game_state == GameState::Playing { outputs & !(feedback::BUZZER | feedback::VIBE) }
+            else { outputs };
+        feedback.tick((frame_us / 1000) as u32);
+
+        // Fade back in once the new screen has been drawn; the screensaver keeps the panel dim
+        backlight.fade(if game_state == GameState::Screensaver { SAVER_BACKLIGHT } else { BACKLIGHT_FULL }).await;
+
+        // Light the corner on the frame that answers a press, and blank it on the next (a run's flush repaints it)
+        let pressed = a_just || b_just || x_just || y_just;
+        let corner = Rectangle::new(Point::new(SCREEN_W - LATENCY_FLASH, SCREEN_H - LATENCY_FLASH),
+            Size::new(LATENCY_FLASH as u32, LATENCY_FLASH as u32));
+        if latency && pressed {
+            corner.into_styled(PrimitiveStyle::with_fill(Rgb565::WHITE)).draw(&mut display).unwrap();
+            latency_lit = true;
+        } else if latency_lit {
+            if game_state != GameState::Playing { display.erase(corner); }
+            latency_lit = false;
+        }
+
+        // A dropped frame may have left whatever is drawn once half done, so it's all drawn again
+        let mut repaint = false;
+        match display.end_frame() {
+            Frame::Drawn => {}
+            Frame::Dropped => repaint = true,
+            Frame::Stuck => reinit = true,
+            Frame::Fatal(code) => display_fault(&mut display, &mut watchdog, code),
+        }
+        if reinit {
+            log::warn!("Re-initializing the display");
+            display = display.rebuild(|screen| reinit_screen(screen, settings.flipped));
+            display.erase_all();
+            repaint = true;
+        }
+        if repaint {
+            match game_state {
+                // The playfield is redrawn every frame anyway
+                GameState::Playing => hud_stale = true,
+                // Their entry code also banks the run, so only what's drawn every frame comes back
+                GameState::GameOver | GameState::Victory => {}
+                // Menu entry code only draws, so rerunning it repaints the screen
+                _ => prev_state = GameState::Playing,
+            }
+        }
+
+        watchdog.set_scratch(SCRATCH_SCORE, score);
+        watchdog.feed();
+        let took = frame_start.elapsed().as_micros();
+        busy_us = (busy_us * 7 + took) / 8;
+        if took > frame_us { clog!(Render, Debug, "f={} took {} us of {}", frame, took, frame_us); }
+        if latency && pressed {
+            // An edge that came in while the frame was busy is only seen at the poll
+            let (from, source) = match edge_at { Some(t) => (t, "edge"), None => (frame_start, "poll") };
+            log::info!("Latency f={} {} us from {} to frame out", frame, from.elapsed().as_micros(), source);
+        }
+        edge_at = None;
         frame = frame.wrapping_add(1);
-        Timer::at(frame_start + Duration::from_millis(50)).await;
+        let deadline = frame_start + Duration::from_micros(frame_us);
+        if latency {
+            // Wait out the frame on the button edges too, to stamp a press the moment it lands
+            let edges = select4(btn_a.wait_for_falling_edge(), btn_b.wait_for_falling_edge(),
+                btn_x.wait_for_falling_edge(), btn_y.wait_for_falling_edge());
+            if let Either::Second(_) = select(Timer::at(deadline), edges).await { edge_at = Some(Instant::now()); }
+        }
+        Timer::at(deadline).await;
     }
 }
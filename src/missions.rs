@@ -0,0 +1,81 @@
+//! Per-run objectives rolled at the start of a game.
+//!
+//! Missions count the same gameplay events as achievements; each one
+//! completed by game over adds `BONUS` to the final score.
+
+use crate::achievements::Event;
+use crate::strings::Msg;
+
+pub const BONUS: u32 = 10;
+pub const PER_RUN: usize = 2;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MissionKind {
+    Destroy,
+    Gifts,
+    Survive,
+    Coins,
+    Score,
+}
+
+impl MissionKind {
+    pub const ALL: [Self; 5] = [Self::Destroy, Self::Gifts, Self::Survive, Self::Coins, Self::Score];
+
+    fn target(self) -> u32 {
+        match self {
+            Self::Destroy => 15,
+            Self::Gifts => 3,
+            Self::Survive => 90,
+            Self::Coins => 5,
+            Self::Score => 60,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Mission {
+    pub kind: MissionKind,
+    count: u32,
+    survived_ms: u32,
+}
+
+impl Mission {
+    pub const fn new(kind: MissionKind) -> Self { Self { kind, count: 0, survived_ms: 0 } }
+
+    /// Text before and after the target number, e.g. "Destroy " 15 " obstacles" in English.
+    pub fn describe(&self) -> (Msg, u32, Msg) {
+        let t = self.kind.target();
+        match self.kind {
+            MissionKind::Destroy => (Msg::DestroyPre, t, Msg::DestroyPost),
+            MissionKind::Gifts => (Msg::GiftsPre, t, Msg::GiftsPost),
+            MissionKind::Survive => (Msg::SurvivePre, t, Msg::SurvivePost),
+            MissionKind::Coins => (Msg::CoinsPre, t, Msg::CoinsPost),
+            MissionKind::Score => (Msg::ScorePre, t, Msg::ScorePost),
+        }
+    }
+
+    /// Progress as (count, survived milliseconds), for a saved run.
+    pub fn progress(&self) -> (u32, u32) { (self.count, self.survived_ms) }
+
+    pub const fn restore(kind: MissionKind, count: u32, survived_ms: u32) -> Self { Self { kind, count, survived_ms } }
+
+    pub fn done(&self) -> bool { self.count >= self.kind.target() }
+
+    /// Advance by one frame of play.
+    pub fn tick(&mut self, frame_ms: u32) {
+        if self.kind != MissionKind::Survive { return; }
+        self.survived_ms += frame_ms;
+        self.count = self.survived_ms / 1000;
+    }
+
+    pub fn handle(&mut self, ev: Event) {
+        match (self.kind, ev) {
+            (MissionKind::Destroy, Event::Kill { .. }) => self.count += 1,
+            (MissionKind::Destroy, Event::Bomb(n)) => self.count += n,
+            (MissionKind::Gifts, Event::Gift) => self.count += 1,
+            (MissionKind::Coins, Event::Coin { .. }) => self.count += 1,
+            (MissionKind::Score, Event::Score(s)) => self.count = self.count.max(s),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! ST7789 write path on a PIO state machine fed by DMA.
+//!
+//! Replaces the SPI peripheral with a TX-only SPI mode 0 program (SCK on
+//! side-set, MOSI on OUT). Commands are pushed by the CPU; pixel data is
+//! streamed by DMA from a double buffer so the next chunk is filled while
+//! the previous one is still shifting out.
+
+use core::convert::Infallible;
+
+use embassy_futures::block_on;
+use embassy_rp::clocks::clk_sys_freq;
+use embassy_rp::dma::AnyChannel;
+use embassy_rp::gpio::{Level, Output};
+use embassy_rp::pio::{Common, Config, Direction, Instance, PioPin, ShiftDirection, StateMachine};
+use embassy_rp::Peri;
+use mipidsi::interface::Interface;
+
+pub struct PioInterface<'d, PIO: Instance, const SM: usize> {
+    _common: Common<'d, PIO>,
+    sm: StateMachine<'d, PIO, SM>,
+    dma: Peri<'d, AnyChannel>,
+    cs: Output<'d>,
+    dc: Output<'d>,
+    buf: &'d mut [u8],
+}
+
+impl<'d, PIO: Instance, const SM: usize> PioInterface<'d, PIO, SM> {
+    /// `max_hz` caps the bit clock; the PIO divider is derived from clk_sys.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mut common: Common<'d, PIO>,
+        mut sm: StateMachine<'d, PIO, SM>,
+        clk: Peri<'d, impl PioPin>,
+        mosi: Peri<'d, impl PioPin>,
+        cs: Output<'d>,
+        dc: Output<'d>,
+        dma: Peri<'d, AnyChannel>,
+        buf: &'d mut [u8],
+        max_hz: u32,
+    ) -> Self {
+        // SCK idles low while stalled on an empty FIFO, data is sampled on the rising edge
+        let prg = embassy_rp::pio::program::pio_asm!(
+            r#"
+                .side_set 1
+                out pins, 1 side 0
+                nop         side 1
+            "#
+        );
+        let prg = common.load_program(&prg.program);
+        let clk = common.make_pio_pin(clk);
+        let mosi = common.make_pio_pin(mosi);
+
+        let mut cfg = Config::default();
+        cfg.use_program(&prg, &[&clk]);
+        cfg.set_out_pins(&[&mosi]);
+        cfg.shift_out.auto_fill = true;
+        cfg.shift_out.direction = ShiftDirection::Left;
+        cfg.shift_out.threshold = 8;
+        // Two PIO cycles per bit
+        cfg.clock_divider = (clk_sys_freq().div_ceil(2 * max_hz) as u8).into();
+        sm.set_config(&cfg);
+        sm.set_pins(Level::Low, &[&clk, &mosi]);
+        sm.set_pin_dirs(Direction::Out, &[&clk, &mosi]);
+        sm.set_enable(true);
+
+        Self { _common: common, sm, dma, cs, dc, buf }
+    }
+
+    /// Push bytes from the CPU; fine for short command sequences.
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            // Narrow values must be left-justified for a left-shifting OSR
+            while !self.sm.tx().try_push((b as u32) << 24) {}
+        }
+    }
+
+    /// Wait until the FIFO is drained and the last bit has been clocked out.
+    fn wait_idle(&mut self) {
+        // Clear any stale stall, then wait for the state machine to stall again
+        self.sm.tx().stalled();
+        while !self.sm.tx().stalled() {}
+    }
+}
+
+/// Copy pixels into `dst` until it is full or the iterator ends; returns bytes written.
+fn fill<const N: usize>(dst: &mut [u8], pixels: &mut impl Iterator<Item = [u8; N]>) -> usize {
+    let mut len = 0;
+    while len + N <= dst.len() {
+        let Some(p) = pixels.next() else { break };
+        dst[len..len + N].copy_from_slice(&p);
+        len += N;
+    }
+    len
+}
+
+impl<PIO: Instance, const SM: usize> Interface for PioInterface<'_, PIO, SM> {
+    type Word = u8;
+    type Error = Infallible;
+
+    fn send_command(&mut self, command: u8, args: &[u8]) -> Result<(), Self::Error> {
+        self.cs.set_low();
+        self.dc.set_low();
+        self.push_bytes(&[command]);
+        self.wait_idle();
+        self.dc.set_high();
+        self.push_bytes(args);
+        self.wait_idle();
+        self.cs.set_high();
+        Ok(())
+    }
+
+    fn send_pixels<const N: usize>(
+        &mut self,
+        pixels: impl IntoIterator<Item = [u8; N]>,
+    ) -> Result<(), Self::Error> {
+        let mut pixels = pixels.into_iter();
+        let half = self.buf.len() / 2;
+        let (a, b) = self.buf.split_at_mut(half);
+        self.cs.set_low();
+
+        // Ping-pong: one half shifts out over DMA while the other is filled
+        let mut len_a = fill(a, &mut pixels);
+        while len_a > 0 {
+            let t = self.sm.tx().dma_push(self.dma.reborrow(), &a[..len_a], false);
+            let len_b = fill(b, &mut pixels);
+            block_on(t);
+            if len_b == 0 { break; }
+            let t = self.sm.tx().dma_push(self.dma.reborrow(), &b[..len_b], false);
+            len_a = fill(a, &mut pixels);
+            block_on(t);
+        }
+
+        self.wait_idle();
+        self.cs.set_high();
+        Ok(())
+    }
+
+    fn send_repeated_pixel<const N: usize>(
+        &mut self,
+        pixel: [u8; N],
+        count: u32,
+    ) -> Result<(), Self::Error> {
+        let run = self.buf.len() / N;
+        for chunk in self.buf.chunks_exact_mut(N) {
+            chunk.copy_from_slice(&pixel);
+        }
+        self.cs.set_low();
+
+        let mut left = count as usize;
+        while left > 0 {
+            let n = left.min(run);
+            block_on(self.sm.tx().dma_push(self.dma.reborrow(), &self.buf[..n * N], false));
+            left -= n;
+        }
+
+        self.wait_idle();
+        self.cs.set_high();
+        Ok(())
+    }
+}
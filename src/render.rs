@@ -0,0 +1,30 @@
+//! What a scene needs from the display, whatever the erase strategy behind it.
+//!
+//! Scenes bracket their drawing with `begin_frame` and `end_frame` and blank
+//! what they are about to redraw with `erase`, instead of clearing the panel
+//! or filling rectangles themselves. `Guarded` draws straight to the panel,
+//! so there an erase is an immediate fill; a dirty-rect or framebuffer
+//! renderer can collect erases and flush them in `end_frame` without any
+//! scene changing.
+
+use core::convert::Infallible;
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+use crate::guard::Frame;
+
+pub trait Renderer: DrawTarget<Color = Rgb565, Error = Infallible> {
+    /// Call once per frame, before the first draw.
+    fn begin_frame(&mut self);
+
+    /// Blank `region` to the black background.
+    fn erase(&mut self, region: Rectangle);
+
+    /// Blank the whole screen, for scenes that redraw everything on entry.
+    fn erase_all(&mut self) { self.erase(self.bounding_box()); }
+
+    /// Call once per frame, after the last draw.
+    fn end_frame(&mut self) -> Frame;
+}
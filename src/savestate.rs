@@ -0,0 +1,194 @@
+//! A paused run saved to storage so it can be resumed after a power cycle.
+//!
+//! The snapshot is packed field by field into little-endian bytes behind a
+//! format version and kept under its own key in the `kv` store. A save from
+//! another format version reads as no save at all. Only what a run needs to
+//! carry on is kept: missiles, gifts and effects in flight are dropped.
+
+use crate::kv::{Error, Key, Store, VALUE_MAX};
+
+/// Bump whenever the payload layout changes
+const VERSION: u8 = 5;
+/// Covers the obstacle pool with `big-pools`
+pub const MAX_OBSTACLES: usize = 20;
+const _: () = assert!(crate::MAX_OBS <= MAX_OBSTACLES, "saves would drop obstacles from a bigger pool");
+
+#[derive(Clone, Copy)]
+pub struct SavedObstacle {
+    pub x: i16,
+    pub y: i16,
+    pub dx: i16,
+    /// Index into the game's obstacle kinds
+    pub kind: u8,
+    pub frozen: u16,
+    pub hp: u8,
+}
+
+/// Power-up and cooldown timers, in frames.
+#[derive(Clone, Copy, Default)]
+pub struct Timers {
+    pub freeze: u16,
+    pub homing: u16,
+    pub laser: u16,
+    pub shield: u16,
+    pub twin: u16,
+    pub magnet: u16,
+    pub reverse: u16,
+    pub dash_cooldown: u16,
+    pub bomb_regen: u16,
+}
+
+#[derive(Clone)]
+pub struct Snapshot {
+    pub rng: u32,
+    pub score: u32,
+    pub score_offset: u32,
+    pub perfect_points: u32,
+    pub next_shop_score: u32,
+    pub next_mini_score: u32,
+    pub speed_base_score: u32,
+    pub speed_level: u8,
+    /// Endless-mode loop, from 1
+    pub loop_count: u8,
+    pub wallet: u32,
+    pub run_kills: u32,
+    pub lives: u8,
+    pub bombs: u8,
+    pub max_bombs: u8,
+    pub bolts: u8,
+    /// Ship class, an index into `ships::PROFILES`
+    pub profile: u8,
+    pub charge: u16,
+    pub player_x: i16,
+    pub player_y: i16,
+    pub timers: Timers,
+    pub wave_leaks: u16,
+    /// Obstacles past the bottom over the whole run
+    pub run_leaks: u16,
+    pub wave_hit: bool,
+    /// Cheats were used, so the run stays off the records once resumed
+    pub cheated: bool,
+    /// Boss health, 0 when no boss is up
+    pub boss_hp: u8,
+    pub boss_wave: u8,
+    pub mini_hp: u8,
+    /// Kind index, count and survived milliseconds per mission
+    pub missions: [(u8, u32, u32); crate::missions::PER_RUN],
+    pub obstacles: heapless::Vec<SavedObstacle, MAX_OBSTACLES>,
+}
+
+impl Snapshot {
+    fn encode(&self, w: &mut Writer) {
+        for v in [self.rng, self.score, self.score_offset, self.perfect_points, self.next_shop_score,
+            self.next_mini_score, self.speed_base_score, self.wallet, self.run_kills]
+        {
+            w.u32(v);
+        }
+        for v in [self.speed_level, self.loop_count, self.lives, self.bombs, self.max_bombs, self.bolts, self.profile] {
+            w.u8(v);
+        }
+        w.u16(self.charge);
+        w.i16(self.player_x);
+        w.i16(self.player_y);
+        let t = &self.timers;
+        for v in [t.freeze, t.homing, t.laser, t.shield, t.twin, t.magnet, t.reverse, t.dash_cooldown, t.bomb_regen] {
+            w.u16(v);
+        }
+        w.u16(self.wave_leaks);
+        w.u16(self.run_leaks);
+        for v in [self.wave_hit as u8, self.boss_hp, self.boss_wave, self.mini_hp, self.cheated as u8] { w.u8(v); }
+        for &(kind, count, ms) in &self.missions {
+            w.u8(kind);
+            w.u32(count);
+            w.u32(ms);
+        }
+        w.u8(self.obstacles.len() as u8);
+        for o in &self.obstacles {
+            w.i16(o.x);
+            w.i16(o.y);
+            w.i16(o.dx);
+            w.u8(o.kind);
+            w.u16(o.frozen);
+            w.u8(o.hp);
+        }
+    }
+
+    fn decode(r: &mut Reader) -> Option<Self> {
+        let mut s = Self {
+            rng: r.u32()?, score: r.u32()?, score_offset: r.u32()?, perfect_points: r.u32()?,
+            next_shop_score: r.u32()?, next_mini_score: r.u32()?, speed_base_score: r.u32()?, wallet: r.u32()?,
+            run_kills: r.u32()?, speed_level: r.u8()?, loop_count: r.u8()?, lives: r.u8()?, bombs: r.u8()?, max_bombs: r.u8()?,
+            bolts: r.u8()?, profile: r.u8()?, charge: r.u16()?, player_x: r.i16()?, player_y: r.i16()?,
+            timers: Timers {
+                freeze: r.u16()?, homing: r.u16()?, laser: r.u16()?, shield: r.u16()?, twin: r.u16()?,
+                magnet: r.u16()?, reverse: r.u16()?, dash_cooldown: r.u16()?, bomb_regen: r.u16()?,
+            },
+            wave_leaks: r.u16()?, run_leaks: r.u16()?, wave_hit: r.u8()? != 0, boss_hp: r.u8()?, boss_wave: r.u8()?, mini_hp: r.u8()?,
+            cheated: r.u8()? != 0,
+            missions: [(0, 0, 0); crate::missions::PER_RUN],
+            obstacles: heapless::Vec::new(),
+        };
+        for m in s.missions.iter_mut() { *m = (r.u8()?, r.u32()?, r.u32()?); }
+        for _ in 0..r.u8()? {
+            let o = SavedObstacle {
+                x: r.i16()?, y: r.i16()?, dx: r.i16()?, kind: r.u8()?, frozen: r.u16()?, hp: r.u8()?,
+            };
+            s.obstacles.push(o).ok()?;
+        }
+        Some(s)
+    }
+
+    /// The saved run, if there is a valid one.
+    pub fn load(store: &mut Store<'_>) -> Option<Self> {
+        let mut buf = [0u8; VALUE_MAX];
+        let len = store.get(Key::Run, &mut buf)?;
+        if buf[0] != VERSION { return None; }
+        Self::decode(&mut Reader { buf: &buf[1..len], pos: 0 })
+    }
+
+    pub fn save(&self, store: &mut Store<'_>) -> Result<(), Error> {
+        let mut buf = [0u8; VALUE_MAX];
+        buf[0] = VERSION;
+        let mut w = Writer { buf: &mut buf[1..], pos: 0 };
+        self.encode(&mut w);
+        let len = 1 + w.pos;
+        store.set(Key::Run, &buf[..len])
+    }
+}
+
+/// Drop the save, e.g. once it has been resumed.
+pub fn erase(store: &mut Store<'_>) -> Result<(), Error> { store.remove(Key::Run) }
+
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl Writer<'_> {
+    /// The payload fits `VALUE_MAX` by construction
+    fn bytes(&mut self, b: &[u8]) {
+        self.buf[self.pos..self.pos + b.len()].copy_from_slice(b);
+        self.pos += b.len();
+    }
+    fn u8(&mut self, v: u8) { self.bytes(&[v]); }
+    fn u16(&mut self, v: u16) { self.bytes(&v.to_le_bytes()); }
+    fn i16(&mut self, v: i16) { self.bytes(&v.to_le_bytes()); }
+    fn u32(&mut self, v: u32) { self.bytes(&v.to_le_bytes()); }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let b = self.buf.get(self.pos..self.pos + N)?.try_into().ok()?;
+        self.pos += N;
+        Some(b)
+    }
+    fn u8(&mut self) -> Option<u8> { Some(self.bytes::<1>()?[0]) }
+    fn u16(&mut self) -> Option<u16> { Some(u16::from_le_bytes(self.bytes()?)) }
+    fn i16(&mut self) -> Option<i16> { Some(i16::from_le_bytes(self.bytes()?)) }
+    fn u32(&mut self) -> Option<u32> { Some(u32::from_le_bytes(self.bytes()?)) }
+}
@@ -0,0 +1,106 @@
+//! CSV log of finished runs on an SPI SD card, with the `sd-log` feature.
+//!
+//! Each game over appends one line to `RUNS.CSV` in the card's first FAT
+//! volume, writing the header first if the file is new. The card is opened
+//! per append and closed right after, so it can be pulled between games. A
+//! missing or failing card only logs a warning; the next append starts over
+//! with a fresh card init.
+
+use core::fmt::Write;
+
+use embassy_rp::gpio::{Level, Output};
+use embassy_rp::peripherals::{PIN_8, PIN_9, PIN_10, PIN_11, SPI1};
+use embassy_rp::spi::{self, Blocking, Spi};
+use embassy_rp::Peri;
+use embassy_time::{Delay, Instant};
+use embedded_hal_bus::spi::{ExclusiveDevice, NoDelay};
+use embedded_sdmmc::{Error, Mode, SdCard, SdCardError, TimeSource, Timestamp, VolumeIdx, VolumeManager};
+
+const FILE: &str = "RUNS.CSV";
+const HEADER: &str = "uptime_ms,frame,score,accuracy_pct,duration_ms,seed\n";
+/// Cards must be brought up at 400 kHz or less
+const INIT_HZ: u32 = 400_000;
+const FAST_HZ: u32 = 16_000_000;
+
+type Card<'d> = SdCard<ExclusiveDevice<Spi<'d, SPI1, Blocking>, Output<'d>, NoDelay>, Delay>;
+
+/// One finished run.
+pub struct Run {
+    /// Frame counter since boot when the run ended
+    pub frame: u32,
+    pub score: u32,
+    pub shots: u32,
+    /// Shots that destroyed something
+    pub hits: u32,
+    pub duration_ms: u32,
+    /// RNG state when the run started
+    pub seed: u32,
+}
+
+impl Run {
+    /// Hits per shot in percent; missiles and lasers can push it over 100, so it's capped.
+    pub fn accuracy(&self) -> u32 {
+        (self.hits * 100).checked_div(self.shots).map_or(0, |a| a.min(100))
+    }
+}
+
+/// No RTC on board, so files get a fixed date.
+struct Clock;
+
+impl TimeSource for Clock {
+    fn get_timestamp(&self) -> Timestamp {
+        Timestamp { year_since_1970: 56, zero_indexed_month: 0, zero_indexed_day: 0, hours: 0, minutes: 0, seconds: 0 }
+    }
+}
+
+pub struct SdLog<'d> {
+    volumes: VolumeManager<Card<'d>, Clock, 1, 1, 1>,
+    /// The card is up and the bus has been sped up
+    fast: bool,
+}
+
+impl<'d> SdLog<'d> {
+    pub fn new(
+        spi: Peri<'d, SPI1>, clk: Peri<'d, PIN_10>, mosi: Peri<'d, PIN_11>, miso: Peri<'d, PIN_8>, cs: Peri<'d, PIN_9>,
+    ) -> Self {
+        let mut config = spi::Config::default();
+        config.frequency = INIT_HZ;
+        let bus = Spi::new_blocking(spi, clk, mosi, miso, config);
+        let device = ExclusiveDevice::new_no_delay(bus, Output::new(cs, Level::High)).unwrap();
+        Self { volumes: VolumeManager::new_with_limits(SdCard::new(device, Delay), Clock, 0), fast: false }
+    }
+
+    /// Append `run` to the log; takes a few tens of milliseconds with a card in.
+    pub fn append(&mut self, run: &Run) {
+        // Every field at its widest comes to 67 bytes
+        let mut line = heapless::String::<80>::new();
+        if writeln!(line, "{},{},{},{},{},{:08x}", Instant::now().as_millis(), run.frame, run.score,
+            run.accuracy(), run.duration_ms, run.seed).is_err()
+        {
+            log::warn!("SD log line too long; run not logged");
+            return;
+        }
+        if let Err(e) = self.write_line(&line) {
+            log::warn!("SD log failed: {:?}", e);
+            self.fast = false;
+            self.volumes.device(|card| {
+                card.mark_card_uninit();
+                card.spi(|dev| dev.bus_mut().set_frequency(INIT_HZ));
+            });
+        }
+    }
+
+    fn write_line(&mut self, line: &str) -> Result<(), Error<SdCardError>> {
+        if !self.fast {
+            self.volumes.device(|card| card.num_bytes()).map_err(Error::DeviceError)?;
+            self.volumes.device(|card| card.spi(|dev| dev.bus_mut().set_frequency(FAST_HZ)));
+            self.fast = true;
+        }
+        let volume = self.volumes.open_volume(VolumeIdx(0))?;
+        let root = volume.open_root_dir()?;
+        let file = root.open_file_in_dir(FILE, Mode::ReadWriteCreateOrAppend)?;
+        if file.length() == 0 { file.write(HEADER.as_bytes())?; }
+        file.write(line.as_bytes())?;
+        file.close()
+    }
+}
@@ -0,0 +1,183 @@
+//! Player settings and lifetime progress, kept in the `kv` store.
+//!
+//! A missing value loads the defaults, and a shorter payload from an older
+//! build keeps defaults for the newer fields. Builds before the store kept
+//! one record per sector; `migrate` carries those over.
+
+use embassy_rp::flash::ERASE_SIZE;
+
+use crate::feedback;
+use crate::kv::{Error, Key, Store};
+
+const PAYLOAD_MAX: usize = 16;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// Index into the main palette table
+    pub theme: u8,
+    /// Screen rotated 180° with the button sides swapped
+    pub flipped: bool,
+    /// Buzzer sound effects
+    pub sound: bool,
+    /// Player ship sprite
+    pub ship: u8,
+    /// Ease off after repeated short runs
+    pub assist: bool,
+    /// Obstacles that reach the bottom cost a point instead of earning one
+    pub hard: bool,
+    /// Obstacles drop on a fixed column grid and the ship steps between lanes
+    pub lanes: bool,
+    /// Index into the in-game frame rate table; the game runs slower or faster with it
+    pub pace: u8,
+    /// Index into the UI languages
+    pub lang: u8,
+    /// Ship class last picked in the hangar, an index into `ships::PROFILES`
+    pub profile: u8,
+    /// A set number of obstacles past the bottom ends the run, whatever the lives
+    pub leak_limit: bool,
+    /// `feedback` output bits switched on besides the buzzer
+    pub outputs: u8,
+    /// The ship leaves one side edge and comes in at the other; lane mode keeps its walls
+    pub wrap: bool,
+}
+
+impl Settings {
+    pub const fn new() -> Self {
+        Self {
+            theme: 0, flipped: false, sound: true, ship: 0, assist: false, hard: false, lanes: false, pace: 1, lang: 0, profile: 0,
+            leak_limit: false, outputs: feedback::RGB | feedback::VIBE | feedback::LED, wrap: false,
+        }
+    }
+
+    fn encode(&self) -> (usize, [u8; PAYLOAD_MAX]) {
+        let mut p = [0u8; PAYLOAD_MAX];
+        p[0] = self.theme;
+        p[1] = self.flipped as u8;
+        p[2] = self.sound as u8;
+        p[3] = self.ship;
+        p[4] = self.assist as u8;
+        p[5] = self.hard as u8;
+        p[6] = self.pace;
+        p[7] = self.lang;
+        p[8] = self.lanes as u8;
+        p[9] = self.profile;
+        p[10] = self.leak_limit as u8;
+        p[11] = self.outputs;
+        p[12] = self.wrap as u8;
+        (13, p)
+    }
+
+    fn decode(payload: &[u8]) -> Self {
+        let mut s = Self::new();
+        if let Some(&b) = payload.first() { s.theme = b; }
+        if let Some(&b) = payload.get(1) { s.flipped = b != 0; }
+        if let Some(&b) = payload.get(2) { s.sound = b != 0; }
+        if let Some(&b) = payload.get(3) { s.ship = b; }
+        if let Some(&b) = payload.get(4) { s.assist = b != 0; }
+        if let Some(&b) = payload.get(5) { s.hard = b != 0; }
+        if let Some(&b) = payload.get(6) { s.pace = b; }
+        if let Some(&b) = payload.get(7) { s.lang = b; }
+        if let Some(&b) = payload.get(8) { s.lanes = b != 0; }
+        if let Some(&b) = payload.get(9) { s.profile = b; }
+        if let Some(&b) = payload.get(10) { s.leak_limit = b != 0; }
+        if let Some(&b) = payload.get(11) { s.outputs = b; }
+        if let Some(&b) = payload.get(12) { s.wrap = b != 0; }
+        s
+    }
+
+    pub fn load(store: &mut Store<'_>) -> Self {
+        let mut p = [0u8; PAYLOAD_MAX];
+        store.get(Key::Settings, &mut p).map_or(Self::new(), |len| Self::decode(&p[..len]))
+    }
+
+    pub fn save(&self, store: &mut Store<'_>) -> Result<(), Error> {
+        let (len, payload) = self.encode();
+        store.set(Key::Settings, &payload[..len])
+    }
+}
+
+/// Lifetime stats that unlock rewards; only ever grows.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Obstacles shot down or bombed across all real games
+    pub kills: u32,
+    /// Bitmask of earned achievements
+    pub achievements: u16,
+    /// Furthest wave reached, offered as a starting point
+    pub best_wave: u8,
+    /// Final boss beaten at least once; starred on the title screen
+    pub cleared: bool,
+    /// Highest final score of a real game
+    pub best_score: u32,
+}
+
+impl Progress {
+    pub const fn new() -> Self { Self { kills: 0, achievements: 0, best_wave: 1, cleared: false, best_score: 0 } }
+
+    fn decode(payload: &[u8]) -> Self {
+        let mut p = Self::new();
+        let len = payload.len();
+        if len < 4 { return p; }
+        p.kills = u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        if len >= 6 { p.achievements = u16::from_le_bytes([payload[4], payload[5]]); }
+        if len >= 7 { p.best_wave = payload[6].max(1); }
+        if len >= 8 { p.cleared = payload[7] != 0; }
+        if len >= 12 { p.best_score = u32::from_le_bytes([payload[8], payload[9], payload[10], payload[11]]); }
+        p
+    }
+
+    pub fn load(store: &mut Store<'_>) -> Self {
+        let mut p = [0u8; PAYLOAD_MAX];
+        store.get(Key::Progress, &mut p).map_or(Self::new(), |len| Self::decode(&p[..len]))
+    }
+
+    pub fn save(&self, store: &mut Store<'_>) -> Result<(), Error> {
+        let mut payload = [0u8; 12];
+        payload[..4].copy_from_slice(&self.kills.to_le_bytes());
+        payload[4..6].copy_from_slice(&self.achievements.to_le_bytes());
+        payload[6] = self.best_wave;
+        payload[7] = self.cleared as u8;
+        payload[8..].copy_from_slice(&self.best_score.to_le_bytes());
+        store.set(Key::Progress, &payload)
+    }
+}
+
+// --- Pre-store layout: one record per sector (magic, length, payload, byte-sum checksum) ---
+const LEGACY: [(u32, [u8; 4], Key); 2] = [(0, *b"DSET", Key::Settings), (ERASE_SIZE as u32, *b"DPRG", Key::Progress)];
+const LEGACY_HEADER_LEN: usize = 4 + 1;
+const LEGACY_RECORD_LEN: usize = LEGACY_HEADER_LEN + PAYLOAD_MAX + 1;
+
+/// On the first boot with a blank store, copy over the settings and progress
+/// an older build left behind. They sit where the store's first bank goes,
+/// so both are read before anything is written. Older builds only had
+/// flash, so a blank FRAM starts from defaults.
+pub fn migrate(store: &mut Store<'_>) {
+    if cfg!(feature = "fram") || !store.is_blank() { return; }
+    let mut found = [None; LEGACY.len()];
+    for (slot, &(offset, magic, _)) in found.iter_mut().zip(&LEGACY) {
+        *slot = read_legacy(store, offset, magic);
+    }
+    for (slot, &(_, _, key)) in found.iter().zip(&LEGACY) {
+        if let Some((len, payload)) = slot
+            && store.set(key, &payload[..*len]).is_err()
+        {
+            log::warn!("Settings migration failed");
+        }
+    }
+}
+
+fn read_legacy(store: &mut Store<'_>, offset: u32, magic: [u8; 4]) -> Option<(usize, [u8; PAYLOAD_MAX])> {
+    let mut rec = [0u8; LEGACY_RECORD_LEN];
+    store.read_raw(offset, &mut rec).ok()?;
+    let len = rec[magic.len()] as usize;
+    if rec[..magic.len()] != magic || len > PAYLOAD_MAX { return None; }
+    let body = &rec[..LEGACY_HEADER_LEN + len];
+    if checksum(body) != rec[LEGACY_HEADER_LEN + len] { return None; }
+    let mut payload = [0u8; PAYLOAD_MAX];
+    payload[..len].copy_from_slice(&body[LEGACY_HEADER_LEN..]);
+    Some((len, payload))
+}
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |a, &b| a.wrapping_add(b)) ^ 0xA5
+}
@@ -0,0 +1,42 @@
+//! Ship classes picked on the hangar screen before a game.
+//!
+//! A profile sets the hull the game collides with and draws, how fast it
+//! strafes and the bombs it sets out with. The sprite style in the options
+//! is separate and fits any hull.
+
+use crate::strings::Msg;
+use crate::{LANE_W, OBS_W};
+
+pub struct ShipProfile {
+    pub name: Msg,
+    /// Hull width in px; the height is the same for every class
+    pub width: i32,
+    /// Strafe speed, px/s
+    pub speed: i32,
+    /// Bombs on top of the standard load; the cap rises with them
+    pub extra_bombs: u8,
+}
+
+impl ShipProfile {
+    /// Clears the obstacles centered in the neighbouring lanes, so lane mode can fly it.
+    pub const fn fits_lanes(&self) -> bool { self.width / 2 < LANE_W - OBS_W / 2 }
+}
+
+pub const PROFILES: [ShipProfile; 3] = [
+    ShipProfile { name: Msg::ClassBalanced, width: 24, speed: 100, extra_bombs: 0 },
+    ShipProfile { name: Msg::ClassFast, width: 16, speed: 140, extra_bombs: 0 },
+    ShipProfile { name: Msg::ClassHeavy, width: 32, speed: 75, extra_bombs: 1 },
+];
+
+/// Lane mode falls back to the first class
+const _: () = assert!(PROFILES[0].fits_lanes(), "the standard ship must fit a lane");
+
+/// Widest hull, so spawns leave every class a way through
+pub const MAX_WIDTH: i32 = {
+    let (mut w, mut i) = (0, 0);
+    while i < PROFILES.len() {
+        if PROFILES[i].width > w { w = PROFILES[i].width; }
+        i += 1;
+    }
+    w
+};
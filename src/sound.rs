@@ -0,0 +1,123 @@
+//! Piezo buzzer on PWM and the table of game sound effects.
+//!
+//! Effects are short tone sequences advanced once per frame, so a tone
+//! lasts at least one frame. Events missing from `SFX_TABLE` play
+//! `DEFAULT_BLIP`, so new events are never silent.
+//!
+//! The piezo has one voice. When several events land together, an effect
+//! only cuts in on one of equal or higher priority, so a hit or game over
+//! is never drowned out by the kill or pickup that happened in the same frame.
+
+use embassy_rp::clocks::clk_sys_freq;
+use embassy_rp::pwm::{Config as PwmConfig, Pwm};
+
+/// Frequency in Hz (0 = rest) and length in milliseconds
+pub type Tone = (u16, u16);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Sfx {
+    Start,
+    Fire,
+    Destroy,
+    PlayerHit,
+    Pickup,
+    Bomb,
+    SpeedUp,
+    GameOver,
+    MenuMove,
+    Heartbeat,
+    Warning,
+    Coin,
+    Achievement,
+    Fanfare,
+    /// Bass pulse under the game; its tempo comes from the zone
+    Beat,
+}
+
+const DEFAULT_BLIP: (u8, &[Tone]) = (0, &[(1500, 15)]);
+
+/// Every effect in one place with its priority (higher cuts in); tweak freely.
+const SFX_TABLE: &[(Sfx, u8, &[Tone])] = &[
+    (Sfx::Start, 2, &[(523, 60), (659, 60), (784, 90)]),
+    (Sfx::Fire, 0, &[(1800, 15)]),
+    (Sfx::Destroy, 1, &[(400, 20), (250, 30)]),
+    (Sfx::PlayerHit, 3, &[(150, 80), (0, 20), (110, 120)]),
+    (Sfx::Pickup, 2, &[(988, 40), (1319, 60)]),
+    (Sfx::Bomb, 2, &[(120, 60), (90, 80), (70, 120)]),
+    (Sfx::SpeedUp, 2, &[(660, 50), (880, 50), (1100, 70)]),
+    (Sfx::GameOver, 4, &[(392, 150), (330, 150), (262, 300)]),
+    (Sfx::Heartbeat, 1, &[(70, 40), (0, 80), (60, 60)]),
+    (Sfx::Warning, 1, &[(2400, 20), (0, 20), (2400, 20)]),
+    (Sfx::Coin, 1, &[(1568, 30), (2093, 50)]),
+    (Sfx::Achievement, 2, &[(784, 60), (1047, 60), (1319, 60), (1568, 120)]),
+    (Sfx::Fanfare, 3, &[(523, 80), (659, 80), (784, 80), (1047, 120), (784, 60), (1047, 240)]),
+    (Sfx::Beat, 0, &[(98, 25)]),
+];
+
+fn lookup(sfx: Sfx) -> (u8, &'static [Tone]) {
+    SFX_TABLE.iter().find(|(e, _, _)| *e == sfx).map_or(DEFAULT_BLIP, |&(_, p, t)| (p, t))
+}
+
+// Integer divider; the 16-bit counter then bottoms out near 15 Hz at 125 MHz and 30 Hz at
+// 250 MHz, half the lowest tone here (60 Hz), and 20 kHz still gets ~50-100 steps
+const PWM_DIVIDER: u32 = 128;
+
+pub struct Buzzer<'d> {
+    pwm: Pwm<'d>,
+    cfg: PwmConfig,
+    seq: &'static [Tone],
+    prio: u8,
+    idx: usize,
+    left_ms: i32,
+    /// Cleared to mute; `play` is ignored and any tone stops on the next tick
+    pub enabled: bool,
+}
+
+impl<'d> Buzzer<'d> {
+    pub fn config() -> PwmConfig {
+        let mut cfg = PwmConfig::default();
+        cfg.divider = (PWM_DIVIDER as u8).into();
+        cfg.compare_a = 0;
+        cfg
+    }
+
+    pub fn new(pwm: Pwm<'d>) -> Self {
+        Self { pwm, cfg: Self::config(), seq: &[], prio: 0, idx: 0, left_ms: 0, enabled: true }
+    }
+
+    /// Start an effect unless one of higher priority is still playing.
+    pub fn play(&mut self, sfx: Sfx) {
+        if !self.enabled { return; }
+        let (prio, seq) = lookup(sfx);
+        if !self.seq.is_empty() && prio < self.prio { return; }
+        self.seq = seq;
+        self.prio = prio;
+        self.idx = 0;
+        self.left_ms = 0;
+    }
+
+    fn set_tone(&mut self, hz: u16) {
+        if hz == 0 {
+            self.cfg.compare_a = 0;
+        } else {
+            let top = (clk_sys_freq() / PWM_DIVIDER / hz as u32).clamp(2, u16::MAX as u32);
+            self.cfg.top = (top - 1) as u16;
+            self.cfg.compare_a = (top / 2) as u16;
+        }
+        self.pwm.set_config(&self.cfg);
+    }
+
+    /// Advance by one frame of `frame_ms`; call every frame.
+    pub fn tick(&mut self, frame_ms: u32) {
+        if !self.enabled && !self.seq.is_empty() { self.seq = &[]; self.set_tone(0); return; }
+        if self.seq.is_empty() { return; }
+        if self.left_ms <= 0 {
+            if self.idx == self.seq.len() { self.seq = &[]; self.set_tone(0); return; }
+            let (hz, ms) = self.seq[self.idx];
+            self.idx += 1;
+            self.left_ms = ms as i32;
+            self.set_tone(hz);
+        }
+        self.left_ms -= frame_ms as i32;
+    }
+}
@@ -0,0 +1,347 @@
+//! UI string table: every piece of on-screen text in each language.
+//!
+//! Screens ask for a `Msg` and render `msg.text(lang)`. Korean text is drawn
+//! with the bitmap syllables in `hangul`, which only bundles the syllables
+//! used here; run `hangul_font.py` after changing a Korean string.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang {
+    En,
+    Ko,
+}
+
+impl Lang {
+    pub const ALL: [Self; 2] = [Self::En, Self::Ko];
+
+    /// Settings index back to a language; out of range falls back to English.
+    pub fn from_index(i: u8) -> Self { Self::ALL.get(i as usize).copied().unwrap_or(Self::En) }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Msg {
+    Blank,
+    // Crash recovery
+    RecoveredFrom,
+    Crash,
+    WatchdogTimeout,
+    Panic,
+    DisplayFault,
+    LastScore,
+    // Title
+    MoveHelp,
+    FireHelp,
+    Combos,
+    PressAny,
+    Hot,
+    HoldBootsel,
+    BootselMode,
+    HoldErase,
+    // Factory reset
+    EraseAll,
+    EraseWhat,
+    EraseOrCancel,
+    Erased,
+    EraseFailed,
+    // Options
+    Options,
+    OptionsHint,
+    Theme,
+    Screen,
+    Sound,
+    Light,
+    Rumble,
+    StatusLed,
+    Ship,
+    Assist,
+    HardMode,
+    LaneMode,
+    EdgeWrap,
+    LeakLimit,
+    GameSpeed,
+    Language,
+    Back,
+    Flipped,
+    Normal,
+    On,
+    Off,
+    Slow,
+    Fast,
+    LangName,
+    ThemeClassic,
+    ThemeCrt,
+    ThemeContrast,
+    ThemePastel,
+    ThemeSunset,
+    ShipClassic,
+    ShipArrow,
+    // Hangar
+    ChooseShip,
+    HangarHint,
+    ClassBalanced,
+    ClassFast,
+    ClassHeavy,
+    StatSpeed,
+    StatWidth,
+    StatBombs,
+    // Unlocks
+    Unlocks,
+    LifetimeKills,
+    UnlockArrow,
+    UnlockSunset,
+    UnlockBombBay,
+    Achievements,
+    AnyBack,
+    // Briefing
+    Missions,
+    P1Missions,
+    P2Missions,
+    BonusPre,
+    BonusPost,
+    DestroyPre,
+    DestroyPost,
+    GiftsPre,
+    GiftsPost,
+    SurvivePre,
+    SurvivePost,
+    CoinsPre,
+    CoinsPost,
+    ScorePre,
+    ScorePost,
+    StartPrompt,
+    StartWave,
+    // Shop
+    Shop,
+    ShopHint,
+    Coins,
+    BuyBomb,
+    BuyLife,
+    BuyTwin,
+    BuyBolts,
+    NextWave,
+    // Game over and victory
+    Victory,
+    GameOver,
+    ScoreLabel,
+    MissionsPlus,
+    MissionsLabel,
+    Best,
+    Unlocked,
+    Total,
+    CreditsTitle,
+    CreditsDesign,
+    CreditsCrew,
+    CreditsBuilt,
+    CreditsPico,
+    CreditsThanks,
+    // Two players
+    Player2,
+    GetReady,
+    P1Scored,
+    Results,
+    P1Wins,
+    P2Wins,
+    Draw,
+    // Pause and resume
+    Paused,
+    RunSaved,
+    SaveFailed,
+    ResumeHint,
+    SavedRun,
+    WaveLabel,
+    ResumeOrDiscard,
+    // In play
+    PracticeHint,
+    Skip,
+    TutorialMove,
+    TutorialFire,
+    TutorialGift,
+    TutorialBomb,
+    TutorialDone,
+    Achievement,
+    Asteroids,
+    Squeeze,
+    Reversed,
+    Perfect,
+    SpeedUp,
+    Loop,
+    // Achievement names
+    FirstKill,
+    Combo10,
+    Sniper,
+    BigBomb,
+    Pacifist,
+    Untouchable,
+    LastStand,
+    GiftHunter,
+    CoinPurse,
+    Shopper,
+    Wave3,
+    Score50,
+    Score100,
+    Score200,
+    Veteran,
+    BossSlayer,
+}
+
+impl Msg {
+    pub fn text(self, lang: Lang) -> &'static str {
+        let [en, ko] = match self {
+            Self::Blank => ["", ""],
+            Self::RecoveredFrom => ["RECOVERED FROM", "오류에서"],
+            Self::Crash => ["CRASH", "복구됨"],
+            Self::WatchdogTimeout => ["Watchdog timeout", "워치독 시간 초과"],
+            Self::Panic => ["Panic", "패닉"],
+            Self::DisplayFault => ["Display error", "화면 오류"],
+            Self::LastScore => ["Last score: ", "마지막 점수: "],
+            Self::MoveHelp => ["B:Left Y:Right", "B:왼쪽 Y:오른쪽"],
+            Self::FireHelp => ["A:Fire X:Fire", "A:발사 X:발사"],
+            Self::Combos => [
+                "B+Y OPTIONS  A+B UNLOCKS  X+Y TUTORIAL  A+Y PRACTICE  B+X 2P",
+                "B+Y 설정 A+B 해금 X+Y 안내 A+Y 연습 B+X 2인",
+            ],
+            Self::PressAny => ["Press any button", "버튼을 누르세요"],
+            Self::Hot => ["HOT ", "과열 "],
+            Self::HoldBootsel => ["Hold for BOOTSEL", "길게 눌러 BOOTSEL"],
+            Self::BootselMode => ["BOOTSEL MODE", "BOOTSEL 모드"],
+            Self::HoldErase => ["Hold to erase data", "계속 눌러 삭제"],
+            Self::EraseAll => ["ERASE ALL DATA?", "데이터 삭제?"],
+            Self::EraseWhat => ["Scores, settings, unlocks, saves", "점수, 설정, 해금, 저장된 게임"],
+            Self::EraseOrCancel => ["A/X: erase  B/Y: cancel", "A/X: 삭제  B/Y: 취소"],
+            Self::Erased => ["Erased. Rebooting...", "삭제 완료"],
+            Self::EraseFailed => ["Erase failed", "삭제 실패"],
+            Self::Options => ["OPTIONS", "설정"],
+            Self::OptionsHint => ["B/Y: select  A/X: change", "B/Y: 선택  A/X: 변경"],
+            Self::Theme => ["Theme", "테마"],
+            Self::Screen => ["Screen", "화면"],
+            Self::Sound => ["Sound", "소리"],
+            Self::Light => ["RGB LED", "RGB LED"],
+            Self::Rumble => ["Rumble", "진동"],
+            Self::StatusLed => ["Status LED", "상태 LED"],
+            Self::Ship => ["Ship", "기체"],
+            Self::Assist => ["Assist", "어시스트"],
+            Self::HardMode => ["Hard mode", "하드 모드"],
+            Self::LaneMode => ["Lane mode", "레인 모드"],
+            Self::EdgeWrap => ["Edge wrap", "벽 통과"],
+            Self::LeakLimit => ["Leak limit", "통과 제한"],
+            Self::GameSpeed => ["Game speed", "게임 속도"],
+            Self::Language => ["Language", "언어"],
+            Self::Back => ["Back", "뒤로"],
+            Self::Flipped => ["Flipped", "뒤집힘"],
+            Self::Normal => ["Normal", "보통"],
+            Self::On => ["On", "켜짐"],
+            Self::Off => ["Off", "꺼짐"],
+            Self::Slow => ["Slow", "느림"],
+            Self::Fast => ["Fast", "빠름"],
+            Self::LangName => ["English", "한국어"],
+            Self::ThemeClassic => ["Classic", "클래식"],
+            Self::ThemeCrt => ["CRT green", "CRT 녹색"],
+            Self::ThemeContrast => ["Contrast", "고대비"],
+            Self::ThemePastel => ["Pastel", "파스텔"],
+            Self::ThemeSunset => ["Sunset", "노을"],
+            Self::ShipClassic => ["Classic", "클래식"],
+            Self::ShipArrow => ["Arrow", "화살"],
+            Self::ChooseShip => ["CHOOSE SHIP", "기체 선택"],
+            Self::HangarHint => ["B/Y: select  A/X: start", "B/Y: 선택  A/X: 시작"],
+            Self::ClassBalanced => ["Balanced", "균형형"],
+            Self::ClassFast => ["Fast", "고속형"],
+            Self::ClassHeavy => ["Heavy", "중장갑"],
+            Self::StatSpeed => ["Speed", "속도"],
+            Self::StatWidth => ["Width", "폭"],
+            Self::StatBombs => ["Bombs", "폭탄"],
+            Self::Unlocks => ["UNLOCKS", "해금"],
+            Self::LifetimeKills => ["Lifetime kills: ", "누적 격추: "],
+            Self::UnlockArrow => ["Arrow ship", "화살 기체"],
+            Self::UnlockSunset => ["Sunset theme", "노을 테마"],
+            Self::UnlockBombBay => ["Bomb bay +1", "폭탄 칸 +1"],
+            Self::Achievements => ["Achievements: ", "업적: "],
+            Self::AnyBack => ["Any button: back", "아무 버튼: 뒤로"],
+            Self::Missions => ["MISSIONS", "임무"],
+            Self::P1Missions => ["P1 MISSIONS", "P1 임무"],
+            Self::P2Missions => ["P2 MISSIONS", "P2 임무"],
+            Self::BonusPre => ["+", "완료한 임무마다 +"],
+            Self::BonusPost => [" points each at game over", "점"],
+            Self::DestroyPre => ["Destroy ", "장애물 "],
+            Self::DestroyPost => [" obstacles", "개 파괴"],
+            Self::GiftsPre => ["Collect ", "선물 "],
+            Self::GiftsPost => [" gifts", "개 획득"],
+            Self::SurvivePre => ["Survive ", ""],
+            Self::SurvivePost => [" seconds", "초 생존"],
+            Self::CoinsPre => ["Pick up ", "동전 "],
+            Self::CoinsPost => [" coins", "개 줍기"],
+            Self::ScorePre => ["Reach ", ""],
+            Self::ScorePost => [" points", "점 달성"],
+            Self::StartPrompt => ["A/X: Start", "A/X: 시작"],
+            Self::StartWave => ["B/Y: start at wave ", "B/Y: 시작 웨이브 "],
+            Self::Shop => ["SHOP", "상점"],
+            Self::ShopHint => ["B/Y: select  X: buy", "B/Y: 선택  X: 구매"],
+            Self::Coins => ["Coins: ", "동전: "],
+            Self::BuyBomb => ["Bomb +1", "폭탄 +1"],
+            Self::BuyLife => ["Life +1", "생명 +1"],
+            Self::BuyTwin => ["Twin shot", "쌍발 사격"],
+            Self::BuyBolts => ["Freeze bolt x3", "냉동탄 x3"],
+            Self::NextWave => ["Next wave", "다음 웨이브"],
+            Self::Victory => ["VICTORY!", "승리!"],
+            Self::GameOver => ["GAME OVER", "게임 오버"],
+            Self::ScoreLabel => ["Score ", "점수 "],
+            Self::MissionsPlus => ["  Missions +", "  임무 +"],
+            Self::MissionsLabel => ["Missions ", "임무 "],
+            Self::Best => ["Best: ", "최고: "],
+            Self::Unlocked => ["Unlocked: ", "해금: "],
+            Self::Total => ["Total ", "합계 "],
+            Self::CreditsTitle => ["DODGE & SHOOT", "DODGE & SHOOT"],
+            Self::CreditsDesign => ["Game design, code and sound", "게임 디자인, 코드, 사운드"],
+            Self::CreditsCrew => ["by the Dodge & Shoot crew", "Dodge & Shoot 팀"],
+            Self::CreditsBuilt => ["Built with Rust and Embassy", "Rust와 Embassy로 제작"],
+            Self::CreditsPico => ["on a Raspberry Pi Pico", "라즈베리 파이 피코 위에서"],
+            Self::CreditsThanks => ["Thanks for playing!", "플레이해 주셔서 감사합니다!"],
+            Self::Player2 => ["PLAYER 2", "플레이어 2"],
+            Self::GetReady => ["GET READY", "준비하세요"],
+            Self::P1Scored => ["Player 1 scored ", "플레이어 1 점수 "],
+            Self::Results => ["RESULTS", "결과"],
+            Self::P1Wins => ["PLAYER 1 WINS!", "플레이어 1 승리!"],
+            Self::P2Wins => ["PLAYER 2 WINS!", "플레이어 2 승리!"],
+            Self::Draw => ["DRAW!", "무승부!"],
+            Self::Paused => ["PAUSED", "일시정지"],
+            Self::RunSaved => ["Run saved: safe to power off", "저장 완료: 전원을 꺼도 됩니다"],
+            Self::SaveFailed => ["Save failed", "저장 실패"],
+            Self::ResumeHint => ["A/X: resume", "A/X: 계속하기"],
+            Self::SavedRun => ["SAVED RUN", "저장된 게임"],
+            Self::WaveLabel => ["Wave ", "웨이브 "],
+            Self::ResumeOrDiscard => ["A/X: resume  B/Y: discard", "A/X: 계속하기  B/Y: 버리기"],
+            Self::PracticeHint => ["X: obstacle  tap Y: gift  B+Y: exit", "X: 장애물  Y: 선물  B+Y: 나가기"],
+            Self::Skip => ["B+Y: skip", "B+Y: 건너뛰기"],
+            Self::TutorialMove => ["B/Y: move left and right", "B/Y: 좌우로 이동"],
+            Self::TutorialFire => ["A/X: fire at the block", "A/X: 블록을 쏘세요"],
+            Self::TutorialGift => ["Shoot the gift for a power-up", "선물을 쏘면 파워업"],
+            Self::TutorialBomb => ["A+X together: drop a bomb", "A+X 동시에: 폭탄"],
+            Self::TutorialDone => ["You're ready!", "준비 완료!"],
+            Self::Achievement => ["ACHIEVEMENT: ", "업적: "],
+            Self::Asteroids => ["ASTEROIDS!", "소행성!"],
+            Self::Squeeze => ["SQUEEZE!", "벽이 좁혀온다!"],
+            Self::Reversed => ["REVERSED!", "반전!"],
+            Self::Perfect => ["PERFECT +", "완벽 +"],
+            Self::SpeedUp => ["SPEED UP!", "가속!"],
+            Self::Loop => ["LOOP ", "루프 "],
+            Self::FirstKill => ["First blood", "첫 격추"],
+            Self::Combo10 => ["10 kill combo", "10 연속 격추"],
+            Self::Sniper => ["Sniper", "저격수"],
+            Self::BigBomb => ["Big bang", "대폭발"],
+            Self::Pacifist => ["Pacifist", "평화주의자"],
+            Self::Untouchable => ["Untouchable", "무적"],
+            Self::LastStand => ["Last stand", "최후의 저항"],
+            Self::GiftHunter => ["Gift hunter", "선물 사냥꾼"],
+            Self::CoinPurse => ["Coin purse", "동전 지갑"],
+            Self::Shopper => ["Shopper", "단골 손님"],
+            Self::Wave3 => ["Wave 3", "웨이브 3"],
+            Self::Score50 => ["Score 50", "점수 50"],
+            Self::Score100 => ["Score 100", "점수 100"],
+            Self::Score200 => ["Score 200", "점수 200"],
+            Self::Veteran => ["Veteran", "베테랑"],
+            Self::BossSlayer => ["Boss slayer", "보스 사냥꾼"],
+        };
+        match lang {
+            Lang::En => en,
+            Lang::Ko => ko,
+        }
+    }
+}
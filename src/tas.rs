@@ -0,0 +1,89 @@
+//! Frame-indexed input scripts, uploaded over the serial console and played
+//! back in place of the buttons.
+//!
+//! A script is a seed and a list of steps, each naming the buttons held from
+//! its frame on. `tas play` starts a run from the seed with the frame counter
+//! at zero and feeds the loop one step of `Script::next` per frame, so the
+//! same script on the same settings plays out the same run every time.
+
+pub const MAX_STEPS: usize = 512;
+
+/// Button bits, in `read_buttons` order
+pub const A: u8 = 1;
+pub const B: u8 = 2;
+pub const X: u8 = 4;
+pub const Y: u8 = 8;
+
+#[derive(PartialEq)]
+pub enum Cmd {
+    /// Start a new script
+    Seed(u32),
+    /// Hold these buttons from this frame on
+    Step(u32, u8),
+    Play,
+    Stop,
+    Status,
+}
+
+pub struct Script {
+    pub seed: u32,
+    steps: heapless::Vec<(u32, u8), MAX_STEPS>,
+    /// Next step to take effect
+    cursor: usize,
+    /// Frame of playback about to be fed
+    frame: u32,
+    held: u8,
+}
+
+impl Script {
+    pub const fn new() -> Self { Self { seed: 0, steps: heapless::Vec::new(), cursor: 0, frame: 0, held: 0 } }
+
+    pub fn clear(&mut self, seed: u32) {
+        self.seed = seed;
+        self.steps.clear();
+        self.rewind();
+    }
+
+    /// Steps go in frame order.
+    pub fn push(&mut self, frame: u32, buttons: u8) -> Result<(), &'static str> {
+        if self.steps.last().is_some_and(|&(f, _)| f >= frame) { return Err("out of order"); }
+        self.steps.push((frame, buttons)).map_err(|_| "script full")
+    }
+
+    pub fn len(&self) -> usize { self.steps.len() }
+
+    pub fn is_empty(&self) -> bool { self.steps.is_empty() }
+
+    /// Frame of the last step; playback ends after it.
+    pub fn end(&self) -> Option<u32> { self.steps.last().map(|&(f, _)| f) }
+
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+        self.frame = 0;
+        self.held = 0;
+    }
+
+    /// Buttons for the next frame of playback, or `None` once the script is over.
+    pub fn next(&mut self) -> Option<(bool, bool, bool, bool)> {
+        if self.frame > self.end()? { return None; }
+        while let Some(&(f, b)) = self.steps.get(self.cursor) && f <= self.frame {
+            self.held = b;
+            self.cursor += 1;
+        }
+        self.frame += 1;
+        let h = self.held;
+        Some((h & A != 0, h & B != 0, h & X != 0, h & Y != 0))
+    }
+}
+
+/// Button letters (`a`, `b`, `x`, `y`) or `-` for none.
+pub fn parse_buttons(s: &str) -> Option<u8> {
+    if s == "-" { return Some(0); }
+    s.chars().try_fold(0, |bits, c| match c.to_ascii_lowercase() {
+        'a' => Some(bits | A),
+        'b' => Some(bits | B),
+        'x' => Some(bits | X),
+        'y' => Some(bits | Y),
+        _ => None,
+    })
+}
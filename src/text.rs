@@ -0,0 +1,112 @@
+//! Text drawing for strings that mix ASCII and Hangul.
+//!
+//! ASCII runs go through the mono fonts (or `tiny_font` on the HUD) and
+//! Hangul syllables are drawn from the `hangul` bitmaps. The big font gets
+//! syllables at 2x so both scripts share a line height. `left_edge` lays a
+//! line out against an anchor so centred and right-aligned text keeps its
+//! place whatever the language.
+
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::{Alignment, Baseline, Text};
+
+use crate::{hangul, tiny_font};
+
+/// Hangul syllables double up next to the big font so they match its height.
+fn scale(style: &MonoTextStyle<'_, Rgb565>) -> i32 {
+    if style.font.character_size.height >= 20 { 2 } else { 1 }
+}
+
+/// Width of `text` in `style`, counting Hangul at its bitmap advance.
+pub fn width(text: &str, style: &MonoTextStyle<'_, Rgb565>) -> i32 {
+    let cell = (style.font.character_size.width + style.font.character_spacing) as i32;
+    let syllable = (hangul::SIZE as i32 + 1) * scale(style);
+    text.chars().map(|c| if hangul::glyph(c).is_some() { syllable } else { cell }).sum()
+}
+
+/// Left edge of a line `w` pixels wide anchored at `x` by `align`.
+pub fn place(w: i32, x: i32, align: Alignment) -> i32 {
+    match align {
+        Alignment::Left => x,
+        Alignment::Center => x - w / 2,
+        Alignment::Right => x - w,
+    }
+}
+
+/// Left edge of `text` in `style` anchored at `x` by `align`.
+pub fn left_edge(text: &str, style: &MonoTextStyle<'_, Rgb565>, x: i32, align: Alignment) -> i32 {
+    place(width(text, style), x, align)
+}
+
+/// `Text` with a top baseline that also draws Hangul from the bitmap table,
+/// centred on the font's line height and transparent like the mono font.
+pub fn draw<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, text: &str, pos: Point, style: MonoTextStyle<'_, Rgb565>,
+) -> Result<(), D::Error> {
+    let scale = scale(&style);
+    let gy = pos.y + (style.font.character_size.height as i32 - hangul::SIZE as i32 * scale) / 2;
+    let mut x = pos.x;
+    let mut run = 0;
+    for (i, c) in text.char_indices() {
+        let Some(rows) = hangul::glyph(c) else { continue };
+        if run < i { x = Text::with_baseline(&text[run..i], Point::new(x, pos.y), style, Baseline::Top).draw(target)?.x; }
+        if let Some(fg) = style.text_color {
+            draw_syllable(target, rows, x, gy, scale, fg)?;
+        }
+        x += (hangul::SIZE as i32 + 1) * scale;
+        run = i + c.len_utf8();
+    }
+    if run < text.len() { Text::with_baseline(&text[run..], Point::new(x, pos.y), style, Baseline::Top).draw(target)?; }
+    Ok(())
+}
+
+/// `draw` with `pos.x` as the `align` anchor instead of the left edge.
+pub fn draw_aligned<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, text: &str, pos: Point, style: MonoTextStyle<'_, Rgb565>, align: Alignment,
+) -> Result<(), D::Error> {
+    draw(target, text, Point::new(left_edge(text, &style, pos.x, align), pos.y), style)
+}
+
+fn draw_syllable<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, rows: &[u16; hangul::SIZE], x: i32, y: i32, scale: i32, fg: Rgb565,
+) -> Result<(), D::Error> {
+    let pixels = (0..hangul::SIZE as i32 * scale).flat_map(move |py| {
+        let row = rows[(py / scale) as usize];
+        (0..hangul::SIZE as i32 * scale)
+            .filter(move |px| row & (0x8000 >> (px / scale)) != 0)
+            .map(move |px| Pixel(Point::new(x + px, y + py), fg))
+    });
+    target.draw_iter(pixels)
+}
+
+/// `tiny_font::draw` for strings that may hold Hangul: syllables are drawn at
+/// 1x over the same background, reaching a few pixels above and below the line.
+pub fn draw_tiny<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, text: &str, x: i32, y: i32, fg: Rgb565, bg: Rgb565,
+) -> Result<(), D::Error> {
+    let gy = y + (tiny_font::CELL_H as i32 - hangul::SIZE as i32) / 2;
+    let mut x = x;
+    let mut run = 0;
+    for (i, c) in text.char_indices() {
+        let Some(rows) = hangul::glyph(c) else { continue };
+        if run < i {
+            tiny_font::draw(target, &text[run..i], x, y, 1, fg, bg)?;
+            x += tiny_font::width(i - run, 1) as i32;
+        }
+        let area = Rectangle::new(Point::new(x, gy), Size::new(hangul::SIZE as u32 + 1, hangul::SIZE as u32));
+        let pixels = rows.iter()
+            .flat_map(|row| (0..=hangul::SIZE).map(move |b| if row & (0x8000 >> b) != 0 { fg } else { bg }));
+        target.fill_contiguous(&area, pixels)?;
+        x += hangul::SIZE as i32 + 1;
+        run = i + c.len_utf8();
+    }
+    if run < text.len() { tiny_font::draw(target, &text[run..], x, y, 1, fg, bg)?; }
+    Ok(())
+}
+
+/// Width of `text` as drawn by `draw_tiny`.
+pub fn tiny_width(text: &str) -> i32 {
+    text.chars().map(|c| if hangul::glyph(c).is_some() { hangul::SIZE as i32 + 1 } else { tiny_font::CELL_W as i32 }).sum()
+}
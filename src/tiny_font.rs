@@ -0,0 +1,87 @@
+//! 4x6 HUD font: 3x5 glyphs plus one column and one row of spacing.
+//!
+//! Each glyph packs five 3-bit rows into a u16, top row in the high bits.
+//! Text is drawn over a solid background in a single window write, so a
+//! redraw also erases whatever was there before.
+
+use embedded_graphics::pixelcolor::Rgb565;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+pub const CELL_W: u32 = 4;
+pub const CELL_H: u32 = 6;
+
+/// Lowercase maps to uppercase; anything unknown renders blank.
+const fn glyph(c: u8) -> u16 {
+    match c.to_ascii_uppercase() {
+        b'0' => 0b111_101_101_101_111,
+        b'1' => 0b010_110_010_010_111,
+        b'2' => 0b111_001_111_100_111,
+        b'3' => 0b111_001_111_001_111,
+        b'4' => 0b101_101_111_001_001,
+        b'5' => 0b111_100_111_001_111,
+        b'6' => 0b111_100_111_101_111,
+        b'7' => 0b111_001_001_001_001,
+        b'8' => 0b111_101_111_101_111,
+        b'9' => 0b111_101_111_001_111,
+        b'A' => 0b010_101_111_101_101,
+        b'B' => 0b110_101_110_101_110,
+        b'C' => 0b011_100_100_100_011,
+        b'D' => 0b110_101_101_101_110,
+        b'E' => 0b111_100_110_100_111,
+        b'F' => 0b111_100_110_100_100,
+        b'G' => 0b011_100_101_101_011,
+        b'H' => 0b101_101_111_101_101,
+        b'I' => 0b111_010_010_010_111,
+        b'J' => 0b001_001_001_101_010,
+        b'K' => 0b101_101_110_101_101,
+        b'L' => 0b100_100_100_100_111,
+        b'M' => 0b101_111_111_101_101,
+        b'N' => 0b110_101_101_101_101,
+        b'O' => 0b010_101_101_101_010,
+        b'P' => 0b110_101_110_100_100,
+        b'Q' => 0b010_101_101_110_011,
+        b'R' => 0b110_101_110_101_101,
+        b'S' => 0b011_100_010_001_110,
+        b'T' => 0b111_010_010_010_010,
+        b'U' => 0b101_101_101_101_111,
+        b'V' => 0b101_101_101_101_010,
+        b'W' => 0b101_101_111_111_101,
+        b'X' => 0b101_101_010_101_101,
+        b'Y' => 0b101_101_010_010_010,
+        b'Z' => 0b111_001_010_100_111,
+        b':' => 0b000_010_000_010_000,
+        b'+' => 0b000_010_111_010_000,
+        b'-' => 0b000_000_111_000_000,
+        b'/' => 0b001_001_010_100_100,
+        b'!' => 0b010_010_010_000_010,
+        b'.' => 0b000_000_000_000_010,
+        b'%' => 0b101_001_010_100_101,
+        _ => 0,
+    }
+}
+
+/// Pixel width of `len` characters at `scale`.
+pub const fn width(len: usize, scale: u32) -> u32 { len as u32 * CELL_W * scale }
+
+/// Draw ASCII `text` with its top-left at (x, y), each font pixel `scale` pixels square.
+#[allow(clippy::too_many_arguments)]
+pub fn draw<D: DrawTarget<Color = Rgb565>>(
+    target: &mut D, text: &str, x: i32, y: i32, scale: u32, fg: Rgb565, bg: Rgb565,
+) -> Result<(), D::Error> {
+    let bytes = text.as_bytes();
+    let w = width(bytes.len(), scale);
+    let h = CELL_H * scale;
+    let area = Rectangle::new(Point::new(x, y), Size::new(w, h));
+    let pixels = (0..h).flat_map(move |py| {
+        let row = py / scale;
+        (0..w).map(move |px| {
+            let col = px / scale;
+            let cx = col % CELL_W;
+            if cx == 3 || row == 5 { return bg; }
+            let bits = glyph(bytes[(col / CELL_W) as usize]);
+            if bits & (1 << (14 - (row * 3 + cx))) != 0 { fg } else { bg }
+        })
+    });
+    target.fill_contiguous(&area, pixels)
+}
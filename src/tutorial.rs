@@ -0,0 +1,36 @@
+//! Guided first run: one mechanic per step, each with a prompt and a goal.
+//!
+//! The game loop owns the scripted spawns and checks each step's goal; this
+//! module only holds the script order and the prompt text.
+
+use crate::strings::Msg;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Step {
+    Move,
+    Fire,
+    Gift,
+    Bomb,
+    Done,
+}
+
+impl Step {
+    pub fn prompt(self) -> Msg {
+        match self {
+            Self::Move => Msg::TutorialMove,
+            Self::Fire => Msg::TutorialFire,
+            Self::Gift => Msg::TutorialGift,
+            Self::Bomb => Msg::TutorialBomb,
+            Self::Done => Msg::TutorialDone,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Move => Self::Fire,
+            Self::Fire => Self::Gift,
+            Self::Gift => Self::Bomb,
+            Self::Bomb | Self::Done => Self::Done,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Score milestones that carry a run through "zones".
+//!
+//! Each zone sets how many stars drift behind the playfield, the accent
+//! color of the playfield frame and the tempo of the bass pulse under the
+//! game. The renderer and the buzzer both read the current zone from
+//! `ZONES`, so retuning the progression is one table edit.
+
+use embedded_graphics::pixelcolor::Rgb565;
+
+pub struct Zone {
+    /// Score that enters the zone
+    pub from: u32,
+    pub stars: usize,
+    /// Playfield frame color
+    pub accent: Rgb565,
+    /// Time between bass pulses
+    pub beat_ms: u32,
+}
+
+/// In score order; the first starts at 0.
+pub const ZONES: [Zone; 4] = [
+    Zone { from: 0, stars: 6, accent: Rgb565::new(3, 6, 4), beat_ms: 800 },
+    Zone { from: 50, stars: 12, accent: Rgb565::new(2, 8, 12), beat_ms: 650 },
+    Zone { from: 100, stars: 18, accent: Rgb565::new(12, 4, 12), beat_ms: 500 },
+    Zone { from: 150, stars: 24, accent: Rgb565::new(16, 10, 2), beat_ms: 400 },
+];
+
+/// Most stars any zone draws, for the render batch
+pub const MAX_STARS: usize = 24;
+
+/// Index into `ZONES` for `score`.
+pub fn index(score: u32) -> usize { ZONES.iter().rposition(|z| score >= z.from).unwrap_or(0) }